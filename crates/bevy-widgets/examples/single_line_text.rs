@@ -1,6 +1,6 @@
 #![allow(missing_docs)]
 use bevy::{prelude::*, winit::WinitSettings};
-use bevy_widgets::{input_fields::*, WidgetsPlugin};
+use bevy_widgets::{input_fields::*, WidgetsPlugin, WidgetsSystems};
 use builder::{ErrorValidationCallback, TextInputBuilder, WarningValidationCallback};
 
 fn main() {
@@ -9,7 +9,7 @@ fn main() {
         // Only run the app when there is user input. This will significantly reduce CPU/GPU use.
         .insert_resource(WinitSettings::desktop_app())
         .add_systems(Startup, setup)
-        .add_systems(Update, listener.after(InputFieldSystemSet))
+        .add_systems(Update, listener.after(WidgetsSystems::Events))
         .run();
 }
 
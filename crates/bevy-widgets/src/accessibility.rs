@@ -0,0 +1,94 @@
+use accesskit::{Action, ActionData};
+use bevy::a11y::{ActionRequest, Focus as AccessibilityFocus};
+use bevy::prelude::*;
+
+use crate::{
+    focus::{Clickable, Focus, FocusExt},
+    WidgetsSystems,
+};
+
+/// Plugin publishing the widgets to assistive technologies through `AccessKit`.
+///
+/// Widgets describe their role, name, value and state on their [`bevy::a11y::AccessibilityNode`],
+/// and react to the accessibility actions of screen readers, like focusing, clicking or setting a value.
+pub struct AccessibilityPlugin;
+
+impl Plugin for AccessibilityPlugin {
+    fn build(&self, app: &mut App) {
+        WidgetsSystems::configure(app);
+        app.add_event::<ActionRequest>()
+            .add_observer(sync_focus_gained)
+            .add_observer(sync_focus_lost)
+            .add_systems(Update, focus_actions.in_set(WidgetsSystems::Input));
+    }
+}
+
+/// Entity targeted by an accessibility action
+pub(crate) fn action_target(request: &ActionRequest) -> Option<Entity> {
+    Entity::try_from_bits(request.target.0).ok()
+}
+
+/// Value of a [`Action::SetValue`] request as text
+pub(crate) fn action_value(request: &ActionRequest) -> Option<String> {
+    if request.action != Action::SetValue {
+        return None;
+    }
+    match &request.data {
+        Some(ActionData::Value(value)) => Some(value.to_string()),
+        Some(ActionData::NumericValue(value)) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+/// Value of a [`Action::SetValue`] request as a number
+pub(crate) fn action_numeric_value(request: &ActionRequest) -> Option<f64> {
+    if request.action != Action::SetValue {
+        return None;
+    }
+    match &request.data {
+        Some(ActionData::NumericValue(value)) => Some(*value),
+        Some(ActionData::Value(value)) => value.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Sets the disabled state of an accessibility node, only touching it when the state changes
+pub(crate) fn set_disabled(node: &mut Mut<bevy::a11y::AccessibilityNode>, disabled: bool) {
+    if node.is_disabled() == disabled {
+        return;
+    }
+    if disabled {
+        node.set_disabled();
+    } else {
+        node.clear_disabled();
+    }
+}
+
+fn sync_focus_gained(trigger: Trigger<OnAdd, Focus>, focus: Option<ResMut<AccessibilityFocus>>) {
+    if let Some(mut focus) = focus {
+        focus.0 = Some(trigger.entity());
+    }
+}
+
+fn sync_focus_lost(trigger: Trigger<OnRemove, Focus>, focus: Option<ResMut<AccessibilityFocus>>) {
+    if let Some(mut focus) = focus {
+        if focus.0 == Some(trigger.entity()) {
+            focus.0 = None;
+        }
+    }
+}
+
+fn focus_actions(
+    mut commands: Commands,
+    mut requests: EventReader<ActionRequest>,
+    clickables: Query<(), With<Clickable>>,
+) {
+    for request in requests.read() {
+        if request.action != Action::Focus {
+            continue;
+        }
+        if let Some(target) = action_target(request).filter(|target| clickables.contains(*target)) {
+            commands.set_focus(target);
+        }
+    }
+}
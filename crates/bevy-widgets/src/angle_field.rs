@@ -0,0 +1,354 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::input_fields::{
+    builder::NumericFieldBuilder, components::InputInactive, InputFieldSize, NumberLocale,
+    NumericField, NumericFieldValue, TextFieldExt,
+};
+use crate::WidgetsSystems;
+
+const DIAL_COLOR: Color = Color::srgb(0.29, 0.31, 0.33);
+const DIAL_BORDER_COLOR: Color = Color::srgb(0.49, 0.53, 0.55);
+const NEEDLE_COLOR: Color = Color::srgb(0.51, 0.79, 1.);
+const DIAL_SIZE: f32 = 24.0;
+const NEEDLE_WIDTH: f32 = 2.0;
+const ENTRY_WIDTH: f32 = 52.0;
+/// Angle snapped to while dragging the dial with shift held, in degrees
+const SNAP_DEGREES: f32 = 15.0;
+
+/// Plugin containing the angle field logic
+pub struct AngleFieldPlugin;
+
+impl Plugin for AngleFieldPlugin {
+    fn build(&self, app: &mut App) {
+        WidgetsSystems::configure(app);
+        app.add_event::<AngleChangedEvent>()
+            .register_type::<AngleUnit>()
+            .add_observer(create_angle_field)
+            .add_observer(on_dial_pointer::<DragStart>)
+            .add_observer(on_dial_pointer::<Drag>)
+            .add_systems(
+                Update,
+                (
+                    on_angle_entry_changed.in_set(WidgetsSystems::Events),
+                    (sync_angle_entries, rotate_needles).after(WidgetsSystems::Events),
+                ),
+            );
+    }
+}
+
+/// Unit an [`AngleField`] is displayed and typed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum AngleUnit {
+    /// Degrees, from `0` to `360`
+    #[default]
+    Degrees,
+    /// Radians, from `0` to `TAU`
+    Radians,
+}
+
+impl AngleUnit {
+    /// Converts `radians` to this unit
+    pub const fn from_radians(self, radians: f32) -> f32 {
+        match self {
+            Self::Degrees => radians.to_degrees(),
+            Self::Radians => radians,
+        }
+    }
+
+    /// Converts `value` in this unit to radians
+    pub const fn to_radians(self, value: f32) -> f32 {
+        match self {
+            Self::Degrees => value.to_radians(),
+            Self::Radians => value,
+        }
+    }
+
+    /// Displayed value of `radians`, rounded to the precision of the unit
+    fn display(self, radians: f32) -> f32 {
+        let scale = match self {
+            Self::Degrees => 100.,
+            Self::Radians => 10_000.,
+        };
+        (self.from_radians(radians) * scale).round() / scale
+    }
+
+    const fn drag_step(self) -> f32 {
+        match self {
+            Self::Degrees => 1.,
+            Self::Radians => 0.01,
+        }
+    }
+}
+
+/// Angle edited with a circular dial and a numeric entry, wrapping at a full turn.
+///
+/// The angle grows counter-clockwise from the right of the dial, hold shift while dragging the dial
+/// to snap it to 15°. Typed values outside of a turn are wrapped once editing ends.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::angle_field::{AngleField, AngleUnit};
+/// fn spawn_cone(mut commands: Commands) {
+///     commands.spawn(AngleField::from_degrees(45.).with_unit(AngleUnit::Radians));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+#[require(Node)]
+pub struct AngleField {
+    /// Angle in radians, in `0..TAU`
+    radians: f32,
+    unit: AngleUnit,
+    needle: Option<Entity>,
+    entry: Option<Entity>,
+}
+
+impl Default for AngleField {
+    fn default() -> Self {
+        Self::from_radians(0.)
+    }
+}
+
+impl AngleField {
+    /// Creates an angle field of `radians`
+    pub fn from_radians(radians: f32) -> Self {
+        Self {
+            radians: radians.rem_euclid(TAU),
+            unit: AngleUnit::default(),
+            needle: None,
+            entry: None,
+        }
+    }
+
+    /// Creates an angle field of `degrees`
+    pub fn from_degrees(degrees: f32) -> Self {
+        Self::from_radians(degrees.to_radians())
+    }
+
+    /// Sets the unit the angle is displayed and typed in. Defaults to [`AngleUnit::Degrees`].
+    pub const fn with_unit(mut self, unit: AngleUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Angle in radians, in `0..TAU`
+    pub const fn radians(&self) -> f32 {
+        self.radians
+    }
+
+    /// Angle in degrees, in `0..360`
+    pub const fn degrees(&self) -> f32 {
+        self.radians.to_degrees()
+    }
+
+    /// Unit the angle is displayed and typed in
+    pub const fn unit(&self) -> AngleUnit {
+        self.unit
+    }
+
+    /// Sets the angle in radians, wrapped to a single turn
+    pub fn set_radians(&mut self, radians: f32) {
+        self.radians = radians.rem_euclid(TAU);
+    }
+
+    /// Sets the angle in degrees, wrapped to a single turn
+    pub fn set_degrees(&mut self, degrees: f32) {
+        self.set_radians(degrees.to_radians());
+    }
+}
+
+/// Event sent when the angle of an [`AngleField`] is changed by the user
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct AngleChangedEvent {
+    /// Entity of the [`AngleField`]
+    pub entity: Entity,
+    /// New angle in radians, in `0..TAU`
+    pub radians: f32,
+}
+
+/// Circular dial of an [`AngleField`]
+#[derive(Component, Debug, Clone, Copy)]
+struct AngleDial {
+    field: Entity,
+}
+
+/// Numeric entry of an [`AngleField`]
+#[derive(Component, Debug, Clone, Copy)]
+struct AngleEntry {
+    field: Entity,
+}
+
+fn create_angle_field(
+    trigger: Trigger<OnAdd, AngleField>,
+    mut commands: Commands,
+    mut fields: Query<(&mut AngleField, &mut Node)>,
+) {
+    let entity = trigger.entity();
+    let Ok((mut field, mut node)) = fields.get_mut(entity) else {
+        return;
+    };
+    node.align_items = AlignItems::Center;
+    node.column_gap = Val::Px(6.);
+
+    let mut needle = Entity::PLACEHOLDER;
+    let dial = commands
+        .spawn((
+            Name::new("AngleDial"),
+            AngleDial { field: entity },
+            Node {
+                width: Val::Px(DIAL_SIZE),
+                height: Val::Px(DIAL_SIZE),
+                border: UiRect::all(Val::Px(1.)),
+                flex_shrink: 0.,
+                ..default()
+            },
+            BackgroundColor(DIAL_COLOR),
+            BorderColor(DIAL_BORDER_COLOR),
+            BorderRadius::MAX,
+        ))
+        .with_children(|dial| {
+            // Spans the dial so it rotates around its center, only the right half is drawn
+            needle = dial
+                .spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: Val::Percent(100.),
+                        height: Val::Px(NEEDLE_WIDTH),
+                        top: Val::Percent(50.),
+                        margin: UiRect::top(Val::Px(-NEEDLE_WIDTH / 2.)),
+                        justify_content: JustifyContent::End,
+                        ..default()
+                    },
+                    Transform::from_rotation(Quat::from_rotation_z(-field.radians)),
+                    PickingBehavior::IGNORE,
+                ))
+                .with_child((
+                    Node {
+                        width: Val::Percent(50.),
+                        height: Val::Percent(100.),
+                        ..default()
+                    },
+                    BackgroundColor(NEEDLE_COLOR),
+                    PickingBehavior::IGNORE,
+                ))
+                .id();
+        })
+        .id();
+    let entry = commands
+        .spawn((
+            AngleEntry { field: entity },
+            NumericFieldBuilder::<f32>::default()
+                .with_initial_value(field.unit.display(field.radians))
+                .with_drag_step(field.unit.drag_step())
+                .with_size(InputFieldSize::Small)
+                .with_fixed_width(ENTRY_WIDTH)
+                .build(),
+        ))
+        .id();
+
+    commands.entity(entity).add_children(&[dial, entry]);
+    field.needle = Some(needle);
+    field.entry = Some(entry);
+}
+
+/// Angle of the pointer around the center of `dial`, counter-clockwise from its right
+fn pointer_angle(
+    position: Vec2,
+    dial: &GlobalTransform,
+    computed: &ComputedNode,
+    ui_scale: f32,
+) -> f32 {
+    // Node transforms are in physical pixels, pointers in logical ones
+    let center = dial.translation().truncate() * computed.inverse_scale_factor() * ui_scale;
+    let offset = position - center;
+    (-offset.y).atan2(offset.x)
+}
+
+// Registered for drag start and drag, so pressing the dial already moves the needle
+fn on_dial_pointer<E: std::fmt::Debug + Clone + Reflect>(
+    mut trigger: Trigger<Pointer<E>>,
+    dials: Query<(&AngleDial, &GlobalTransform, &ComputedNode)>,
+    mut fields: Query<&mut AngleField>,
+    keys: Res<ButtonInput<KeyCode>>,
+    ui_scale: Res<UiScale>,
+    mut event_writer: EventWriter<AngleChangedEvent>,
+) {
+    let Ok((dial, transform, computed)) = dials.get(trigger.entity()) else {
+        return;
+    };
+    trigger.propagate(false);
+    let Ok(mut field) = fields.get_mut(dial.field) else {
+        return;
+    };
+    let position = trigger.pointer_location.position;
+    let mut radians = pointer_angle(position, transform, computed, ui_scale.0).rem_euclid(TAU);
+    if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        let snap = SNAP_DEGREES.to_radians();
+        radians = ((radians / snap).round() * snap).rem_euclid(TAU);
+    }
+    if field.radians != radians {
+        field.radians = radians;
+        event_writer.send(AngleChangedEvent {
+            entity: dial.field,
+            radians,
+        });
+    }
+}
+
+fn on_angle_entry_changed(
+    entries: Query<(&AngleEntry, &NumericField<f32>), Changed<NumericField<f32>>>,
+    mut fields: Query<&mut AngleField>,
+    mut event_writer: EventWriter<AngleChangedEvent>,
+) {
+    for (entry, numeric) in &entries {
+        let Ok(mut field) = fields.get_mut(entry.field) else {
+            continue;
+        };
+        let typed = numeric.get_value();
+        // Displayed values are rounded, only typed ones change the angle
+        if field.unit.display(field.radians) == typed {
+            continue;
+        }
+        let radians = field.unit.to_radians(typed).rem_euclid(TAU);
+        if field.radians != radians {
+            field.radians = radians;
+            event_writer.send(AngleChangedEvent {
+                entity: entry.field,
+                radians,
+            });
+        }
+    }
+}
+
+// Displays the wrapped angle, once editing ends for angles being typed
+fn sync_angle_entries(
+    mut commands: Commands,
+    fields: Query<Ref<AngleField>>,
+    entries: Query<(Entity, &AngleEntry, &NumericField<f32>, Ref<InputInactive>)>,
+    locale: Res<NumberLocale>,
+) {
+    for (entity, entry, numeric, inactive) in &entries {
+        let Ok(field) = fields.get(entry.field) else {
+            continue;
+        };
+        if !inactive.0 || (!field.is_changed() && !inactive.is_changed()) {
+            continue;
+        }
+        let displayed = field.unit.display(field.radians);
+        if numeric.get_value() != displayed {
+            commands.set_text(entity, displayed.format_localized(&locale));
+        }
+    }
+}
+
+fn rotate_needles(
+    fields: Query<&AngleField, Changed<AngleField>>,
+    mut needles: Query<&mut Transform>,
+) {
+    for field in &fields {
+        if let Some(mut transform) = field.needle.and_then(|needle| needles.get_mut(needle).ok()) {
+            // UI is y down, so a negative rotation turns counter-clockwise on screen
+            transform.rotation = Quat::from_rotation_z(-field.radians);
+        }
+    }
+}
@@ -0,0 +1,267 @@
+use bevy::prelude::*;
+
+use crate::focus::{stop_propagation, EventPropagation};
+
+const BADGE_COLOR: Color = Color::srgb(0.19, 0.49, 0.71);
+const BADGE_TEXT_COLOR: Color = Color::srgb(0.97, 0.97, 0.98);
+const BADGE_FONT_SIZE: f32 = 8.0;
+const BADGE_SIZE: f32 = 14.0;
+const BADGE_OFFSET: f32 = -6.0;
+const DEFAULT_BADGE_MAX: u32 = 99;
+
+const CHIP_BACKGROUND_COLOR: Color = Color::srgb(0.93, 0.97, 1.0);
+const CHIP_BORDER_COLOR: Color = Color::srgb(0.51, 0.79, 1.);
+const CHIP_TEXT_COLOR: Color = Color::srgb(0.29, 0.31, 0.33);
+const CHIP_REMOVE_HOVERED_COLOR: Color = Color::srgb(0.19, 0.49, 0.71);
+const CHIP_FONT_SIZE: f32 = 10.0;
+
+/// Plugin containing the badge and chip logic
+pub struct BadgesPlugin;
+
+impl Plugin for BadgesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ChipRemovedEvent>()
+            .register_type::<Badge>()
+            .register_type::<Chip>()
+            .add_observer(on_chip_remove_click)
+            .add_systems(Update, (update_badges, chip_remove_hover));
+    }
+}
+
+/// Count bubble shown on the top right corner of its parent widget.
+/// Hidden while the count is `0`.
+/// Should be created with [`BadgeBuilder`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+#[require(Node)]
+pub struct Badge {
+    /// Count displayed
+    pub count: u32,
+    /// Counts above are displayed as `max+`
+    pub max: u32,
+}
+
+/// Builder for [`Badge`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BadgeBuilder {
+    count: u32,
+    max: u32,
+}
+
+impl BadgeBuilder {
+    /// Creates a badge builder displaying `count`
+    pub const fn new(count: u32) -> Self {
+        Self {
+            count,
+            max: DEFAULT_BADGE_MAX,
+        }
+    }
+
+    /// Sets the largest count displayed as is. Defaults to `99`.
+    pub const fn with_max(mut self, max: u32) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Spawns the badge as a child of `target`
+    pub fn build(self, commands: &mut Commands, target: Entity) -> Entity {
+        let badge = commands
+            .spawn((
+                Badge {
+                    count: self.count,
+                    max: self.max,
+                },
+                Name::new("Badge"),
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(BADGE_OFFSET),
+                    right: Val::Px(BADGE_OFFSET),
+                    min_width: Val::Px(BADGE_SIZE),
+                    height: Val::Px(BADGE_SIZE),
+                    padding: UiRect::horizontal(Val::Px(4.)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(BADGE_COLOR),
+                BorderRadius::MAX,
+                PickingBehavior::IGNORE,
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    Text::new(badge_text(self.count, self.max)),
+                    TextColor(BADGE_TEXT_COLOR),
+                    TextFont {
+                        font_size: BADGE_FONT_SIZE,
+                        ..default()
+                    },
+                    PickingBehavior::IGNORE,
+                ));
+            })
+            .id();
+        commands.entity(target).add_child(badge);
+        badge
+    }
+}
+
+fn badge_text(count: u32, max: u32) -> String {
+    if count > max {
+        format!("{max}+")
+    } else {
+        count.to_string()
+    }
+}
+
+fn update_badges(
+    mut badges: Query<(&Badge, &Children, &mut Visibility), Changed<Badge>>,
+    mut texts: Query<&mut Text>,
+) {
+    for (badge, children, mut visibility) in &mut badges {
+        *visibility = if badge.count == 0 {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+        for child in children {
+            if let Ok(mut text) = texts.get_mut(*child) {
+                text.0 = badge_text(badge.count, badge.max);
+            }
+        }
+    }
+}
+
+/// Labelled tag, optionally removable with its close button.
+/// Should be created with [`ChipBuilder`].
+#[derive(Component, Debug, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+#[require(Node)]
+pub struct Chip {
+    /// Text of the chip
+    pub label: String,
+}
+
+/// Close button of a removable [`Chip`]
+#[derive(Component, Debug)]
+#[require(Interaction)]
+pub struct ChipRemoveButton {
+    chip: Entity,
+}
+
+/// An event that is fired when a [`Chip`] is removed with its close button.
+/// The chip is despawned.
+#[derive(Event, Debug, Clone, Reflect)]
+pub struct ChipRemovedEvent {
+    /// The chip entity
+    pub entity: Entity,
+    /// Label of the removed chip
+    pub label: String,
+}
+
+/// Builder for [`Chip`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChipBuilder {
+    label: String,
+    removable: bool,
+}
+
+impl ChipBuilder {
+    /// Creates a chip builder with `label`
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            removable: false,
+        }
+    }
+
+    /// Adds a close button removing the chip
+    pub const fn removable(mut self) -> Self {
+        self.removable = true;
+        self
+    }
+
+    /// Spawns the chip
+    pub fn build(self, commands: &mut Commands) -> Entity {
+        let text_font = TextFont {
+            font_size: CHIP_FONT_SIZE,
+            ..default()
+        };
+        let chip = commands
+            .spawn((
+                Chip {
+                    label: self.label.clone(),
+                },
+                Name::new("Chip"),
+                Node {
+                    padding: UiRect::axes(Val::Px(8.), Val::Px(2.)),
+                    border: UiRect::all(Val::Px(1.)),
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(4.),
+                    ..default()
+                },
+                BackgroundColor(CHIP_BACKGROUND_COLOR),
+                BorderColor(CHIP_BORDER_COLOR),
+                BorderRadius::MAX,
+            ))
+            .with_child((
+                Text::new(self.label),
+                TextColor(CHIP_TEXT_COLOR),
+                text_font.clone(),
+                PickingBehavior::IGNORE,
+            ))
+            .id();
+
+        if self.removable {
+            commands.entity(chip).with_children(|parent| {
+                parent
+                    .spawn((ChipRemoveButton { chip }, Node::default()))
+                    .with_child((
+                        Text::new("x"),
+                        TextColor(CHIP_TEXT_COLOR),
+                        text_font,
+                        PickingBehavior::IGNORE,
+                    ));
+            });
+        }
+        chip
+    }
+}
+
+fn chip_remove_hover(
+    buttons: Query<(&Interaction, &Children), (Changed<Interaction>, With<ChipRemoveButton>)>,
+    mut colors: Query<&mut TextColor>,
+) {
+    for (interaction, children) in &buttons {
+        let color = if *interaction == Interaction::None {
+            CHIP_TEXT_COLOR
+        } else {
+            CHIP_REMOVE_HOVERED_COLOR
+        };
+        for child in children {
+            if let Ok(mut text_color) = colors.get_mut(*child) {
+                text_color.0 = color;
+            }
+        }
+    }
+}
+
+fn on_chip_remove_click(
+    mut trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    buttons: Query<&ChipRemoveButton>,
+    chips: Query<&Chip>,
+    mut event_writer: EventWriter<ChipRemovedEvent>,
+    propagation: Query<&EventPropagation>,
+) {
+    let Ok(button) = buttons.get(trigger.entity()) else {
+        return;
+    };
+    stop_propagation(&mut trigger, &propagation);
+    let Ok(chip) = chips.get(button.chip) else {
+        return;
+    };
+    event_writer.send(ChipRemovedEvent {
+        entity: button.chip,
+        label: chip.label.clone(),
+    });
+    commands.entity(button.chip).despawn_recursive();
+}
@@ -0,0 +1,232 @@
+use std::any::TypeId;
+
+use bevy::ecs::reflect::{ReflectComponent, ReflectResource};
+use bevy::prelude::*;
+use bevy::reflect::{GetPath, PartialReflect, TypeRegistry};
+
+use crate::input_fields::{
+    components::InputInactive, InputTextValue, NumberLocale, NumericInput, SetInputText,
+};
+use crate::WidgetsSystems;
+
+/// Plugin containing the data binding logic
+pub struct BindingPlugin;
+
+impl Plugin for BindingPlugin {
+    fn build(&self, app: &mut App) {
+        WidgetsSystems::configure(app);
+        app.add_systems(Update, sync_bindings.after(WidgetsSystems::Events));
+    }
+}
+
+/// Links the value of a text or numeric field to a reflected field of a component or resource.
+///
+/// The field displays the world value every frame, and edits are written back to the world.
+/// While the field is focused, edits win over changes made to the world, which are displayed once the field loses focus.
+/// Bound values can be numbers or [`String`]s, and the component or resource must be registered with
+/// `#[reflect(Component)]` or `#[reflect(Resource)]`.
+///
+/// Prefer the [`bind!`](crate::bind) macro:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::{bind, input_fields::builder::NumericFieldBuilder};
+/// fn inspect(mut commands: Commands, entity: Entity) {
+///     commands.spawn((
+///         NumericFieldBuilder::<f32>::default().build(),
+///         bind!(Transform.translation.x of entity),
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct Bind {
+    entity: Option<Entity>,
+    type_id: TypeId,
+    path: String,
+    /// World value text at the last sync
+    world_text: Option<String>,
+    /// Field text at the last sync
+    widget_text: Option<String>,
+}
+
+impl Bind {
+    /// Binds to the field at the reflect `path` of the component `C` of `entity`, e.g. `".translation.x"`
+    pub fn component<C: Component>(entity: Entity, path: impl Into<String>) -> Self {
+        Self::new(Some(entity), TypeId::of::<C>(), path.into())
+    }
+
+    /// Binds to the field at the reflect `path` of the resource `R`, e.g. `".0"`
+    pub fn resource<R: Resource>(path: impl Into<String>) -> Self {
+        Self::new(None, TypeId::of::<R>(), path.into())
+    }
+
+    const fn new(entity: Option<Entity>, type_id: TypeId, path: String) -> Self {
+        Self {
+            entity,
+            type_id,
+            path,
+            world_text: None,
+            widget_text: None,
+        }
+    }
+
+    /// Bound entity, `None` for resources
+    pub const fn entity(&self) -> Option<Entity> {
+        self.entity
+    }
+
+    /// Reflect path of the bound field
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+/// Creates a [`Bind`] from a component field path and an entity, or a resource field path.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::bind;
+/// # #[derive(Resource, Reflect)]
+/// # #[reflect(Resource)]
+/// # struct Gravity { strength: f32 }
+/// # let entity = Entity::PLACEHOLDER;
+/// let x = bind!(Transform.translation.x of entity);
+/// assert_eq!(x.path(), ".translation.x");
+/// let gravity = bind!(Gravity.strength);
+/// assert_eq!(gravity.entity(), None);
+/// ```
+#[macro_export]
+macro_rules! bind {
+    ($component:ident $(. $field:tt)+ of $entity:expr) => {
+        $crate::binding::Bind::component::<$component>(
+            $entity,
+            concat!($(".", stringify!($field)),+),
+        )
+    };
+    ($resource:ident $(. $field:tt)+) => {
+        $crate::binding::Bind::resource::<$resource>(concat!($(".", stringify!($field)),+))
+    };
+}
+
+macro_rules! bound_values {
+    ($($t:ty),*) => {
+        /// Text of a bound value
+        fn value_text(value: &dyn PartialReflect) -> Option<String> {
+            $(
+                if let Some(value) = value.try_downcast_ref::<$t>() {
+                    return Some(value.to_string());
+                }
+            )*
+            value.try_downcast_ref::<String>().cloned()
+        }
+
+        /// Parses `text` into a bound value, `false` when it doesn't parse
+        fn parse_into(text: &str, value: &mut dyn PartialReflect) -> bool {
+            $(
+                if let Some(value) = value.try_downcast_mut::<$t>() {
+                    return text.trim().parse().map(|parsed| *value = parsed).is_ok();
+                }
+            )*
+            if let Some(value) = value.try_downcast_mut::<String>() {
+                text.clone_into(value);
+                return true;
+            }
+            false
+        }
+    };
+}
+
+bound_values!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+fn read_bound(world: &World, registry: &TypeRegistry, bind: &Bind) -> Option<String> {
+    let registration = registry.get(bind.type_id)?;
+    let value = match bind.entity {
+        Some(entity) => registration
+            .data::<ReflectComponent>()?
+            .reflect(world.get_entity(entity).ok()?)?,
+        None => registration.data::<ReflectResource>()?.reflect(world)?,
+    };
+    value_text(value.reflect_path(bind.path.as_str()).ok()?)
+}
+
+fn write_bound(world: &mut World, registry: &TypeRegistry, bind: &Bind, text: &str) -> bool {
+    let Some(registration) = registry.get(bind.type_id) else {
+        return false;
+    };
+    let value = match bind.entity {
+        Some(entity) => registration
+            .data::<ReflectComponent>()
+            .zip(world.get_entity_mut(entity).ok())
+            .and_then(|(reflect, entity)| reflect.reflect_mut(entity)),
+        None => registration
+            .data::<ReflectResource>()
+            .and_then(|reflect| reflect.reflect_mut(world)),
+    };
+    let Some(mut value) = value else {
+        return false;
+    };
+    value
+        .reflect_path_mut(bind.path.as_str())
+        .is_ok_and(|field| parse_into(text, field))
+}
+
+fn sync_bindings(world: &mut World) {
+    let mut bound = world.query::<(
+        Entity,
+        &Bind,
+        &InputTextValue,
+        &InputInactive,
+        Has<NumericInput>,
+    )>();
+    let bindings: Vec<_> = bound
+        .iter(world)
+        .map(|(entity, bind, value, inactive, numeric)| {
+            (entity, bind.clone(), value.0.clone(), !inactive.0, numeric)
+        })
+        .collect();
+    if bindings.is_empty() {
+        return;
+    }
+    let locale = world
+        .get_resource::<NumberLocale>()
+        .copied()
+        .unwrap_or_default();
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+
+    for (entity, mut bind, text, focused, numeric) in bindings {
+        let Some(world_text) = read_bound(world, &registry, &bind) else {
+            continue;
+        };
+        let widget_changed = bind.widget_text.as_deref() != Some(text.as_str());
+        let world_changed = bind.world_text.as_deref() != Some(world_text.as_str());
+
+        // Edits of a focused field win over world changes
+        if widget_changed && (focused || !world_changed) {
+            let parsed = if numeric {
+                locale.normalize(&text)
+            } else {
+                Some(text.clone())
+            };
+            // Partial input, like `-`, is written once it parses
+            if !parsed.is_some_and(|parsed| write_bound(world, &registry, &bind, &parsed)) {
+                continue;
+            }
+            bind.world_text = read_bound(world, &registry, &bind);
+            bind.widget_text = Some(text);
+        } else if world_changed && !focused {
+            let displayed = if numeric {
+                locale.localize(&world_text)
+            } else {
+                world_text.clone()
+            };
+            world.trigger_targets(SetInputText(displayed.clone()), entity);
+            bind.world_text = Some(world_text);
+            bind.widget_text = Some(displayed);
+        } else {
+            continue;
+        }
+        if let Some(mut synced) = world.get_mut::<Bind>(entity) {
+            *synced = bind;
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+
+use crate::focus::{stop_propagation, EventPropagation};
+
+const SEGMENT_COLOR: Color = Color::srgb(0.19, 0.49, 0.71);
+const HOVERED_SEGMENT_COLOR: Color = Color::srgb(0.51, 0.79, 1.);
+const CURRENT_SEGMENT_COLOR: Color = Color::srgb(0.29, 0.31, 0.33);
+const SEPARATOR_COLOR: Color = Color::srgb(0.49, 0.53, 0.55);
+const FONT_SIZE: f32 = 10.0;
+const SEPARATOR: &str = ">";
+
+/// Plugin containing the breadcrumbs logic
+pub struct BreadcrumbsPlugin;
+
+impl Plugin for BreadcrumbsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<BreadcrumbClicked>()
+            .register_type::<Breadcrumbs>()
+            .add_observer(on_segment_click)
+            .add_systems(Update, (rebuild_breadcrumbs, segment_hover).chain());
+    }
+}
+
+/// Path of clickable segments, e.g. the parent chain of an entity or a nested field path.
+///
+/// Replace [`Breadcrumbs::segments`] to update the path, the last segment is shown as the current location.
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+#[require(Node)]
+pub struct Breadcrumbs {
+    /// Labels of the segments, from the root
+    pub segments: Vec<String>,
+}
+
+impl Breadcrumbs {
+    /// Creates breadcrumbs from `segments`
+    pub fn new<S: Into<String>>(segments: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            segments: segments.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// A clickable segment of [`Breadcrumbs`]
+#[derive(Component, Debug)]
+#[require(Interaction)]
+pub struct BreadcrumbSegment {
+    /// Index of the segment in [`Breadcrumbs::segments`]
+    pub index: usize,
+    breadcrumbs: Entity,
+}
+
+/// An event that is fired when a segment of [`Breadcrumbs`] is clicked.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub struct BreadcrumbClicked {
+    /// The breadcrumbs entity
+    pub entity: Entity,
+    /// Index of the clicked segment
+    pub index: usize,
+}
+
+fn rebuild_breadcrumbs(
+    mut commands: Commands,
+    mut breadcrumbs: Query<(Entity, &Breadcrumbs, &mut Node), Changed<Breadcrumbs>>,
+) {
+    for (entity, breadcrumbs, mut node) in &mut breadcrumbs {
+        node.flex_direction = FlexDirection::Row;
+        node.flex_wrap = FlexWrap::Wrap;
+        node.align_items = AlignItems::Center;
+        node.column_gap = Val::Px(4.);
+
+        let text_font = TextFont {
+            font_size: FONT_SIZE,
+            ..default()
+        };
+        let last = breadcrumbs.segments.len().saturating_sub(1);
+        commands
+            .entity(entity)
+            .despawn_descendants()
+            .with_children(|parent| {
+                for (index, segment) in breadcrumbs.segments.iter().enumerate() {
+                    if index > 0 {
+                        parent.spawn((
+                            Text::new(SEPARATOR),
+                            TextColor(SEPARATOR_COLOR),
+                            text_font.clone(),
+                            PickingBehavior::IGNORE,
+                        ));
+                    }
+                    parent.spawn((
+                        BreadcrumbSegment {
+                            index,
+                            breadcrumbs: entity,
+                        },
+                        Text::new(segment.clone()),
+                        TextColor(if index == last {
+                            CURRENT_SEGMENT_COLOR
+                        } else {
+                            SEGMENT_COLOR
+                        }),
+                        text_font.clone(),
+                    ));
+                }
+            });
+    }
+}
+
+fn segment_hover(
+    mut segments: Query<(&BreadcrumbSegment, &Interaction, &mut TextColor), Changed<Interaction>>,
+    breadcrumbs: Query<&Breadcrumbs>,
+) {
+    for (segment, interaction, mut color) in &mut segments {
+        let last = breadcrumbs
+            .get(segment.breadcrumbs)
+            .is_ok_and(|breadcrumbs| segment.index + 1 == breadcrumbs.segments.len());
+        color.0 = match (interaction, last) {
+            (Interaction::Hovered | Interaction::Pressed, _) => HOVERED_SEGMENT_COLOR,
+            (Interaction::None, true) => CURRENT_SEGMENT_COLOR,
+            (Interaction::None, false) => SEGMENT_COLOR,
+        };
+    }
+}
+
+fn on_segment_click(
+    mut trigger: Trigger<Pointer<Click>>,
+    segments: Query<&BreadcrumbSegment>,
+    mut event_writer: EventWriter<BreadcrumbClicked>,
+    propagation: Query<&EventPropagation>,
+) {
+    let Ok(segment) = segments.get(trigger.entity()) else {
+        return;
+    };
+    stop_propagation(&mut trigger, &propagation);
+    event_writer.send(BreadcrumbClicked {
+        entity: segment.breadcrumbs,
+        index: segment.index,
+    });
+}
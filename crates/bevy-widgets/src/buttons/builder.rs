@@ -1,6 +1,12 @@
 use std::{convert::Infallible, str::FromStr};
 
-use crate::{buttons::constants::*, focus::Clickable};
+use crate::{
+    buttons::constants::*,
+    focus::Clickable,
+    localization::{LocalizedString, LocalizedTexts},
+    transition::Transition,
+    widget_ref::{WidgetRef, WidgetSpawner},
+};
 use bevy::prelude::*;
 
 /// A helper container for button text
@@ -12,7 +18,8 @@ pub struct ButtonsText(pub String);
 /// - small: height of 20px, padding of 16px x 8px, font size of 10px
 /// - medium: height of 24px, padding of 20px x 12px, font size of 10px
 /// - large: height of 30px, padding of 24px x 16px, font size of 13px
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Component, Reflect)]
+#[reflect(Component)]
 pub enum ButtonSize {
     /// small: height of 20px, padding of 16px x 8px, font size of 10px
     Small,
@@ -158,6 +165,7 @@ pub struct ButtonBuilder {
     button_radius: ButtonRadius,
     text: Option<String>,
     width: Option<Val>,
+    localized: LocalizedTexts,
 }
 
 impl FromStr for ButtonBuilder {
@@ -180,6 +188,12 @@ impl ButtonBuilder {
         }
     }
 
+    /// Sets the button text, a literal or a [`LocalizedString::Key`]
+    pub fn with_text(mut self, text: impl Into<LocalizedString>) -> Self {
+        self.text = Some(LocalizedTexts::track(&mut self.localized.text, text.into()));
+        self
+    }
+
     /// Adds [`ButtonType`] to the builder.
     /// Default value is [`ButtonType::Primary`].
     /// - [`ButtonType::Primary`]
@@ -236,41 +250,61 @@ impl ButtonBuilder {
             .id()
     }
 
-    fn with_button(self, parent: &mut ChildBuilder<'_>) {
-        parent
-            .spawn((
-                Clickable,
-                ButtonsText(self.text.clone().unwrap_or_default()),
-                Button,
-                Node {
-                    width: self.width.unwrap_or(Val::Auto),
-                    height: self.button_size.height(),
-                    border: UiRect::all(Val::Px(1.0)),
-                    justify_content: JustifyContent::Center,
-                    align_items: AlignItems::Center,
-                    padding: self.button_size.padding(),
-                    ..default()
-                },
-                Into::<BorderColor>::into(self.button_type.border_color(SubInteraction::Default)),
-                self.button_radius.radius(),
-                Into::<BackgroundColor>::into(
-                    self.button_type.background_color(SubInteraction::Default),
-                ),
-                self.button_type,
-            ))
-            .with_children(|parent| {
-                if let Some(text) = self.text {
-                    parent.spawn((
-                        Text::new(text),
-                        TextColor(self.button_type.font_color()),
-                        TextFont {
-                            // font: asset_server.load("fonts/FiraSans-Bold.ttf"),
-                            font_size: self.button_size.font_size(),
-                            ..default()
-                        },
-                    ));
-                }
+    /// Spawns the button without a wrapping container, returning the button and text entities
+    ///
+    /// Works from [`Commands`] and from a [`ChildBuilder`].
+    pub fn spawn(self, spawner: &mut impl WidgetSpawner) -> WidgetRef {
+        let mut button = spawner.spawn_root();
+        button.insert(self.button_bundle());
+        let mut widget = WidgetRef::new(button.id());
+        if let Some(text) = self.text.clone() {
+            button.with_children(|parent| {
+                widget.text = Some(parent.spawn(self.text_bundle(text)).id());
             });
+        }
+        widget
+    }
+
+    fn with_button(self, parent: &mut ChildBuilder<'_>) {
+        self.spawn(parent);
+    }
+
+    fn button_bundle(&self) -> impl Bundle {
+        (
+            Clickable,
+            ButtonsText(self.text.clone().unwrap_or_default()),
+            Button,
+            Node {
+                width: self.width.unwrap_or(Val::Auto),
+                height: self.button_size.height(),
+                border: UiRect::all(Val::Px(1.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                padding: self.button_size.padding(),
+                ..default()
+            },
+            Into::<BorderColor>::into(self.button_type.border_color(SubInteraction::Default)),
+            self.button_radius.radius(),
+            Into::<BackgroundColor>::into(
+                self.button_type.background_color(SubInteraction::Default),
+            ),
+            self.button_type,
+            self.button_size,
+            Transition::default(),
+            self.localized.clone(),
+        )
+    }
+
+    fn text_bundle(&self, text: String) -> impl Bundle {
+        (
+            Text::new(text),
+            TextColor(self.button_type.font_color()),
+            TextFont {
+                // font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                font_size: self.button_size.font_size(),
+                ..default()
+            },
+        )
     }
 
     pub(crate) fn child_build(self, commands: &mut ChildBuilder) -> Entity {
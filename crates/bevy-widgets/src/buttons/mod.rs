@@ -5,7 +5,7 @@ use bevy::hierarchy::ChildBuilder;
 use bevy::prelude::Event;
 use bevy::reflect::Reflect;
 
-mod builder;
+pub(crate) mod builder;
 mod constants;
 mod helpers;
 pub(super) mod systems;
@@ -1,9 +1,14 @@
+use accesskit::Action;
+use bevy::a11y::{AccessibilityNode, ActionRequest};
 use bevy::prelude::*;
 
-use crate::focus::Focus;
+use crate::{
+    accessibility::{action_target, set_disabled},
+    focus::Focus,
+};
 
 use super::{
-    builder::{ButtonType, ButtonsText, SubInteraction},
+    builder::{ButtonSize, ButtonType, ButtonsText, SubInteraction},
     ButtonClickedEvent, DisableButton,
 };
 
@@ -32,6 +37,61 @@ pub fn on_add_focus(
     }
 }
 
+/// Re-applies the height, padding and font size of spawned buttons when their [`ButtonSize`] changes
+pub(crate) fn apply_button_size(
+    mut buttons: Query<(Ref<ButtonSize>, &mut Node, Option<&Children>), With<Button>>,
+    mut fonts: Query<&mut TextFont>,
+) {
+    for (size, mut node, children) in &mut buttons {
+        if !size.is_changed() || size.is_added() {
+            continue;
+        }
+        node.height = size.height();
+        node.padding = size.padding();
+        for child in children.into_iter().flatten() {
+            if let Ok(mut font) = fonts.get_mut(*child) {
+                font.font_size = size.font_size();
+            }
+        }
+    }
+}
+
+/// Adds the disabled state and the click and focus actions to the accessibility nodes of buttons
+pub(crate) fn update_button_accessibility(
+    mut buttons: Query<(&mut AccessibilityNode, Has<DisableButton>), With<ButtonsText>>,
+) {
+    for (mut accessible, disabled) in &mut buttons {
+        set_disabled(&mut accessible, disabled);
+        for action in [Action::Click, Action::Focus] {
+            if !accessible.supports_action(action) {
+                accessible.add_action(action);
+            }
+        }
+    }
+}
+
+/// Clicks buttons on the click action of assistive technologies
+pub(crate) fn button_accessibility_actions(
+    mut requests: EventReader<ActionRequest>,
+    buttons: Query<&ButtonsText, (With<Button>, Without<DisableButton>)>,
+    mut event_writer: EventWriter<ButtonClickedEvent>,
+) {
+    for request in requests.read() {
+        if request.action != Action::Click {
+            continue;
+        }
+        let Some(entity) = action_target(request) else {
+            continue;
+        };
+        if let Ok(button_text) = buttons.get(entity) {
+            event_writer.send(ButtonClickedEvent {
+                entity,
+                value: button_text.0.clone(),
+            });
+        }
+    }
+}
+
 pub(crate) fn button_system(
     mut interaction_query: Query<
         (
@@ -0,0 +1,262 @@
+use bevy::prelude::*;
+
+const CARD_BORDER_COLOR: Color = Color::srgb(0.29, 0.31, 0.33);
+const CARD_TITLE_COLOR: Color = Color::srgb(0.97, 0.97, 0.98);
+const CARD_SHADOW_COLOR: Color = Color::srgba(0., 0., 0., 0.5);
+const CARD_FONT_SIZE: f32 = 13.0;
+const CARD_RADIUS: f32 = 8.0;
+const CARD_PADDING: f32 = 8.0;
+const HEADER_HEIGHT: f32 = 28.0;
+
+/// Plugin containing the card logic
+pub struct CardPlugin;
+
+impl Plugin for CardPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CardElevation>()
+            .register_type::<Card>()
+            .add_observer(create_card_header)
+            .add_systems(Update, (move_card_actions, update_cards));
+    }
+}
+
+/// Surface levels of the design system, from the panel background up to floating overlays
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+pub enum CardElevation {
+    /// Same level as the background, outlined only
+    Flat,
+    /// Panel surface
+    #[default]
+    Raised,
+    /// Floating surface with a drop shadow, for popups and dialogs
+    Overlay,
+}
+
+impl CardElevation {
+    /// Background color of the surface
+    pub const fn background_color(&self) -> Color {
+        match self {
+            Self::Flat => Color::srgb(0.1, 0.1, 0.11),
+            Self::Raised => Color::srgb(0.13, 0.13, 0.14),
+            Self::Overlay => Color::srgb(0.18, 0.18, 0.2),
+        }
+    }
+
+    /// Outline color of the surface
+    pub const fn border_color(&self) -> Color {
+        match self {
+            Self::Flat => CARD_BORDER_COLOR,
+            _ => Color::NONE,
+        }
+    }
+
+    /// Drop shadow of the surface
+    pub fn box_shadow(&self) -> BoxShadow {
+        let (blur, offset) = match self {
+            Self::Flat => return BoxShadow::default(),
+            Self::Raised => (4., 1.),
+            Self::Overlay => (16., 4.),
+        };
+        BoxShadow {
+            color: CARD_SHADOW_COLOR,
+            y_offset: Val::Px(offset),
+            blur_radius: Val::Px(blur),
+            ..default()
+        }
+    }
+}
+
+/// Container surface used as the base of inspector panels, with an optional header holding a title and actions.
+///
+/// Children spawned with [`CardAction`] are moved to the right of the header.
+/// Should be created with [`CardBuilder`].
+#[derive(Component, Debug, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+#[require(Node)]
+pub struct Card {
+    /// Surface level
+    pub elevation: CardElevation,
+    /// Title displayed in the header
+    pub title: Option<String>,
+    #[reflect(ignore)]
+    header: Option<Entity>,
+    #[reflect(ignore)]
+    actions: Option<Entity>,
+}
+
+impl Card {
+    /// Header entity, spawned when the card has a title or actions
+    pub const fn header(&self) -> Option<Entity> {
+        self.header
+    }
+}
+
+/// Marks a child of a [`Card`] as an action of its header, e.g. a close or menu button
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Component)]
+pub struct CardAction;
+
+#[derive(Component, Debug)]
+struct CardTitle;
+
+/// Builder for [`Card`]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CardBuilder {
+    elevation: CardElevation,
+    title: Option<String>,
+}
+
+impl CardBuilder {
+    /// Sets the surface level. Defaults to [`CardElevation::Raised`].
+    pub const fn with_elevation(mut self, elevation: CardElevation) -> Self {
+        self.elevation = elevation;
+        self
+    }
+
+    /// Adds a header with `title`
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Builds the card, content should be spawned as its children
+    pub fn build(
+        self,
+    ) -> (
+        Card,
+        Node,
+        BackgroundColor,
+        BorderColor,
+        BorderRadius,
+        BoxShadow,
+    ) {
+        (
+            Card {
+                elevation: self.elevation,
+                title: self.title,
+                header: None,
+                actions: None,
+            },
+            Node {
+                flex_direction: FlexDirection::Column,
+                border: UiRect::all(Val::Px(1.)),
+                padding: UiRect::all(Val::Px(CARD_PADDING)),
+                row_gap: Val::Px(CARD_PADDING),
+                ..default()
+            },
+            BackgroundColor(self.elevation.background_color()),
+            BorderColor(self.elevation.border_color()),
+            BorderRadius::all(Val::Px(CARD_RADIUS)),
+            self.elevation.box_shadow(),
+        )
+    }
+}
+
+fn create_card_header(trigger: Trigger<OnAdd, Card>, cards: Query<&Card>, mut commands: Commands) {
+    let entity = trigger.entity();
+    let Ok(card) = cards.get(entity) else {
+        return;
+    };
+    let title = card.title.clone().unwrap_or_default();
+    let mut actions = None;
+    let header = commands
+        .spawn((
+            Name::new("CardHeader"),
+            Node {
+                display: if card.title.is_some() {
+                    Display::Flex
+                } else {
+                    Display::None
+                },
+                height: Val::Px(HEADER_HEIGHT),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceBetween,
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                CardTitle,
+                Text::new(title),
+                TextColor(CARD_TITLE_COLOR),
+                TextFont {
+                    font_size: CARD_FONT_SIZE,
+                    ..default()
+                },
+            ));
+            actions = Some(
+                parent
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(4.),
+                        ..default()
+                    })
+                    .id(),
+            );
+        })
+        .id();
+    commands.entity(entity).insert_children(0, &[header]);
+    commands
+        .entity(entity)
+        .queue(move |mut entity: EntityWorldMut| {
+            if let Some(mut card) = entity.get_mut::<Card>() {
+                card.header = Some(header);
+                card.actions = actions;
+            }
+        });
+}
+
+fn move_card_actions(
+    mut commands: Commands,
+    new_actions: Query<(Entity, &Parent), Added<CardAction>>,
+    cards: Query<&Card>,
+    mut nodes: Query<&mut Node>,
+) {
+    for (action, parent) in &new_actions {
+        let Ok(card) = cards.get(parent.get()) else {
+            continue;
+        };
+        let (Some(header), Some(actions)) = (card.header, card.actions) else {
+            continue;
+        };
+        commands.entity(actions).add_child(action);
+        if let Ok(mut node) = nodes.get_mut(header) {
+            node.display = Display::Flex;
+        }
+    }
+}
+
+fn update_cards(
+    mut cards: Query<
+        (
+            &Card,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &mut BoxShadow,
+        ),
+        Changed<Card>,
+    >,
+    children: Query<&Children>,
+    mut titles: Query<&mut Text, With<CardTitle>>,
+    mut nodes: Query<&mut Node>,
+) {
+    for (card, mut background, mut border, mut shadow) in &mut cards {
+        background.0 = card.elevation.background_color();
+        border.0 = card.elevation.border_color();
+        *shadow = card.elevation.box_shadow();
+        let Some(header) = card.header else {
+            continue;
+        };
+        if let Some(title) = &card.title {
+            if let Ok(mut node) = nodes.get_mut(header) {
+                node.display = Display::Flex;
+            }
+            for child in children.iter_descendants(header) {
+                if let Ok(mut text) = titles.get_mut(child) {
+                    text.0.clone_from(title);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,313 @@
+use std::collections::VecDeque;
+
+use bevy::app::{App, Plugin};
+use bevy::ecs::system::{ResMut, Resource, SystemParam};
+use bevy::reflect::{
+    serde::{ReflectDeserializer, ReflectSerializer},
+    PartialReflect, Reflect, TypeRegistry,
+};
+use serde::de::DeserializeSeed;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native;
+#[cfg(target_arch = "wasm32")]
+mod web;
+
+#[cfg(not(target_arch = "wasm32"))]
+use native::SystemClipboard;
+#[cfg(target_arch = "wasm32")]
+use web::SystemClipboard;
+
+const DEFAULT_HISTORY_CAPACITY: usize = 16;
+
+/// Plugin containing the copy+paste from clipboard
+pub struct ClipboardPlugin;
+
+impl Plugin for ClipboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ClipboardContext>()
+            .init_resource::<ClipboardHistory>()
+            .register_type::<ClipboardFormat>()
+            .register_type::<ClipboardData>()
+            .register_type::<ClipboardEntry>();
+    }
+}
+
+/// Contains clipboard api instance.
+///
+/// Uses the operating system clipboard on native targets and the browser Clipboard API on `wasm32`.
+/// When the system clipboard is unavailable (headless, permission denied), an in-app clipboard is used instead.
+#[derive(Resource)]
+pub struct ClipboardContext {
+    system: Option<SystemClipboard>,
+    internal: Option<String>,
+}
+
+impl Default for ClipboardContext {
+    fn default() -> Self {
+        Self {
+            system: SystemClipboard::new(),
+            internal: None,
+        }
+    }
+}
+
+impl ClipboardContext {
+    /// Copies text to the system clipboard, keeping an in-app copy as fallback
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        let text = text.into();
+        if let Some(system) = self.system.as_mut() {
+            system.set_text(&text);
+        }
+        self.internal = Some(text);
+    }
+
+    /// Reads text from the system clipboard, or from the in-app clipboard if the system one is unavailable
+    pub fn get_text(&mut self) -> Option<String> {
+        self.system
+            .as_mut()
+            .and_then(SystemClipboard::get_text)
+            .or_else(|| self.internal.clone())
+    }
+
+    /// Checks if a system clipboard backend could be created
+    pub const fn has_system_clipboard(&self) -> bool {
+        self.system.is_some()
+    }
+}
+
+/// Formats a paste target can accept, in order of preference.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+pub enum ClipboardFormat {
+    /// Plain text, every clipboard entry can be pasted as text
+    Text,
+    /// RON serialized reflected value of the type with the given type path
+    Ron {
+        /// Full type path, as in [`bevy::reflect::TypePath::type_path`]
+        type_path: String,
+    },
+}
+
+/// Data handed to a paste target after format negotiation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+pub enum ClipboardData {
+    /// Plain text
+    Text(String),
+    /// RON serialized reflected value
+    Ron {
+        /// Full type path of the serialized value
+        type_path: String,
+        /// RON text of the serialized value
+        ron: String,
+    },
+}
+
+impl ClipboardData {
+    /// Deserializes [`ClipboardData::Ron`] back into a reflected value.
+    ///
+    /// Returns `None` for [`ClipboardData::Text`].
+    pub fn to_reflect(
+        &self,
+        registry: &TypeRegistry,
+    ) -> Option<Result<Box<dyn PartialReflect>, ron::Error>> {
+        let Self::Ron { ron, .. } = self else {
+            return None;
+        };
+        Some(
+            ron::Deserializer::from_str(ron)
+                .map_err(ron::Error::from)
+                .and_then(|mut deserializer| {
+                    ReflectDeserializer::new(registry).deserialize(&mut deserializer)
+                }),
+        )
+    }
+}
+
+/// A single copy operation, containing its plain text representation and optional structured data.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+pub struct ClipboardEntry {
+    /// Plain text representation, the one sent to the system clipboard
+    pub text: String,
+    /// Structured representation, only available inside the app
+    pub structured: Option<ClipboardData>,
+}
+
+impl ClipboardEntry {
+    /// Creates a plain text entry
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            structured: None,
+        }
+    }
+
+    /// Creates an entry from a reflected value, serializing it as RON.
+    ///
+    /// The RON text is also used as the plain text representation.
+    pub fn from_reflect(
+        value: &dyn PartialReflect,
+        registry: &TypeRegistry,
+    ) -> Result<Self, ron::Error> {
+        let ron = ron::ser::to_string_pretty(
+            &ReflectSerializer::new(value, registry),
+            ron::ser::PrettyConfig::default(),
+        )?;
+        Ok(Self {
+            text: ron.clone(),
+            structured: Some(ClipboardData::Ron {
+                type_path: value.reflect_type_path().to_string(),
+                ron,
+            }),
+        })
+    }
+
+    /// Picks the first format in `accepted` that this entry can provide.
+    pub fn negotiate(&self, accepted: &[ClipboardFormat]) -> Option<ClipboardData> {
+        accepted
+            .iter()
+            .find_map(|format| match (format, &self.structured) {
+                (ClipboardFormat::Text, _) => Some(ClipboardData::Text(self.text.clone())),
+                (
+                    ClipboardFormat::Ron { type_path },
+                    Some(
+                        data @ ClipboardData::Ron {
+                            type_path: path, ..
+                        },
+                    ),
+                ) if type_path == path => Some(data.clone()),
+                _ => None,
+            })
+    }
+}
+
+/// Recently copied entries, the most recent first.
+#[derive(Resource, Debug, Clone)]
+pub struct ClipboardHistory {
+    entries: VecDeque<ClipboardEntry>,
+    capacity: usize,
+}
+
+impl Default for ClipboardHistory {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_HISTORY_CAPACITY)
+    }
+}
+
+impl ClipboardHistory {
+    /// Creates an empty history keeping at most `capacity` entries
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Maximum number of entries kept
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Changes the maximum number of entries, dropping the oldest ones if needed
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.entries.truncate(capacity);
+    }
+
+    /// Adds an entry as the most recent one.
+    /// If an equal entry already exists, it is moved to the front instead of duplicated.
+    pub fn push(&mut self, entry: ClipboardEntry) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.entries.retain(|existing| existing != &entry);
+        self.entries.push_front(entry);
+        self.entries.truncate(self.capacity);
+    }
+
+    /// The most recent entry
+    pub fn latest(&self) -> Option<&ClipboardEntry> {
+        self.entries.front()
+    }
+
+    /// Entry at `index`, where `0` is the most recent
+    pub fn get(&self, index: usize) -> Option<&ClipboardEntry> {
+        self.entries.get(index)
+    }
+
+    /// Iterates entries from the most recent to the oldest
+    pub fn iter(&self) -> impl Iterator<Item = &ClipboardEntry> {
+        self.entries.iter()
+    }
+
+    /// Number of entries in the history
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks if the history is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all entries
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// A convenience parameter to copy to and paste from the clipboard while keeping [`ClipboardHistory`] up to date.
+#[derive(SystemParam)]
+pub struct ClipboardAccess<'w> {
+    context: ResMut<'w, ClipboardContext>,
+    history: ResMut<'w, ClipboardHistory>,
+}
+
+impl ClipboardAccess<'_> {
+    /// Copies plain text
+    pub fn copy_text(&mut self, text: impl Into<String>) {
+        self.copy(ClipboardEntry::text(text));
+    }
+
+    /// Copies a reflected value as RON, returning serialization errors
+    pub fn copy_reflect(
+        &mut self,
+        value: &dyn PartialReflect,
+        registry: &TypeRegistry,
+    ) -> Result<(), ron::Error> {
+        self.copy(ClipboardEntry::from_reflect(value, registry)?);
+        Ok(())
+    }
+
+    /// Copies an entry, sending its text to the system clipboard
+    pub fn copy(&mut self, entry: ClipboardEntry) {
+        self.context.set_text(entry.text.clone());
+        self.history.push(entry);
+    }
+
+    /// Pastes the current clipboard content in the first format of `accepted` that is available.
+    ///
+    /// Structured formats are only available while the system clipboard still holds
+    /// the text of the latest entry copied from this app.
+    pub fn paste(&mut self, accepted: &[ClipboardFormat]) -> Option<ClipboardData> {
+        let system_text = self.context.get_text();
+        match (self.history.latest(), system_text) {
+            (Some(entry), Some(text)) if entry.text == text => entry.negotiate(accepted),
+            (Some(entry), None) => entry.negotiate(accepted),
+            (_, Some(text)) => ClipboardEntry::text(text).negotiate(accepted),
+            (None, None) => None,
+        }
+    }
+
+    /// Pastes the current clipboard content as plain text
+    pub fn paste_text(&mut self) -> Option<String> {
+        match self.paste(&[ClipboardFormat::Text])? {
+            ClipboardData::Text(text) => Some(text),
+            ClipboardData::Ron { ron, .. } => Some(ron),
+        }
+    }
+
+    /// Read access to the copy history
+    pub fn history(&self) -> &ClipboardHistory {
+        &self.history
+    }
+}
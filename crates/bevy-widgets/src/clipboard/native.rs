@@ -0,0 +1,18 @@
+use arboard::Clipboard;
+
+/// Operating system clipboard, backed by `arboard`
+pub(super) struct SystemClipboard(Clipboard);
+
+impl SystemClipboard {
+    pub(super) fn new() -> Option<Self> {
+        Clipboard::new().ok().map(Self)
+    }
+
+    pub(super) fn set_text(&mut self, text: &str) -> bool {
+        self.0.set_text(text).is_ok()
+    }
+
+    pub(super) fn get_text(&mut self) -> Option<String> {
+        self.0.get_text().ok()
+    }
+}
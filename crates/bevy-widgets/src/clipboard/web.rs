@@ -0,0 +1,68 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
+
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+
+/// Browser clipboard, backed by the async Clipboard API.
+///
+/// Browser reads are asynchronous, so reading returns the last known clipboard text
+/// and requests a refresh that becomes visible on the following read.
+/// Once the browser denies access, every call fails so the in-app clipboard is used instead.
+pub(super) struct SystemClipboard {
+    clipboard: web_sys::Clipboard,
+    latest: Arc<Mutex<Option<String>>>,
+    denied: Arc<AtomicBool>,
+}
+
+impl SystemClipboard {
+    pub(super) fn new() -> Option<Self> {
+        let window = web_sys::window()?;
+        Some(Self {
+            clipboard: window.navigator().clipboard(),
+            latest: Arc::default(),
+            denied: Arc::default(),
+        })
+    }
+
+    pub(super) fn set_text(&mut self, text: &str) -> bool {
+        if self.denied.load(Ordering::Relaxed) {
+            return false;
+        }
+        if let Ok(mut latest) = self.latest.lock() {
+            *latest = Some(text.to_string());
+        }
+
+        let promise = self.clipboard.write_text(text);
+        let denied = self.denied.clone();
+        spawn_local(async move {
+            if JsFuture::from(promise).await.is_err() {
+                denied.store(true, Ordering::Relaxed);
+            }
+        });
+        true
+    }
+
+    pub(super) fn get_text(&mut self) -> Option<String> {
+        if self.denied.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        let promise = self.clipboard.read_text();
+        let latest = self.latest.clone();
+        let denied = self.denied.clone();
+        spawn_local(async move {
+            match JsFuture::from(promise).await {
+                Ok(value) => {
+                    if let Ok(mut latest) = latest.lock() {
+                        *latest = value.as_string();
+                    }
+                }
+                Err(_) => denied.store(true, Ordering::Relaxed),
+            }
+        });
+
+        self.latest.lock().ok()?.clone()
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt::{self, Write};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Mutex;
+
+use bevy::log::tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+use bevy::log::{BoxedLayer, Level};
+use bevy::prelude::*;
+use bevy::utils::tracing::{
+    field::{Field, Visit},
+    Event as TracingEvent, Subscriber,
+};
+
+/// A log record captured by [`console_log_layer`]
+#[derive(Debug, Clone)]
+pub(super) struct CapturedLog {
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+/// Receiving end of the log channel, drained into [`super::ConsoleLog`]
+#[derive(Resource)]
+pub(super) struct CapturedLogReceiver(pub Mutex<Receiver<CapturedLog>>);
+
+struct ConsoleLayer {
+    sender: Sender<CapturedLog>,
+}
+
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{value:?}");
+        } else {
+            let _ = write!(self.fields, " {}={value:?}", field.name());
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            let _ = write!(self.fields, " {}={value}", field.name());
+        }
+    }
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for ConsoleLayer {
+    fn on_event(&self, event: &TracingEvent<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        // The receiver is gone once the app is dropped, records are discarded then.
+        let _ = self.sender.send(CapturedLog {
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            message: visitor.message + &visitor.fields,
+        });
+    }
+}
+
+/// Tracing layer capturing `bevy_log` output for [`super::Console`] panels.
+///
+/// Set it as [`LogPlugin::custom_layer`](bevy::log::LogPlugin::custom_layer):
+/// ```rust,no_run
+/// # use bevy::{log::LogPlugin, prelude::*};
+/// # use bevy_widgets::console::console_log_layer;
+/// App::new().add_plugins(DefaultPlugins.set(LogPlugin {
+///     custom_layer: console_log_layer,
+///     ..default()
+/// }));
+/// ```
+pub fn console_log_layer(app: &mut App) -> Option<BoxedLayer> {
+    let (sender, receiver) = mpsc::channel();
+    app.insert_resource(CapturedLogReceiver(Mutex::new(receiver)));
+    Some(Box::new(ConsoleLayer { sender }))
+}
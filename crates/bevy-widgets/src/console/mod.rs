@@ -0,0 +1,226 @@
+use std::collections::VecDeque;
+
+use bevy::log::Level;
+use bevy::prelude::*;
+
+mod layer;
+mod systems;
+
+pub use layer::console_log_layer;
+use systems::*;
+
+const DEFAULT_LOG_CAPACITY: usize = 1000;
+
+/// Plugin containing the console panel logic.
+///
+/// Log records are only captured when [`console_log_layer`] is set as the `LogPlugin` custom layer.
+pub struct ConsolePlugin;
+
+impl Plugin for ConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ConsoleLog>()
+            .add_observer(create_console)
+            .add_observer(on_line_click)
+            .add_systems(
+                Update,
+                (
+                    drain_captured_logs,
+                    on_console_toolbar_click,
+                    update_search,
+                    render_console_lines,
+                    scroll_console_lines,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// A log record shown in the [`Console`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsoleLine {
+    /// Sequence number of the record
+    pub id: u64,
+    /// Log level
+    pub level: Level,
+    /// Module that emitted the record
+    pub target: String,
+    /// Formatted message, including the record fields
+    pub message: String,
+}
+
+impl ConsoleLine {
+    fn text(&self) -> String {
+        format!("{:>5} {}: {}", self.level, self.target, self.message)
+    }
+}
+
+/// Latest log records, shared by every [`Console`] panel
+#[derive(Resource, Debug, Clone)]
+pub struct ConsoleLog {
+    lines: VecDeque<ConsoleLine>,
+    capacity: usize,
+    next_id: u64,
+}
+
+impl Default for ConsoleLog {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_LOG_CAPACITY)
+    }
+}
+
+impl ConsoleLog {
+    /// Creates a log keeping the latest `capacity` records
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            lines: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+            next_id: 0,
+        }
+    }
+
+    /// Adds a record, dropping the oldest one when full
+    pub fn push(&mut self, level: Level, target: impl Into<String>, message: impl Into<String>) {
+        if self.lines.len() == self.capacity {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(ConsoleLine {
+            id: self.next_id,
+            level,
+            target: target.into(),
+            message: message.into(),
+        });
+        self.next_id += 1;
+    }
+
+    /// Records from oldest to latest
+    pub fn iter(&self) -> impl Iterator<Item = &ConsoleLine> {
+        self.lines.iter()
+    }
+
+    /// Record with the sequence number `id`, if still kept
+    pub fn get(&self, id: u64) -> Option<&ConsoleLine> {
+        let first = self.lines.front()?.id;
+        self.lines
+            .get(usize::try_from(id.checked_sub(first)?).ok()?)
+    }
+
+    /// Number of records kept
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Checks if there is no record
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Sequence number of the next record
+    pub const fn next_id(&self) -> u64 {
+        self.next_id
+    }
+}
+
+const LEVELS: [Level; 5] = [
+    Level::ERROR,
+    Level::WARN,
+    Level::INFO,
+    Level::DEBUG,
+    Level::TRACE,
+];
+
+const fn level_index(level: Level) -> usize {
+    match level {
+        Level::ERROR => 0,
+        Level::WARN => 1,
+        Level::INFO => 2,
+        Level::DEBUG => 3,
+        Level::TRACE => 4,
+    }
+}
+
+/// Scrolling panel showing the [`ConsoleLog`] records.
+///
+/// Its toolbar filters records by level and text, toggles auto-scroll, copies the selected lines and clears the view.
+#[derive(Component, Debug, Clone)]
+#[require(Node)]
+pub struct Console {
+    levels: [bool; 5],
+    search: String,
+    /// Scrolls to the latest record when one is added
+    pub auto_scroll: bool,
+    cleared_before: u64,
+    rendered_until: u64,
+    dirty: bool,
+    lines: Option<Entity>,
+    search_field: Option<Entity>,
+}
+
+impl Default for Console {
+    fn default() -> Self {
+        Self {
+            levels: [true, true, true, false, false],
+            search: String::new(),
+            auto_scroll: true,
+            cleared_before: 0,
+            rendered_until: 0,
+            dirty: true,
+            lines: None,
+            search_field: None,
+        }
+    }
+}
+
+impl Console {
+    /// Checks if records of `level` are shown
+    pub const fn shows(&self, level: Level) -> bool {
+        self.levels[level_index(level)]
+    }
+
+    /// Shows or hides records of `level`
+    pub const fn set_level_visible(&mut self, level: Level, visible: bool) {
+        let shown = &mut self.levels[level_index(level)];
+        if *shown != visible {
+            *shown = visible;
+            self.dirty = true;
+        }
+    }
+
+    /// Text records must contain to be shown, case insensitive
+    pub fn search(&self) -> &str {
+        &self.search
+    }
+
+    /// Sets the text records must contain to be shown
+    pub fn set_search(&mut self, search: impl Into<String>) {
+        let search = search.into();
+        if self.search != search {
+            self.search = search;
+            self.dirty = true;
+        }
+    }
+
+    /// Hides every record received until now
+    pub const fn clear(&mut self, log: &ConsoleLog) {
+        self.cleared_before = log.next_id;
+        self.dirty = true;
+    }
+
+    fn matches(&self, line: &ConsoleLine) -> bool {
+        line.id >= self.cleared_before
+            && self.shows(line.level)
+            && (self.search.is_empty()
+                || line
+                    .text()
+                    .to_lowercase()
+                    .contains(&self.search.to_lowercase()))
+    }
+}
+
+/// A line of a [`Console`], click to select it
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConsoleLineEntry {
+    /// Sequence number of the record
+    pub id: u64,
+    /// Selected lines are copied by the copy button
+    pub selected: bool,
+}
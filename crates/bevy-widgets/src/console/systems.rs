@@ -0,0 +1,314 @@
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::log::Level;
+use bevy::picking::focus::HoverMap;
+use bevy::prelude::*;
+
+use crate::clipboard::ClipboardAccess;
+use crate::focus::{stop_propagation, EventPropagation};
+use crate::input_fields::{builder::TextInputBuilder, components::InputTextValue, InputFieldSize};
+use crate::toolbar::{ToolbarBuilder, ToolbarButtonBuilder, ToolbarButtonClickedEvent};
+
+use super::{
+    layer::CapturedLogReceiver, level_index, Console, ConsoleLine, ConsoleLineEntry, ConsoleLog,
+    LEVELS,
+};
+
+const MAX_RENDERED_LINES: usize = 500;
+const FONT_SIZE: f32 = 10.0;
+const SCROLL_LINE_HEIGHT: f32 = 20.0;
+const LINES_BACKGROUND_COLOR: Color = Color::srgb(0.08, 0.08, 0.09);
+const SELECTED_LINE_COLOR: Color = Color::srgb(0.11, 0.29, 0.42);
+const ERROR_COLOR: Color = Color::srgb(1.0, 0.4, 0.4);
+const WARN_COLOR: Color = Color::srgb(1., 0.78, 0.);
+const INFO_COLOR: Color = Color::srgb(0.93, 0.97, 1.0);
+const DEBUG_COLOR: Color = Color::srgb(0.51, 0.79, 1.);
+const TRACE_COLOR: Color = Color::srgb(0.49, 0.53, 0.55);
+
+const AUTO_SCROLL_ID: &str = "console.auto_scroll";
+const COPY_ID: &str = "console.copy";
+const CLEAR_ID: &str = "console.clear";
+
+const fn level_color(level: Level) -> Color {
+    match level {
+        Level::ERROR => ERROR_COLOR,
+        Level::WARN => WARN_COLOR,
+        Level::INFO => INFO_COLOR,
+        Level::DEBUG => DEBUG_COLOR,
+        Level::TRACE => TRACE_COLOR,
+    }
+}
+
+fn level_id(level: Level) -> String {
+    format!("console.level.{level}")
+}
+
+pub(super) fn create_console(
+    trigger: Trigger<OnAdd, Console>,
+    mut commands: Commands,
+    mut consoles: Query<(&mut Console, &mut Node)>,
+) {
+    let entity = trigger.entity();
+    let Ok((mut console, mut node)) = consoles.get_mut(entity) else {
+        return;
+    };
+    node.flex_direction = FlexDirection::Column;
+
+    let toolbar = commands.spawn(ToolbarBuilder::default().build()).id();
+    for level in LEVELS {
+        ToolbarButtonBuilder::new(level_id(level))
+            .with_label(level.as_str())
+            .toggle()
+            .pressed(console.levels[level_index(level)])
+            .build(&mut commands, toolbar);
+    }
+    let search_field = commands
+        .spawn(
+            TextInputBuilder::default()
+                .with_size(InputFieldSize::Small)
+                .with_placeholder("Search".to_string())
+                .build(),
+        )
+        .id();
+    commands.entity(toolbar).add_child(search_field);
+    ToolbarButtonBuilder::new(AUTO_SCROLL_ID)
+        .with_label("Auto-scroll")
+        .toggle()
+        .pressed(console.auto_scroll)
+        .build(&mut commands, toolbar);
+    ToolbarButtonBuilder::new(COPY_ID)
+        .with_label("Copy")
+        .build(&mut commands, toolbar);
+    ToolbarButtonBuilder::new(CLEAR_ID)
+        .with_label("Clear")
+        .build(&mut commands, toolbar);
+
+    let lines = commands
+        .spawn((
+            Name::new("ConsoleLines"),
+            Node {
+                flex_grow: 1.,
+                min_height: Val::Px(0.),
+                flex_direction: FlexDirection::Column,
+                overflow: Overflow::scroll_y(),
+                padding: UiRect::all(Val::Px(4.)),
+                ..default()
+            },
+            BackgroundColor(LINES_BACKGROUND_COLOR),
+            ScrollPosition::default(),
+        ))
+        .id();
+
+    commands.entity(entity).add_children(&[toolbar, lines]);
+    console.lines = Some(lines);
+    console.search_field = Some(search_field);
+}
+
+pub(super) fn drain_captured_logs(
+    receiver: Option<Res<CapturedLogReceiver>>,
+    mut log: ResMut<ConsoleLog>,
+) {
+    let Some(receiver) = receiver else {
+        return;
+    };
+    let Ok(receiver) = receiver.0.lock() else {
+        return;
+    };
+    for captured in receiver.try_iter() {
+        log.push(captured.level, captured.target, captured.message);
+    }
+}
+
+pub(super) fn on_console_toolbar_click(
+    mut events: EventReader<ToolbarButtonClickedEvent>,
+    parents: Query<&Parent>,
+    mut consoles: Query<&mut Console>,
+    entries: Query<&ConsoleLineEntry>,
+    children: Query<&Children>,
+    log: Res<ConsoleLog>,
+    mut clipboard: ClipboardAccess,
+) {
+    for event in events.read() {
+        let Some(console_entity) = parents
+            .iter_ancestors(event.entity)
+            .find(|ancestor| consoles.contains(*ancestor))
+        else {
+            continue;
+        };
+        let Ok(mut console) = consoles.get_mut(console_entity) else {
+            continue;
+        };
+
+        if let Some(level) = LEVELS
+            .into_iter()
+            .find(|level| level_id(*level) == event.id)
+        {
+            console.set_level_visible(level, event.pressed);
+            continue;
+        }
+        match event.id.as_str() {
+            AUTO_SCROLL_ID => console.auto_scroll = event.pressed,
+            CLEAR_ID => console.clear(&log),
+            COPY_ID => {
+                let selected: Vec<String> = console
+                    .lines
+                    .and_then(|lines| children.get(lines).ok())
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|line| entries.get(*line).ok())
+                    .filter(|entry| entry.selected)
+                    .filter_map(|entry| log.get(entry.id))
+                    .map(ConsoleLine::text)
+                    .collect();
+                if !selected.is_empty() {
+                    clipboard.copy_text(selected.join("\n"));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+pub(super) fn update_search(
+    mut consoles: Query<&mut Console>,
+    fields: Query<&InputTextValue, Changed<InputTextValue>>,
+) {
+    for mut console in &mut consoles {
+        let Some(value) = console
+            .search_field
+            .and_then(|field| fields.get(field).ok())
+        else {
+            continue;
+        };
+        if console.search != value.0 {
+            console.set_search(value.0.clone());
+        }
+    }
+}
+
+pub(super) fn render_console_lines(
+    mut commands: Commands,
+    log: Res<ConsoleLog>,
+    mut consoles: Query<&mut Console>,
+    children: Query<&Children>,
+    mut scrolls: Query<&mut ScrollPosition>,
+) {
+    for mut console in &mut consoles {
+        let Some(lines) = console.lines else {
+            continue;
+        };
+        if !console.dirty && console.rendered_until == log.next_id() {
+            continue;
+        }
+        let console = console.bypass_change_detection();
+        let rendered = if console.dirty {
+            commands.entity(lines).despawn_descendants();
+            console.rendered_until = console.cleared_before;
+            console.dirty = false;
+            0
+        } else {
+            children.get(lines).map_or(0, |children| children.len())
+        };
+
+        let new_lines: Vec<&ConsoleLine> = log
+            .iter()
+            .filter(|line| line.id >= console.rendered_until && console.matches(line))
+            .collect();
+        console.rendered_until = log.next_id();
+        if new_lines.is_empty() {
+            continue;
+        }
+
+        let skip = new_lines.len().saturating_sub(MAX_RENDERED_LINES);
+        let overflow = (rendered + new_lines.len() - skip).saturating_sub(MAX_RENDERED_LINES);
+        if let Ok(existing) = children.get(lines) {
+            for line in existing.iter().take(overflow.min(rendered)) {
+                commands.entity(*line).despawn_recursive();
+            }
+        }
+
+        commands.entity(lines).with_children(|parent| {
+            for line in new_lines.into_iter().skip(skip) {
+                parent.spawn((
+                    ConsoleLineEntry {
+                        id: line.id,
+                        selected: false,
+                    },
+                    Text::new(line.text()),
+                    TextColor(level_color(line.level)),
+                    TextFont {
+                        font_size: FONT_SIZE,
+                        ..default()
+                    },
+                    BackgroundColor(Color::NONE),
+                ));
+            }
+        });
+
+        if console.auto_scroll {
+            if let Ok(mut scroll) = scrolls.get_mut(lines) {
+                // Clamped to the content size by the layout.
+                scroll.offset_y = f32::MAX;
+            }
+        }
+    }
+}
+
+pub(super) fn on_line_click(
+    mut trigger: Trigger<Pointer<Click>>,
+    mut entries: Query<(&mut ConsoleLineEntry, &mut BackgroundColor)>,
+    propagation: Query<&EventPropagation>,
+) {
+    let Ok((mut entry, mut background)) = entries.get_mut(trigger.entity()) else {
+        return;
+    };
+    stop_propagation(&mut trigger, &propagation);
+    entry.selected = !entry.selected;
+    background.0 = if entry.selected {
+        SELECTED_LINE_COLOR
+    } else {
+        Color::NONE
+    };
+}
+
+pub(super) fn scroll_console_lines(
+    mut mouse_wheel: EventReader<MouseWheel>,
+    hover_map: Res<HoverMap>,
+    parents: Query<&Parent>,
+    consoles: Query<&Console>,
+    mut scrolls: Query<&mut ScrollPosition>,
+) {
+    for event in mouse_wheel.read() {
+        let delta = match event.unit {
+            MouseScrollUnit::Line => event.y * SCROLL_LINE_HEIGHT,
+            MouseScrollUnit::Pixel => event.y,
+        };
+        for (_, hits) in hover_map.iter() {
+            for hovered in hits.keys() {
+                let Some(console_entity) = std::iter::once(*hovered)
+                    .chain(parents.iter_ancestors(*hovered))
+                    .find(|entity| consoles.contains(*entity))
+                else {
+                    continue;
+                };
+                let Some(lines) = consoles
+                    .get(console_entity)
+                    .ok()
+                    .and_then(|console| console.lines)
+                else {
+                    continue;
+                };
+                let is_over_lines = std::iter::once(*hovered)
+                    .chain(parents.iter_ancestors(*hovered))
+                    .any(|entity| entity == lines);
+                if !is_over_lines {
+                    continue;
+                }
+                if let Ok(mut scroll) = scrolls.get_mut(lines) {
+                    scroll.offset_y = (scroll.offset_y - delta).max(0.);
+                }
+                // Only the topmost hovered line scrolls.
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use bevy::picking::pointer::PointerId;
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, SystemCursorIcon};
+use bevy::winit::cursor::CursorIcon;
+
+use crate::{
+    buttons::DisableButton,
+    input_fields::{components::InputInactive, InputFieldState, NumericInput, TextInput},
+    link::Link,
+};
+
+/// Plugin changing the OS cursor while hovering widgets
+pub struct CursorPlugin;
+
+impl Plugin for CursorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CursorOwner>()
+            .register_type::<HoverCursor>()
+            .add_observer(insert_hover_cursor::<TextInput>)
+            .add_observer(insert_hover_cursor::<NumericInput>)
+            .add_observer(insert_hover_cursor::<Button>)
+            .add_observer(insert_hover_cursor::<Link>)
+            .add_observer(cursor_over)
+            .add_observer(cursor_out)
+            .add_systems(Update, release_despawned_owner);
+    }
+}
+
+/// Cursor icon of the primary window while the mouse is over the entity.
+///
+/// Inserted on text fields (I-beam), numeric fields (horizontal resize, as they are dragged), split pane
+/// dividers (resize along the split) and buttons and links (pointer hand). Insert it before spawning to
+/// override the default of a widget, or on any node to give it a cursor. The default cursor is restored
+/// when the mouse leaves the entity, and disabled widgets keep the default cursor.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub struct HoverCursor(pub SystemCursorIcon);
+
+/// Widgets with a default [`HoverCursor`]
+trait DefaultHoverCursor: Component {
+    const ICON: SystemCursorIcon;
+}
+
+impl DefaultHoverCursor for TextInput {
+    const ICON: SystemCursorIcon = SystemCursorIcon::Text;
+}
+
+impl DefaultHoverCursor for NumericInput {
+    const ICON: SystemCursorIcon = SystemCursorIcon::EwResize;
+}
+
+impl DefaultHoverCursor for Button {
+    const ICON: SystemCursorIcon = SystemCursorIcon::Pointer;
+}
+
+impl DefaultHoverCursor for Link {
+    const ICON: SystemCursorIcon = SystemCursorIcon::Pointer;
+}
+
+/// Entity whose [`HoverCursor`] is displayed, and the hovered entity that selected it
+#[derive(Resource, Debug, Default)]
+struct CursorOwner {
+    entity: Option<Entity>,
+    target: Option<Entity>,
+}
+
+fn insert_hover_cursor<W: DefaultHoverCursor>(
+    trigger: Trigger<OnAdd, W>,
+    mut commands: Commands,
+    cursors: Query<(), With<HoverCursor>>,
+) {
+    let entity = trigger.entity();
+    if !cursors.contains(entity) {
+        commands.entity(entity).insert(HoverCursor(W::ICON));
+    }
+}
+
+fn set_window_cursor(
+    commands: &mut Commands,
+    windows: &Query<Entity, With<PrimaryWindow>>,
+    icon: SystemCursorIcon,
+) {
+    if let Ok(window) = windows.get_single() {
+        commands.entity(window).insert(CursorIcon::System(icon));
+    }
+}
+
+fn cursor_over(
+    trigger: Trigger<Pointer<Over>>,
+    mut commands: Commands,
+    mut owner: ResMut<CursorOwner>,
+    cursors: Query<(
+        &HoverCursor,
+        Option<&InputFieldState>,
+        Option<&InputInactive>,
+        Has<DisableButton>,
+        Has<NumericInput>,
+    )>,
+    windows: Query<Entity, With<PrimaryWindow>>,
+) {
+    if trigger.pointer_id != PointerId::Mouse {
+        return;
+    }
+    let entity = trigger.entity();
+    let Ok((cursor, state, inactive, disabled_button, numeric)) = cursors.get(entity) else {
+        return;
+    };
+    // The event bubbles from the hovered entity, the innermost cursor wins
+    if owner.target == Some(trigger.target) && owner.entity != Some(entity) {
+        return;
+    }
+    owner.entity = Some(entity);
+    owner.target = Some(trigger.target);
+
+    let icon = if disabled_button || state == Some(&InputFieldState::Disabled) {
+        SystemCursorIcon::Default
+    } else if numeric && inactive.is_some_and(|inactive| !inactive.0) {
+        // Numeric fields are typed in while focused
+        SystemCursorIcon::Text
+    } else {
+        cursor.0
+    };
+    set_window_cursor(&mut commands, &windows, icon);
+}
+
+fn cursor_out(
+    trigger: Trigger<Pointer<Out>>,
+    mut commands: Commands,
+    mut owner: ResMut<CursorOwner>,
+    windows: Query<Entity, With<PrimaryWindow>>,
+) {
+    if trigger.pointer_id != PointerId::Mouse || owner.entity != Some(trigger.entity()) {
+        return;
+    }
+    *owner = CursorOwner::default();
+    set_window_cursor(&mut commands, &windows, SystemCursorIcon::Default);
+}
+
+// Despawned entities never receive `Pointer<Out>`
+fn release_despawned_owner(
+    mut commands: Commands,
+    mut owner: ResMut<CursorOwner>,
+    cursors: Query<(), With<HoverCursor>>,
+    windows: Query<Entity, With<PrimaryWindow>>,
+) {
+    let Some(entity) = owner.entity else {
+        return;
+    };
+    if !cursors.contains(entity) {
+        *owner = CursorOwner::default();
+        set_window_cursor(&mut commands, &windows, SystemCursorIcon::Default);
+    }
+}
@@ -0,0 +1,367 @@
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+mod systems;
+
+use systems::*;
+
+const MAX_CURVE_POINTS: usize = 32;
+
+/// Plugin containing the curve and gradient editor logic
+pub struct CurvesPlugin;
+
+impl Plugin for CurvesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CurveChangedEvent>()
+            .add_event::<GradientChangedEvent>()
+            .register_type::<CurveInterpolation>()
+            .add_observer(create_curve_editor)
+            .add_observer(create_gradient_editor)
+            .add_observer(on_curve_area_click)
+            .add_observer(on_curve_handle_click)
+            .add_observer(on_curve_handle_drag)
+            .add_observer(on_gradient_bar_click)
+            .add_observer(on_gradient_marker_click)
+            .add_observer(on_gradient_marker_drag)
+            .add_systems(
+                Update,
+                (
+                    on_interpolation_click,
+                    sync_curve_handles,
+                    sync_gradient_markers,
+                    update_gradients,
+                )
+                    .chain(),
+            )
+            .add_systems(PostUpdate, draw_curves.after(UiSystem::Layout));
+    }
+}
+
+/// How a [`CurveEditor`] interpolates between its control points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum CurveInterpolation {
+    /// Keeps the value of the previous point
+    Constant,
+    /// Straight line between points
+    #[default]
+    Linear,
+    /// Eases in and out of each point
+    Smooth,
+}
+
+impl CurveInterpolation {
+    const ALL: [Self; 3] = [Self::Constant, Self::Linear, Self::Smooth];
+
+    const fn label(self) -> &'static str {
+        match self {
+            Self::Constant => "Constant",
+            Self::Linear => "Linear",
+            Self::Smooth => "Smooth",
+        }
+    }
+}
+
+/// Editable curve, e.g. an animation curve or a particle size over lifetime.
+///
+/// Control points are normalized in `[0, 1]` on both axes and sorted by `x`.
+/// Drag a handle to move its point, click the area to add a point and right click a handle to remove it.
+#[derive(Component, Debug, Clone)]
+#[require(Node)]
+pub struct CurveEditor {
+    points: Vec<Vec2>,
+    interpolation: CurveInterpolation,
+    area: Option<Entity>,
+    handles_dirty: bool,
+}
+
+impl Default for CurveEditor {
+    fn default() -> Self {
+        Self::new([Vec2::ZERO, Vec2::ONE])
+    }
+}
+
+impl CurveEditor {
+    /// Creates an editor of the curve going through `points`
+    pub fn new(points: impl IntoIterator<Item = Vec2>) -> Self {
+        let mut editor = Self {
+            points: Vec::new(),
+            interpolation: CurveInterpolation::default(),
+            area: None,
+            handles_dirty: true,
+        };
+        editor.set_points(points);
+        editor
+    }
+
+    /// Sets the interpolation mode
+    pub const fn with_interpolation(mut self, interpolation: CurveInterpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Control points sorted by `x`
+    pub fn points(&self) -> &[Vec2] {
+        &self.points
+    }
+
+    /// Replaces the control points, clamped to `[0, 1]`
+    pub fn set_points(&mut self, points: impl IntoIterator<Item = Vec2>) {
+        self.points = points
+            .into_iter()
+            .take(MAX_CURVE_POINTS)
+            .map(|point| point.clamp(Vec2::ZERO, Vec2::ONE))
+            .collect();
+        self.points.sort_by(|a, b| a.x.total_cmp(&b.x));
+        self.handles_dirty = true;
+    }
+
+    /// Interpolation mode
+    pub const fn interpolation(&self) -> CurveInterpolation {
+        self.interpolation
+    }
+
+    /// Sets the interpolation mode
+    pub const fn set_interpolation(&mut self, interpolation: CurveInterpolation) {
+        self.interpolation = interpolation;
+    }
+
+    /// Value of the curve at `x`, the curve is flat outside of its points
+    pub fn sample(&self, x: f32) -> f32 {
+        let (Some(first), Some(last)) = (self.points.first(), self.points.last()) else {
+            return 0.;
+        };
+        if x <= first.x {
+            return first.y;
+        }
+        if x >= last.x {
+            return last.y;
+        }
+        let index = self.points.partition_point(|point| point.x <= x);
+        let (start, end) = (self.points[index - 1], self.points[index]);
+        let span = end.x - start.x;
+        if span <= f32::EPSILON {
+            return end.y;
+        }
+        let t = (x - start.x) / span;
+        match self.interpolation {
+            CurveInterpolation::Constant => start.y,
+            CurveInterpolation::Linear => start.y.lerp(end.y, t),
+            CurveInterpolation::Smooth => start.y.lerp(end.y, t * t * 2f32.mul_add(-t, 3.)),
+        }
+    }
+
+    fn insert_point(&mut self, point: Vec2) -> bool {
+        if self.points.len() >= MAX_CURVE_POINTS {
+            return false;
+        }
+        let index = self
+            .points
+            .partition_point(|existing| existing.x <= point.x);
+        self.points
+            .insert(index, point.clamp(Vec2::ZERO, Vec2::ONE));
+        self.handles_dirty = true;
+        true
+    }
+
+    fn remove_point(&mut self, index: usize) -> bool {
+        if self.points.len() <= 2 || index >= self.points.len() {
+            return false;
+        }
+        self.points.remove(index);
+        self.handles_dirty = true;
+        true
+    }
+
+    /// Moves a point, keeping it between its neighbors so the points stay sorted
+    fn move_point(&mut self, index: usize, point: Vec2) {
+        let min_x = index
+            .checked_sub(1)
+            .and_then(|previous| self.points.get(previous))
+            .map_or(0., |previous| previous.x);
+        let max_x = self.points.get(index + 1).map_or(1., |next| next.x);
+        if let Some(current) = self.points.get_mut(index) {
+            *current = Vec2::new(point.x.clamp(min_x, max_x), point.y.clamp(0., 1.));
+        }
+    }
+}
+
+/// Draggable control point of a [`CurveEditor`]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[require(Node)]
+pub struct CurveHandle {
+    editor: Entity,
+    index: usize,
+}
+
+impl CurveHandle {
+    /// Index of the point in [`CurveEditor::points`]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// Event sent when a curve is edited
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct CurveChangedEvent {
+    /// Entity of the [`CurveEditor`]
+    pub entity: Entity,
+    /// Control points of the curve
+    pub points: Vec<Vec2>,
+    /// Interpolation mode of the curve
+    pub interpolation: CurveInterpolation,
+}
+
+/// Color of a [`GradientEditor`] at a position
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradientStop {
+    /// Position in `[0, 1]`
+    pub position: f32,
+    /// Color at the position
+    pub color: Color,
+}
+
+impl GradientStop {
+    /// Creates a stop of `color` at `position`
+    pub const fn new(position: f32, color: Color) -> Self {
+        Self { position, color }
+    }
+}
+
+/// Editable color gradient, e.g. a particle color over lifetime.
+///
+/// Drag a marker to move its stop, click the gradient to add a stop and right click a marker to remove it.
+#[derive(Component, Debug, Clone)]
+#[require(Node)]
+pub struct GradientEditor {
+    stops: Vec<GradientStop>,
+    bar: Option<Entity>,
+    track: Option<Entity>,
+    markers_dirty: bool,
+}
+
+impl Default for GradientEditor {
+    fn default() -> Self {
+        Self::new([
+            GradientStop::new(0., Color::BLACK),
+            GradientStop::new(1., Color::WHITE),
+        ])
+    }
+}
+
+impl GradientEditor {
+    /// Creates an editor of the gradient going through `stops`
+    pub fn new(stops: impl IntoIterator<Item = GradientStop>) -> Self {
+        let mut editor = Self {
+            stops: Vec::new(),
+            bar: None,
+            track: None,
+            markers_dirty: true,
+        };
+        editor.set_stops(stops);
+        editor
+    }
+
+    /// Stops sorted by position
+    pub fn stops(&self) -> &[GradientStop] {
+        &self.stops
+    }
+
+    /// Replaces the stops, positions are clamped to `[0, 1]`
+    pub fn set_stops(&mut self, stops: impl IntoIterator<Item = GradientStop>) {
+        self.stops = stops
+            .into_iter()
+            .take(MAX_CURVE_POINTS)
+            .map(|stop| GradientStop::new(stop.position.clamp(0., 1.), stop.color))
+            .collect();
+        self.stops.sort_by(|a, b| a.position.total_cmp(&b.position));
+        self.markers_dirty = true;
+    }
+
+    /// Sets the color of the stop at `index`
+    pub fn set_color(&mut self, index: usize, color: Color) {
+        if let Some(stop) = self.stops.get_mut(index) {
+            stop.color = color;
+        }
+    }
+
+    /// Color of the gradient at `position`, interpolated in linear space
+    pub fn sample(&self, position: f32) -> Color {
+        let (Some(first), Some(last)) = (self.stops.first(), self.stops.last()) else {
+            return Color::NONE;
+        };
+        if position <= first.position {
+            return first.color;
+        }
+        if position >= last.position {
+            return last.color;
+        }
+        let index = self.stops.partition_point(|stop| stop.position <= position);
+        let (start, end) = (self.stops[index - 1], self.stops[index]);
+        let span = end.position - start.position;
+        if span <= f32::EPSILON {
+            return end.color;
+        }
+        start
+            .color
+            .to_linear()
+            .mix(&end.color.to_linear(), (position - start.position) / span)
+            .into()
+    }
+
+    fn insert_stop(&mut self, position: f32) -> bool {
+        if self.stops.len() >= MAX_CURVE_POINTS {
+            return false;
+        }
+        let position = position.clamp(0., 1.);
+        let color = self.sample(position);
+        let index = self.stops.partition_point(|stop| stop.position <= position);
+        self.stops.insert(index, GradientStop::new(position, color));
+        self.markers_dirty = true;
+        true
+    }
+
+    fn remove_stop(&mut self, index: usize) -> bool {
+        if self.stops.len() <= 2 || index >= self.stops.len() {
+            return false;
+        }
+        self.stops.remove(index);
+        self.markers_dirty = true;
+        true
+    }
+
+    /// Moves a stop, keeping it between its neighbors so the stops stay sorted
+    fn move_stop(&mut self, index: usize, position: f32) {
+        let min = index
+            .checked_sub(1)
+            .and_then(|previous| self.stops.get(previous))
+            .map_or(0., |previous| previous.position);
+        let max = self.stops.get(index + 1).map_or(1., |next| next.position);
+        if let Some(stop) = self.stops.get_mut(index) {
+            stop.position = position.clamp(min, max);
+        }
+    }
+}
+
+/// Draggable stop marker of a [`GradientEditor`]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[require(Node)]
+pub struct GradientMarker {
+    editor: Entity,
+    index: usize,
+}
+
+impl GradientMarker {
+    /// Index of the stop in [`GradientEditor::stops`]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// Event sent when a gradient is edited
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct GradientChangedEvent {
+    /// Entity of the [`GradientEditor`]
+    pub entity: Entity,
+    /// Stops of the gradient
+    pub stops: Vec<GradientStop>,
+}
@@ -0,0 +1,540 @@
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+
+use crate::toolbar::{ToolbarBuilder, ToolbarButtonBuilder, ToolbarButtonClickedEvent};
+
+use super::{
+    CurveChangedEvent, CurveEditor, CurveHandle, CurveInterpolation, GradientChangedEvent,
+    GradientEditor, GradientMarker, MAX_CURVE_POINTS,
+};
+
+const AREA_BACKGROUND_COLOR: Color = Color::srgb(0.08, 0.08, 0.09);
+const CURVE_COLOR: Color = Color::srgb(0.51, 0.79, 1.);
+const HANDLE_COLOR: Color = Color::srgb(0.93, 0.97, 1.0);
+const HANDLE_BORDER_COLOR: Color = Color::srgb(0.19, 0.49, 0.71);
+const MARKER_BORDER_COLOR: Color = Color::srgb(0.93, 0.97, 1.0);
+const CURVE_HEIGHT: f32 = 120.0;
+const CURVE_THICKNESS: f32 = 1.5;
+const HANDLE_SIZE: f32 = 8.0;
+const GRADIENT_HEIGHT: f32 = 20.0;
+const MARKER_SIZE: f32 = 10.0;
+const GRADIENT_SLICES: usize = 64;
+const SMOOTH_SEGMENTS: usize = 64;
+/// Constant curves need two segments per point plus the trailing one.
+const CURVE_SEGMENTS: usize = 2 * MAX_CURVE_POINTS + 1;
+
+#[derive(Component, Debug)]
+pub(super) struct CurveArea {
+    editor: Entity,
+}
+
+#[derive(Component, Debug)]
+pub(super) struct CurveSegment;
+
+#[derive(Component, Debug)]
+pub(super) struct GradientBar {
+    editor: Entity,
+}
+
+#[derive(Component, Debug)]
+pub(super) struct GradientSlice;
+
+fn interpolation_id(interpolation: CurveInterpolation) -> String {
+    format!("curve.interpolation.{}", interpolation.label())
+}
+
+fn curve_changed(entity: Entity, editor: &CurveEditor) -> CurveChangedEvent {
+    CurveChangedEvent {
+        entity,
+        points: editor.points.clone(),
+        interpolation: editor.interpolation,
+    }
+}
+
+fn gradient_changed(entity: Entity, editor: &GradientEditor) -> GradientChangedEvent {
+    GradientChangedEvent {
+        entity,
+        stops: editor.stops.clone(),
+    }
+}
+
+pub(super) fn create_curve_editor(
+    trigger: Trigger<OnAdd, CurveEditor>,
+    mut commands: Commands,
+    mut editors: Query<(&mut CurveEditor, &mut Node)>,
+) {
+    let entity = trigger.entity();
+    let Ok((mut editor, mut node)) = editors.get_mut(entity) else {
+        return;
+    };
+    node.flex_direction = FlexDirection::Column;
+
+    let toolbar = commands.spawn(ToolbarBuilder::default().build()).id();
+    for interpolation in CurveInterpolation::ALL {
+        ToolbarButtonBuilder::new(interpolation_id(interpolation))
+            .with_label(interpolation.label())
+            .in_group("curve.interpolation")
+            .pressed(editor.interpolation == interpolation)
+            .build(&mut commands, toolbar);
+    }
+
+    let area = commands
+        .spawn((
+            CurveArea { editor: entity },
+            Name::new("CurveArea"),
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Px(CURVE_HEIGHT),
+                ..default()
+            },
+            BackgroundColor(AREA_BACKGROUND_COLOR),
+            RelativeCursorPosition::default(),
+        ))
+        .with_children(|parent| {
+            for _ in 0..CURVE_SEGMENTS {
+                parent.spawn((
+                    CurveSegment,
+                    Node {
+                        position_type: PositionType::Absolute,
+                        height: Val::Px(CURVE_THICKNESS),
+                        ..default()
+                    },
+                    BackgroundColor(CURVE_COLOR),
+                    Visibility::Hidden,
+                    PickingBehavior::IGNORE,
+                ));
+            }
+        })
+        .id();
+
+    commands.entity(entity).add_children(&[toolbar, area]);
+    editor.area = Some(area);
+    editor.handles_dirty = true;
+}
+
+pub(super) fn on_interpolation_click(
+    mut events: EventReader<ToolbarButtonClickedEvent>,
+    parents: Query<&Parent>,
+    mut editors: Query<&mut CurveEditor>,
+    mut event_writer: EventWriter<CurveChangedEvent>,
+) {
+    for event in events.read() {
+        let Some(interpolation) = CurveInterpolation::ALL
+            .into_iter()
+            .find(|interpolation| interpolation_id(*interpolation) == event.id)
+        else {
+            continue;
+        };
+        let Some(editor_entity) = parents
+            .iter_ancestors(event.entity)
+            .find(|ancestor| editors.contains(*ancestor))
+        else {
+            continue;
+        };
+        let Ok(mut editor) = editors.get_mut(editor_entity) else {
+            continue;
+        };
+        if editor.interpolation != interpolation {
+            editor.set_interpolation(interpolation);
+            event_writer.send(curve_changed(editor_entity, &editor));
+        }
+    }
+}
+
+pub(super) fn on_curve_area_click(
+    trigger: Trigger<Pointer<Click>>,
+    areas: Query<(&CurveArea, &RelativeCursorPosition)>,
+    mut editors: Query<&mut CurveEditor>,
+    mut event_writer: EventWriter<CurveChangedEvent>,
+) {
+    if trigger.button != PointerButton::Primary {
+        return;
+    }
+    let Ok((area, cursor)) = areas.get(trigger.entity()) else {
+        return;
+    };
+    let Some(position) = cursor.normalized else {
+        return;
+    };
+    let Ok(mut editor) = editors.get_mut(area.editor) else {
+        return;
+    };
+    if editor.insert_point(Vec2::new(position.x, 1. - position.y)) {
+        event_writer.send(curve_changed(area.editor, &editor));
+    }
+}
+
+pub(super) fn on_curve_handle_click(
+    mut trigger: Trigger<Pointer<Click>>,
+    handles: Query<&CurveHandle>,
+    mut editors: Query<&mut CurveEditor>,
+    mut event_writer: EventWriter<CurveChangedEvent>,
+) {
+    let Ok(handle) = handles.get(trigger.entity()) else {
+        return;
+    };
+    trigger.propagate(false);
+    if trigger.button != PointerButton::Secondary {
+        return;
+    }
+    let Ok(mut editor) = editors.get_mut(handle.editor) else {
+        return;
+    };
+    if editor.remove_point(handle.index) {
+        event_writer.send(curve_changed(handle.editor, &editor));
+    }
+}
+
+pub(super) fn on_curve_handle_drag(
+    mut trigger: Trigger<Pointer<Drag>>,
+    handles: Query<&CurveHandle>,
+    mut editors: Query<&mut CurveEditor>,
+    computed_nodes: Query<&ComputedNode>,
+    mut event_writer: EventWriter<CurveChangedEvent>,
+    ui_scale: Res<UiScale>,
+) {
+    let Ok(handle) = handles.get(trigger.entity()) else {
+        return;
+    };
+    trigger.propagate(false);
+    let Ok(mut editor) = editors.get_mut(handle.editor) else {
+        return;
+    };
+    let Some(computed) = editor.area.and_then(|area| computed_nodes.get(area).ok()) else {
+        return;
+    };
+    let size = computed.size() * computed.inverse_scale_factor();
+    if size.x <= 0. || size.y <= 0. {
+        return;
+    }
+    let Some(point) = editor.points.get(handle.index).copied() else {
+        return;
+    };
+    let delta = trigger.delta / ui_scale.0;
+    let delta = Vec2::new(delta.x / size.x, -delta.y / size.y);
+    editor.move_point(handle.index, point + delta);
+    event_writer.send(curve_changed(handle.editor, &editor));
+}
+
+pub(super) fn sync_curve_handles(
+    mut commands: Commands,
+    mut editors: Query<(Entity, &mut CurveEditor)>,
+    children: Query<&Children>,
+    handles: Query<(), With<CurveHandle>>,
+) {
+    for (entity, mut editor) in &mut editors {
+        if !editor.handles_dirty {
+            continue;
+        }
+        let Some(area) = editor.area else {
+            continue;
+        };
+        editor.handles_dirty = false;
+        for child in children.get(area).into_iter().flatten() {
+            if handles.contains(*child) {
+                commands.entity(*child).despawn_recursive();
+            }
+        }
+        commands.entity(area).with_children(|parent| {
+            for index in 0..editor.points.len() {
+                parent.spawn((
+                    CurveHandle {
+                        editor: entity,
+                        index,
+                    },
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: Val::Px(HANDLE_SIZE),
+                        height: Val::Px(HANDLE_SIZE),
+                        margin: UiRect::all(Val::Px(-HANDLE_SIZE / 2.)),
+                        border: UiRect::all(Val::Px(1.)),
+                        ..default()
+                    },
+                    BackgroundColor(HANDLE_COLOR),
+                    BorderColor(HANDLE_BORDER_COLOR),
+                    BorderRadius::MAX,
+                ));
+            }
+        });
+    }
+}
+
+/// Normalized polyline of the curve, `y` going up
+fn curve_polyline(editor: &CurveEditor) -> Vec<Vec2> {
+    let (Some(first), Some(last)) = (editor.points.first(), editor.points.last()) else {
+        return Vec::new();
+    };
+    match editor.interpolation {
+        CurveInterpolation::Constant => {
+            let mut y = first.y;
+            let mut polyline = vec![Vec2::new(0., y)];
+            for point in &editor.points {
+                polyline.push(Vec2::new(point.x, y));
+                y = point.y;
+                polyline.push(Vec2::new(point.x, y));
+            }
+            polyline.push(Vec2::new(1., y));
+            polyline
+        }
+        CurveInterpolation::Linear => std::iter::once(Vec2::new(0., first.y))
+            .chain(editor.points.iter().copied())
+            .chain(std::iter::once(Vec2::new(1., last.y)))
+            .collect(),
+        CurveInterpolation::Smooth => (0..=SMOOTH_SEGMENTS)
+            .map(|index| {
+                let x = index as f32 / SMOOTH_SEGMENTS as f32;
+                Vec2::new(x, editor.sample(x))
+            })
+            .collect(),
+    }
+}
+
+pub(super) fn draw_curves(
+    editors: Query<Ref<CurveEditor>>,
+    areas: Query<(&CurveArea, Ref<ComputedNode>, &Children)>,
+    mut segments: Query<(&mut Node, &mut Transform, &mut Visibility), With<CurveSegment>>,
+    mut handles: Query<(&CurveHandle, &mut Node), Without<CurveSegment>>,
+) {
+    for (area, computed, children) in &areas {
+        let Ok(editor) = editors.get(area.editor) else {
+            continue;
+        };
+        if !editor.is_changed() && !computed.is_changed() {
+            continue;
+        }
+        let size = computed.size() * computed.inverse_scale_factor();
+        let points: Vec<Vec2> = curve_polyline(&editor)
+            .into_iter()
+            .map(|point| Vec2::new(point.x * size.x, (1. - point.y) * size.y))
+            .collect();
+
+        let mut iter = segments.iter_many_mut(children);
+        let mut index = 0;
+        while let Some((mut node, mut transform, mut visibility)) = iter.fetch_next() {
+            let (start, end) = (points.get(index), points.get(index + 1));
+            index += 1;
+            let (Some(start), Some(end)) = (start, end) else {
+                *visibility = Visibility::Hidden;
+                continue;
+            };
+            let delta = *end - *start;
+            let length = delta.length();
+            let center = (*start + *end) / 2.;
+            node.width = Val::Px(length);
+            node.left = Val::Px(center.x - length / 2.);
+            node.top = Val::Px(center.y - CURVE_THICKNESS / 2.);
+            transform.rotation = Quat::from_rotation_z(delta.y.atan2(delta.x));
+            *visibility = Visibility::Inherited;
+        }
+
+        let mut iter = handles.iter_many_mut(children);
+        while let Some((handle, mut node)) = iter.fetch_next() {
+            if let Some(point) = editor.points.get(handle.index) {
+                node.left = Val::Percent(point.x * 100.);
+                node.top = Val::Percent((1. - point.y) * 100.);
+            }
+        }
+    }
+}
+
+pub(super) fn create_gradient_editor(
+    trigger: Trigger<OnAdd, GradientEditor>,
+    mut commands: Commands,
+    mut editors: Query<(&mut GradientEditor, &mut Node)>,
+) {
+    let entity = trigger.entity();
+    let Ok((mut editor, mut node)) = editors.get_mut(entity) else {
+        return;
+    };
+    node.flex_direction = FlexDirection::Column;
+    node.row_gap = Val::Px(2.);
+
+    let bar = commands
+        .spawn((
+            GradientBar { editor: entity },
+            Name::new("GradientBar"),
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Px(GRADIENT_HEIGHT),
+                ..default()
+            },
+            RelativeCursorPosition::default(),
+        ))
+        .with_children(|parent| {
+            for slice in 0..GRADIENT_SLICES {
+                let position = (slice as f32 + 0.5) / GRADIENT_SLICES as f32;
+                parent.spawn((
+                    GradientSlice,
+                    Node {
+                        flex_grow: 1.,
+                        height: Val::Percent(100.),
+                        ..default()
+                    },
+                    BackgroundColor(editor.sample(position)),
+                    PickingBehavior::IGNORE,
+                ));
+            }
+        })
+        .id();
+    let track = commands
+        .spawn((
+            Name::new("GradientTrack"),
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Px(MARKER_SIZE),
+                ..default()
+            },
+        ))
+        .id();
+
+    commands.entity(entity).add_children(&[bar, track]);
+    editor.bar = Some(bar);
+    editor.track = Some(track);
+    editor.markers_dirty = true;
+}
+
+pub(super) fn on_gradient_bar_click(
+    trigger: Trigger<Pointer<Click>>,
+    bars: Query<(&GradientBar, &RelativeCursorPosition)>,
+    mut editors: Query<&mut GradientEditor>,
+    mut event_writer: EventWriter<GradientChangedEvent>,
+) {
+    if trigger.button != PointerButton::Primary {
+        return;
+    }
+    let Ok((bar, cursor)) = bars.get(trigger.entity()) else {
+        return;
+    };
+    let Some(position) = cursor.normalized else {
+        return;
+    };
+    let Ok(mut editor) = editors.get_mut(bar.editor) else {
+        return;
+    };
+    if editor.insert_stop(position.x) {
+        event_writer.send(gradient_changed(bar.editor, &editor));
+    }
+}
+
+pub(super) fn on_gradient_marker_click(
+    mut trigger: Trigger<Pointer<Click>>,
+    markers: Query<&GradientMarker>,
+    mut editors: Query<&mut GradientEditor>,
+    mut event_writer: EventWriter<GradientChangedEvent>,
+) {
+    let Ok(marker) = markers.get(trigger.entity()) else {
+        return;
+    };
+    trigger.propagate(false);
+    if trigger.button != PointerButton::Secondary {
+        return;
+    }
+    let Ok(mut editor) = editors.get_mut(marker.editor) else {
+        return;
+    };
+    if editor.remove_stop(marker.index) {
+        event_writer.send(gradient_changed(marker.editor, &editor));
+    }
+}
+
+pub(super) fn on_gradient_marker_drag(
+    mut trigger: Trigger<Pointer<Drag>>,
+    markers: Query<&GradientMarker>,
+    mut editors: Query<&mut GradientEditor>,
+    computed_nodes: Query<&ComputedNode>,
+    mut event_writer: EventWriter<GradientChangedEvent>,
+    ui_scale: Res<UiScale>,
+) {
+    let Ok(marker) = markers.get(trigger.entity()) else {
+        return;
+    };
+    trigger.propagate(false);
+    let Ok(mut editor) = editors.get_mut(marker.editor) else {
+        return;
+    };
+    let Some(computed) = editor
+        .track
+        .and_then(|track| computed_nodes.get(track).ok())
+    else {
+        return;
+    };
+    let width = computed.size().x * computed.inverse_scale_factor();
+    if width <= 0. {
+        return;
+    }
+    let Some(stop) = editor.stops.get(marker.index).copied() else {
+        return;
+    };
+    editor.move_stop(
+        marker.index,
+        stop.position + trigger.delta.x / ui_scale.0 / width,
+    );
+    event_writer.send(gradient_changed(marker.editor, &editor));
+}
+
+pub(super) fn sync_gradient_markers(
+    mut commands: Commands,
+    mut editors: Query<(Entity, &mut GradientEditor)>,
+) {
+    for (entity, mut editor) in &mut editors {
+        if !editor.markers_dirty {
+            continue;
+        }
+        let Some(track) = editor.track else {
+            continue;
+        };
+        editor.markers_dirty = false;
+        commands
+            .entity(track)
+            .despawn_descendants()
+            .with_children(|parent| {
+                for (index, stop) in editor.stops.iter().enumerate() {
+                    parent.spawn((
+                        GradientMarker {
+                            editor: entity,
+                            index,
+                        },
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Percent(stop.position * 100.),
+                            width: Val::Px(MARKER_SIZE),
+                            height: Val::Px(MARKER_SIZE),
+                            margin: UiRect::left(Val::Px(-MARKER_SIZE / 2.)),
+                            border: UiRect::all(Val::Px(1.)),
+                            ..default()
+                        },
+                        BackgroundColor(stop.color),
+                        BorderColor(MARKER_BORDER_COLOR),
+                    ));
+                }
+            });
+    }
+}
+
+pub(super) fn update_gradients(
+    editors: Query<&GradientEditor, Changed<GradientEditor>>,
+    children: Query<&Children>,
+    mut slices: Query<&mut BackgroundColor, With<GradientSlice>>,
+    mut markers: Query<(&GradientMarker, &mut Node, &mut BackgroundColor), Without<GradientSlice>>,
+) {
+    for editor in &editors {
+        if let Some(bar_children) = editor.bar.and_then(|bar| children.get(bar).ok()) {
+            let mut iter = slices.iter_many_mut(bar_children);
+            let mut slice = 0;
+            while let Some(mut background) = iter.fetch_next() {
+                let position = (slice as f32 + 0.5) / GRADIENT_SLICES as f32;
+                background.0 = editor.sample(position);
+                slice += 1;
+            }
+        }
+        if let Some(track_children) = editor.track.and_then(|track| children.get(track).ok()) {
+            let mut iter = markers.iter_many_mut(track_children);
+            while let Some((marker, mut node, mut background)) = iter.fetch_next() {
+                if let Some(stop) = editor.stops.get(marker.index) {
+                    node.left = Val::Percent(stop.position * 100.);
+                    background.0 = stop.color;
+                }
+            }
+        }
+    }
+}
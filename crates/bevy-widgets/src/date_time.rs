@@ -0,0 +1,906 @@
+use std::fmt;
+
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+use crate::{
+    focus::{Clickable, Focus},
+    overlay::{OverlayPlugin, PopupAnchor, UiLayer},
+    WidgetsSystems,
+};
+
+const FIELD_BACKGROUND_COLOR: Color = Color::srgb(0.93, 0.97, 1.0);
+const FIELD_BORDER_COLOR: Color = Color::srgb(0.8, 0.83, 0.85);
+const TEXT_COLOR: Color = Color::srgb(0.29, 0.31, 0.33);
+const SEPARATOR_COLOR: Color = Color::srgb(0.49, 0.53, 0.55);
+const SELECTED_SEGMENT_COLOR: Color = Color::srgb(0.51, 0.79, 1.);
+const POPUP_BACKGROUND_COLOR: Color = Color::srgb(0.13, 0.13, 0.14);
+const POPUP_TEXT_COLOR: Color = Color::srgb(0.97, 0.97, 0.98);
+const HOVERED_DAY_COLOR: Color = Color::srgb(0.11, 0.29, 0.42);
+const SELECTED_DAY_COLOR: Color = Color::srgb(0.19, 0.49, 0.71);
+const FONT_SIZE: f32 = 13.0;
+const POPUP_FONT_SIZE: f32 = 10.0;
+const DAY_CELL_WIDTH: f32 = 22.0;
+const DAY_CELL_HEIGHT: f32 = 18.0;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// Plugin containing the date/time field logic
+pub struct DateTimePlugin;
+
+impl Plugin for DateTimePlugin {
+    fn build(&self, app: &mut App) {
+        WidgetsSystems::configure(app);
+        OverlayPlugin::configure(app);
+        app.add_event::<DateTimeChangedEvent>()
+            .add_observer(create_date_time_field)
+            .add_observer(on_segment_click)
+            .add_observer(on_calendar_button_click)
+            .add_observer(on_calendar_nav_click)
+            .add_observer(on_calendar_day_click)
+            .add_observer(on_add_focus)
+            .add_observer(on_remove_focus)
+            .add_systems(
+                Update,
+                (
+                    keyboard.in_set(WidgetsSystems::Input),
+                    (update_date_time_fields, calendar_day_hover).in_set(WidgetsSystems::Style),
+                ),
+            );
+    }
+}
+
+/// Checks if `year` has a 29th of February
+pub const fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Number of days of `month` (`1` to `12`) in `year`, `0` for invalid months
+pub const fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Day of the week of a date, `0` being Monday
+const fn weekday(year: i32, month: u8, day: u8) -> u8 {
+    const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let year = if month < 3 { year - 1 } else { year };
+    let sunday_based =
+        (year + year / 4 - year / 100 + year / 400 + OFFSETS[month as usize - 1] + day as i32)
+            .rem_euclid(7);
+    ((sunday_based + 6) % 7) as u8
+}
+
+/// A calendar date with a time of day, precise to the minute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect)]
+pub struct DateTime {
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+}
+
+impl Default for DateTime {
+    fn default() -> Self {
+        Self {
+            year: 2000,
+            month: 1,
+            day: 1,
+            hour: 0,
+            minute: 0,
+        }
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute
+        )
+    }
+}
+
+impl DateTime {
+    /// Creates a date time, `None` if it is not a real calendar date between years `1` and `9999`
+    pub const fn new(year: i32, month: u8, day: u8, hour: u8, minute: u8) -> Option<Self> {
+        if year < 1 || year > 9999 || month < 1 || month > 12 {
+            return None;
+        }
+        if day < 1 || day > days_in_month(year, month) || hour > 23 || minute > 59 {
+            return None;
+        }
+        Some(Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+        })
+    }
+
+    /// Year, from `1` to `9999`
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Month, from `1` to `12`
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Day of the month, from `1`
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Hour, from `0` to `23`
+    pub const fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Minute, from `0` to `59`
+    pub const fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Value of `segment`
+    pub const fn get(&self, segment: DateTimeSegment) -> i32 {
+        match segment {
+            DateTimeSegment::Year => self.year,
+            DateTimeSegment::Month => self.month as i32,
+            DateTimeSegment::Day => self.day as i32,
+            DateTimeSegment::Hour => self.hour as i32,
+            DateTimeSegment::Minute => self.minute as i32,
+        }
+    }
+
+    /// Replaces the value of `segment`, `None` if the result is not a real date.
+    ///
+    /// Changing the year or month clamps the day to the length of the month.
+    pub fn with(self, segment: DateTimeSegment, value: i32) -> Option<Self> {
+        let narrow = |value: i32| u8::try_from(value).ok();
+        let Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+        } = self;
+        match segment {
+            DateTimeSegment::Year => Self::new(
+                value,
+                month,
+                day.min(days_in_month(value, month)),
+                hour,
+                minute,
+            ),
+            DateTimeSegment::Month => {
+                let month = narrow(value)?;
+                Self::new(
+                    year,
+                    month,
+                    day.min(days_in_month(year, month)),
+                    hour,
+                    minute,
+                )
+            }
+            DateTimeSegment::Day => Self::new(year, month, narrow(value)?, hour, minute),
+            DateTimeSegment::Hour => Self::new(year, month, day, narrow(value)?, minute),
+            DateTimeSegment::Minute => Self::new(year, month, day, hour, narrow(value)?),
+        }
+    }
+
+    /// Adds `delta` to `segment`, wrapping around within the segment.
+    ///
+    /// Years are clamped, and the day is clamped to the length of the resulting month.
+    pub fn step(self, segment: DateTimeSegment, delta: i32) -> Self {
+        let (min, max) = segment.bounds(self);
+        let value = if segment == DateTimeSegment::Year {
+            (self.year + delta).clamp(min, max)
+        } else {
+            (self.get(segment) - min + delta).rem_euclid(max - min + 1) + min
+        };
+        let mut stepped = self;
+        match segment {
+            DateTimeSegment::Year => stepped.year = value,
+            DateTimeSegment::Month => stepped.month = value as u8,
+            DateTimeSegment::Day => stepped.day = value as u8,
+            DateTimeSegment::Hour => stepped.hour = value as u8,
+            DateTimeSegment::Minute => stepped.minute = value as u8,
+        }
+        stepped.day = stepped.day.min(days_in_month(stepped.year, stepped.month));
+        stepped
+    }
+}
+
+/// Editable part of a [`DateTimeField`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum DateTimeSegment {
+    /// Four digit year
+    Year,
+    /// Month of the year
+    Month,
+    /// Day of the month
+    Day,
+    /// Hour of the day
+    Hour,
+    /// Minute of the hour
+    Minute,
+}
+
+impl DateTimeSegment {
+    const ALL: [Self; 5] = [Self::Year, Self::Month, Self::Day, Self::Hour, Self::Minute];
+
+    const fn digits(self) -> usize {
+        match self {
+            Self::Year => 4,
+            _ => 2,
+        }
+    }
+
+    const fn bounds(self, value: DateTime) -> (i32, i32) {
+        match self {
+            Self::Year => (1, 9999),
+            Self::Month => (1, 12),
+            Self::Day => (1, days_in_month(value.year, value.month) as i32),
+            Self::Hour => (0, 23),
+            Self::Minute => (0, 59),
+        }
+    }
+
+    /// Separator displayed before the segment
+    const fn separator(self) -> &'static str {
+        match self {
+            Self::Year => "",
+            Self::Month | Self::Day => "-",
+            Self::Hour => " ",
+            Self::Minute => ":",
+        }
+    }
+
+    fn next(self) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .skip_while(|segment| *segment != self)
+            .nth(1)
+    }
+
+    fn previous(self) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .rev()
+            .skip_while(|segment| *segment != self)
+            .nth(1)
+    }
+}
+
+/// Date and time field edited one segment at a time.
+///
+/// Click a segment or focus the field, then type digits or use the up/down arrows to change it
+/// and the left/right arrows to move between segments. Only real calendar dates are accepted.
+#[derive(Component, Debug, Clone, Default)]
+#[require(Node, Clickable)]
+pub struct DateTimeField {
+    value: DateTime,
+    selected: Option<DateTimeSegment>,
+    typed: String,
+    calendar: bool,
+    popup: Option<Entity>,
+}
+
+impl DateTimeField {
+    /// Creates a field editing `value`
+    pub fn new(value: DateTime) -> Self {
+        Self { value, ..default() }
+    }
+
+    /// Adds a button opening a calendar to pick the date
+    pub const fn with_calendar(mut self) -> Self {
+        self.calendar = true;
+        self
+    }
+
+    /// Current value
+    pub const fn value(&self) -> DateTime {
+        self.value
+    }
+
+    /// Sets the current value
+    pub fn set_value(&mut self, value: DateTime) {
+        self.value = value;
+        self.typed.clear();
+    }
+
+    /// Segment edited by the keyboard, while focused
+    pub const fn selected(&self) -> Option<DateTimeSegment> {
+        self.selected
+    }
+
+    fn segment_text(&self, segment: DateTimeSegment) -> String {
+        if self.selected == Some(segment) && !self.typed.is_empty() {
+            format!("{:_<width$}", self.typed, width = segment.digits())
+        } else {
+            format!(
+                "{:0width$}",
+                self.value.get(segment),
+                width = segment.digits()
+            )
+        }
+    }
+}
+
+/// Event sent when the value of a [`DateTimeField`] is changed by the user
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateTimeChangedEvent {
+    /// Entity of the [`DateTimeField`]
+    pub entity: Entity,
+    /// New value
+    pub value: DateTime,
+}
+
+#[derive(Component, Debug)]
+struct DateTimeSegmentText {
+    field: Entity,
+    segment: DateTimeSegment,
+}
+
+#[derive(Component, Debug)]
+#[require(Interaction)]
+struct CalendarButton {
+    field: Entity,
+}
+
+/// Dropdown calendar of a [`DateTimeField`], showing a month
+#[derive(Component, Debug)]
+pub struct DateTimeCalendar {
+    field: Entity,
+    year: i32,
+    month: u8,
+}
+
+#[derive(Component, Debug)]
+#[require(Interaction)]
+struct CalendarNav {
+    calendar: Entity,
+    months: i32,
+}
+
+#[derive(Component, Debug)]
+#[require(Interaction)]
+struct CalendarDay {
+    calendar: Entity,
+    day: u8,
+}
+
+fn create_date_time_field(
+    trigger: Trigger<OnAdd, DateTimeField>,
+    mut commands: Commands,
+    mut fields: Query<(&DateTimeField, &mut Node)>,
+) {
+    let entity = trigger.entity();
+    let Ok((field, mut node)) = fields.get_mut(entity) else {
+        return;
+    };
+    node.flex_direction = FlexDirection::Row;
+    node.align_items = AlignItems::Center;
+    node.padding = UiRect::axes(Val::Px(6.), Val::Px(2.));
+    node.border = UiRect::all(Val::Px(1.));
+
+    let text_font = TextFont {
+        font_size: FONT_SIZE,
+        ..default()
+    };
+    commands
+        .entity(entity)
+        .insert((
+            BackgroundColor(FIELD_BACKGROUND_COLOR),
+            BorderColor(FIELD_BORDER_COLOR),
+            BorderRadius::all(Val::Px(4.)),
+        ))
+        .with_children(|parent| {
+            for segment in DateTimeSegment::ALL {
+                if !segment.separator().is_empty() {
+                    parent.spawn((
+                        Text::new(segment.separator()),
+                        TextColor(SEPARATOR_COLOR),
+                        text_font.clone(),
+                        PickingBehavior::IGNORE,
+                    ));
+                }
+                parent.spawn((
+                    DateTimeSegmentText {
+                        field: entity,
+                        segment,
+                    },
+                    Text::new(field.segment_text(segment)),
+                    TextColor(TEXT_COLOR),
+                    text_font.clone(),
+                    BackgroundColor(Color::NONE),
+                ));
+            }
+            if field.calendar {
+                parent.spawn((
+                    CalendarButton { field: entity },
+                    Node {
+                        margin: UiRect::left(Val::Px(6.)),
+                        ..default()
+                    },
+                    Text::new("v"),
+                    TextColor(SEPARATOR_COLOR),
+                    text_font,
+                ));
+            }
+        });
+}
+
+fn on_add_focus(trigger: Trigger<OnAdd, Focus>, mut fields: Query<&mut DateTimeField>) {
+    if let Ok(mut field) = fields.get_mut(trigger.entity()) {
+        if field.selected.is_none() {
+            field.selected = Some(DateTimeSegment::Year);
+        }
+    }
+}
+
+fn on_remove_focus(trigger: Trigger<OnRemove, Focus>, mut fields: Query<&mut DateTimeField>) {
+    if let Ok(mut field) = fields.get_mut(trigger.entity()) {
+        field.selected = None;
+        field.typed.clear();
+    }
+}
+
+fn on_segment_click(
+    trigger: Trigger<Pointer<Click>>,
+    segments: Query<&DateTimeSegmentText>,
+    mut fields: Query<&mut DateTimeField>,
+) {
+    // Propagates to the field so it gets focused.
+    let Ok(segment) = segments.get(trigger.entity()) else {
+        return;
+    };
+    if let Ok(mut field) = fields.get_mut(segment.field) {
+        field.selected = Some(segment.segment);
+        field.typed.clear();
+    }
+}
+
+fn keyboard(
+    mut events: EventReader<KeyboardInput>,
+    mut fields: Query<(Entity, &mut DateTimeField), With<Focus>>,
+    mut event_writer: EventWriter<DateTimeChangedEvent>,
+) {
+    let events: Vec<&KeyboardInput> = events
+        .read()
+        .filter(|event| event.state.is_pressed())
+        .collect();
+    if events.is_empty() {
+        return;
+    }
+    for (entity, mut field) in &mut fields {
+        let previous = field.value;
+        for event in &events {
+            let Some(segment) = field.selected else {
+                continue;
+            };
+            match &event.logical_key {
+                Key::ArrowUp => {
+                    field.value = field.value.step(segment, 1);
+                    field.typed.clear();
+                }
+                Key::ArrowDown => {
+                    field.value = field.value.step(segment, -1);
+                    field.typed.clear();
+                }
+                Key::ArrowLeft => {
+                    field.selected = Some(segment.previous().unwrap_or(segment));
+                    field.typed.clear();
+                }
+                Key::ArrowRight => {
+                    field.selected = Some(segment.next().unwrap_or(segment));
+                    field.typed.clear();
+                }
+                Key::Backspace => field.typed.clear(),
+                Key::Character(text) => {
+                    for digit in text.chars().filter(char::is_ascii_digit) {
+                        type_digit(&mut field, digit);
+                    }
+                }
+                _ => {}
+            }
+        }
+        if field.value != previous {
+            event_writer.send(DateTimeChangedEvent {
+                entity,
+                value: field.value,
+            });
+        }
+    }
+}
+
+/// Adds a digit to the selected segment, applying it once complete if it makes a real date
+fn type_digit(field: &mut DateTimeField, digit: char) {
+    let Some(segment) = field.selected else {
+        return;
+    };
+    field.typed.push(digit);
+    if field.typed.len() < segment.digits() {
+        return;
+    }
+    let typed = std::mem::take(&mut field.typed);
+    if let Some(value) = typed
+        .parse()
+        .ok()
+        .and_then(|value| field.value.with(segment, value))
+    {
+        field.value = value;
+        field.selected = Some(segment.next().unwrap_or(segment));
+    }
+}
+
+fn update_date_time_fields(
+    fields: Query<(&DateTimeField, Has<Focus>), Changed<DateTimeField>>,
+    mut segments: Query<(&DateTimeSegmentText, &mut Text, &mut BackgroundColor)>,
+) {
+    for (segment, mut text, mut background) in &mut segments {
+        let Ok((field, focused)) = fields.get(segment.field) else {
+            continue;
+        };
+        let content = field.segment_text(segment.segment);
+        if text.0 != content {
+            text.0 = content;
+        }
+        background.0 = if focused && field.selected == Some(segment.segment) {
+            SELECTED_SEGMENT_COLOR
+        } else {
+            Color::NONE
+        };
+    }
+}
+
+fn on_calendar_button_click(
+    mut trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    buttons: Query<&CalendarButton>,
+    mut fields: Query<&mut DateTimeField>,
+) {
+    let Ok(button) = buttons.get(trigger.entity()) else {
+        return;
+    };
+    trigger.propagate(false);
+    let Ok(mut field) = fields.get_mut(button.field) else {
+        return;
+    };
+    if let Some(popup) = field.popup.take() {
+        commands.entity(popup).despawn_recursive();
+        return;
+    }
+    let calendar = DateTimeCalendar {
+        field: button.field,
+        year: field.value.year,
+        month: field.value.month,
+    };
+    let popup = commands
+        .spawn((
+            Name::new("DateTimeCalendar"),
+            Node {
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(4.)),
+                margin: UiRect::top(Val::Px(2.)),
+                ..default()
+            },
+            BackgroundColor(POPUP_BACKGROUND_COLOR),
+            BorderRadius::all(Val::Px(4.)),
+            UiLayer::Popup,
+            PopupAnchor::below(button.field),
+        ))
+        .id();
+    spawn_calendar_month(&mut commands, popup, &calendar, field.value);
+    commands.entity(popup).insert(calendar);
+    field.popup = Some(popup);
+}
+
+fn spawn_calendar_month(
+    commands: &mut Commands,
+    popup: Entity,
+    calendar: &DateTimeCalendar,
+    value: DateTime,
+) {
+    let text_font = TextFont {
+        font_size: POPUP_FONT_SIZE,
+        ..default()
+    };
+    let cell = Node {
+        width: Val::Px(DAY_CELL_WIDTH),
+        height: Val::Px(DAY_CELL_HEIGHT),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    };
+    commands.entity(popup).with_children(|parent| {
+        parent
+            .spawn(Node {
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                margin: UiRect::bottom(Val::Px(4.)),
+                ..default()
+            })
+            .with_children(|header| {
+                for (label, months) in [("<", -1), ("", 0), (">", 1)] {
+                    if months == 0 {
+                        header.spawn((
+                            Text::new(format!(
+                                "{} {}",
+                                MONTH_NAMES[calendar.month as usize - 1],
+                                calendar.year
+                            )),
+                            TextColor(POPUP_TEXT_COLOR),
+                            text_font.clone(),
+                        ));
+                        continue;
+                    }
+                    header
+                        .spawn((
+                            CalendarNav {
+                                calendar: popup,
+                                months,
+                            },
+                            cell.clone(),
+                            BackgroundColor(Color::NONE),
+                            BorderRadius::all(Val::Px(2.)),
+                        ))
+                        .with_child((
+                            Text::new(label),
+                            TextColor(POPUP_TEXT_COLOR),
+                            text_font.clone(),
+                            PickingBehavior::IGNORE,
+                        ));
+                }
+            });
+
+        parent
+            .spawn(Node {
+                width: Val::Px(DAY_CELL_WIDTH * 7.),
+                flex_wrap: FlexWrap::Wrap,
+                ..default()
+            })
+            .with_children(|grid| {
+                for name in WEEKDAY_NAMES {
+                    grid.spawn(cell.clone()).with_child((
+                        Text::new(name),
+                        TextColor(SEPARATOR_COLOR),
+                        text_font.clone(),
+                    ));
+                }
+                for _ in 0..weekday(calendar.year, calendar.month, 1) {
+                    grid.spawn(cell.clone());
+                }
+                for day in 1..=days_in_month(calendar.year, calendar.month) {
+                    let selected = value.year == calendar.year
+                        && value.month == calendar.month
+                        && value.day == day;
+                    grid.spawn((
+                        CalendarDay {
+                            calendar: popup,
+                            day,
+                        },
+                        cell.clone(),
+                        BackgroundColor(if selected {
+                            SELECTED_DAY_COLOR
+                        } else {
+                            Color::NONE
+                        }),
+                        BorderRadius::all(Val::Px(2.)),
+                    ))
+                    .with_child((
+                        Text::new(day.to_string()),
+                        TextColor(POPUP_TEXT_COLOR),
+                        text_font.clone(),
+                        PickingBehavior::IGNORE,
+                    ));
+                }
+            });
+    });
+}
+
+fn on_calendar_nav_click(
+    mut trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    navs: Query<&CalendarNav>,
+    mut calendars: Query<&mut DateTimeCalendar>,
+    fields: Query<&DateTimeField>,
+) {
+    let Ok(nav) = navs.get(trigger.entity()) else {
+        return;
+    };
+    trigger.propagate(false);
+    let Ok(mut calendar) = calendars.get_mut(nav.calendar) else {
+        return;
+    };
+    let months = calendar.year * 12 + i32::from(calendar.month) - 1 + nav.months;
+    let year = months.div_euclid(12);
+    if !(1..=9999).contains(&year) {
+        return;
+    }
+    calendar.year = year;
+    calendar.month = months.rem_euclid(12) as u8 + 1;
+    let value = fields
+        .get(calendar.field)
+        .map(DateTimeField::value)
+        .unwrap_or_default();
+    commands.entity(nav.calendar).despawn_descendants();
+    spawn_calendar_month(&mut commands, nav.calendar, &calendar, value);
+}
+
+fn on_calendar_day_click(
+    mut trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    days: Query<&CalendarDay>,
+    calendars: Query<&DateTimeCalendar>,
+    mut fields: Query<&mut DateTimeField>,
+    mut event_writer: EventWriter<DateTimeChangedEvent>,
+) {
+    let Ok(day) = days.get(trigger.entity()) else {
+        return;
+    };
+    trigger.propagate(false);
+    let Ok(calendar) = calendars.get(day.calendar) else {
+        return;
+    };
+    let Ok(mut field) = fields.get_mut(calendar.field) else {
+        return;
+    };
+    let value = field.value;
+    if let Some(value) = DateTime::new(
+        calendar.year,
+        calendar.month,
+        day.day,
+        value.hour,
+        value.minute,
+    ) {
+        field.set_value(value);
+        event_writer.send(DateTimeChangedEvent {
+            entity: calendar.field,
+            value,
+        });
+    }
+    field.popup = None;
+    commands.entity(day.calendar).despawn_recursive();
+}
+
+fn calendar_day_hover(
+    mut cells: Query<
+        (&Interaction, &mut BackgroundColor, Option<&CalendarDay>),
+        (
+            Changed<Interaction>,
+            Or<(With<CalendarDay>, With<CalendarNav>)>,
+        ),
+    >,
+    calendars: Query<&DateTimeCalendar>,
+    fields: Query<&DateTimeField>,
+) {
+    for (interaction, mut background, day) in &mut cells {
+        let selected = day.is_some_and(|day| {
+            calendars
+                .get(day.calendar)
+                .ok()
+                .and_then(|calendar| {
+                    let value = fields.get(calendar.field).ok()?.value;
+                    Some(
+                        value.year == calendar.year
+                            && value.month == calendar.month
+                            && value.day == day.day,
+                    )
+                })
+                .unwrap_or(false)
+        });
+        background.0 = match (interaction, selected) {
+            (_, true) => SELECTED_DAY_COLOR,
+            (Interaction::None, false) => Color::NONE,
+            (_, false) => HOVERED_DAY_COLOR,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leap_centuries() {
+        assert!(is_leap_year(2000));
+        assert!(!is_leap_year(1900));
+        assert!(is_leap_year(2024));
+        assert_eq!(days_in_month(1900, 2), 28);
+        assert_eq!(days_in_month(2000, 2), 29);
+        assert_eq!(days_in_month(2000, 13), 0);
+    }
+
+    #[test]
+    fn weekday_of_known_dates() {
+        // Monday is 0
+        assert_eq!(weekday(2000, 1, 1), 5);
+        assert_eq!(weekday(1970, 1, 1), 3);
+        assert_eq!(weekday(2024, 2, 29), 3);
+        assert_eq!(weekday(1900, 3, 1), 3);
+    }
+
+    #[test]
+    fn day_is_clamped_when_the_month_shortens() {
+        let date = DateTime::new(2024, 1, 31, 12, 0).unwrap();
+        assert_eq!(
+            date.step(DateTimeSegment::Month, 1),
+            DateTime::new(2024, 2, 29, 12, 0).unwrap()
+        );
+        assert_eq!(
+            date.with(DateTimeSegment::Month, 4),
+            DateTime::new(2024, 4, 30, 12, 0)
+        );
+        let leap_day = DateTime::new(2024, 2, 29, 0, 0).unwrap();
+        assert_eq!(
+            leap_day.step(DateTimeSegment::Year, 1),
+            DateTime::new(2025, 2, 28, 0, 0).unwrap()
+        );
+        assert_eq!(leap_day.with(DateTimeSegment::Day, 30), None);
+    }
+
+    #[test]
+    fn step_wraps_within_the_segment() {
+        let date = DateTime::new(2024, 12, 31, 23, 59).unwrap();
+        let stepped = date.step(DateTimeSegment::Month, 1);
+        assert_eq!((stepped.year(), stepped.month()), (2024, 1));
+        assert_eq!(date.step(DateTimeSegment::Day, 1).day(), 1);
+        assert_eq!(date.step(DateTimeSegment::Minute, 1).minute(), 0);
+        let january = DateTime::new(2024, 1, 1, 0, 0).unwrap();
+        assert_eq!(january.step(DateTimeSegment::Month, -1).month(), 12);
+        assert_eq!(january.step(DateTimeSegment::Hour, -1).hour(), 23);
+        // Years are clamped instead
+        let last = DateTime::new(9999, 6, 1, 0, 0).unwrap();
+        assert_eq!(last.step(DateTimeSegment::Year, 1).year(), 9999);
+    }
+
+    #[test]
+    fn typed_digits_apply_once_the_segment_is_complete() {
+        let mut field = DateTimeField::new(DateTime::new(2024, 3, 15, 0, 0).unwrap());
+        field.selected = Some(DateTimeSegment::Year);
+        for digit in "199".chars() {
+            type_digit(&mut field, digit);
+        }
+        assert_eq!(field.value().year(), 2024);
+        type_digit(&mut field, '9');
+        assert_eq!(field.value().year(), 1999);
+        assert_eq!(field.selected(), Some(DateTimeSegment::Month));
+    }
+
+    #[test]
+    fn typed_year_zero_is_rejected() {
+        let mut field = DateTimeField::new(DateTime::new(2024, 3, 15, 0, 0).unwrap());
+        field.selected = Some(DateTimeSegment::Year);
+        for digit in "0000".chars() {
+            type_digit(&mut field, digit);
+        }
+        assert_eq!(field.value().year(), 2024);
+        assert_eq!(field.selected(), Some(DateTimeSegment::Year));
+        assert!(field.typed.is_empty());
+    }
+}
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+use crate::focus::Clickable;
+
+/// Plugin keeping decorative children from swallowing pointer events
+pub struct DecorativePlugin;
+
+impl Plugin for DecorativePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Decorative>()
+            .register_type::<PickingTarget>()
+            .add_systems(PostUpdate, guard_decorative_children);
+    }
+}
+
+/// Marks an entity as purely visual, it never receives pointer events or blocks them.
+///
+/// Texts and images spawned inside interactive widgets (anything with an [`Interaction`] or [`Clickable`])
+/// are treated as decorative automatically, this marker covers other nodes such as icon containers.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+#[reflect(Component)]
+pub struct Decorative;
+
+/// Keeps a text or image inside an interactive widget pickable, opting it out of the decorative guard
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+#[reflect(Component)]
+pub struct PickingTarget;
+
+fn guard_decorative_children(
+    mut commands: Commands,
+    added: Query<
+        (Entity, Has<Decorative>),
+        (
+            Or<(Added<Text>, Added<ImageNode>, Added<Decorative>)>,
+            Without<Interaction>,
+            Without<Clickable>,
+            Without<PickingTarget>,
+        ),
+    >,
+    parents: Query<&Parent>,
+    interactive: Query<(), Or<(With<Interaction>, With<Clickable>)>>,
+) {
+    for (entity, decorative) in &added {
+        if decorative
+            || parents
+                .iter_ancestors(entity)
+                .any(|ancestor| interactive.contains(ancestor))
+        {
+            commands
+                .entity(entity)
+                .insert((PickingBehavior::IGNORE, FocusPolicy::Pass));
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt;
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+use crate::WidgetsSystems;
+
+/// Plugin containing the derived label logic
+pub struct DerivedLabelPlugin;
+
+impl Plugin for DerivedLabelPlugin {
+    fn build(&self, app: &mut App) {
+        WidgetsSystems::configure(app);
+        app.add_systems(Update, update_derived_labels.in_set(WidgetsSystems::Layout));
+    }
+}
+
+/// Read-only text displaying a value computed from the world, like a statistic.
+///
+/// The text is recomputed every frame, or every `interval` seconds with [`DerivedLabel::with_interval`],
+/// and only rewritten when the computed value changes.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::derived_label::DerivedLabel;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn(
+///         DerivedLabel::new(|world| format!("Entities: {}", world.entities().len()))
+///             .with_interval(0.5),
+///     );
+/// }
+/// ```
+#[derive(Component, Clone)]
+#[require(Text)]
+pub struct DerivedLabel {
+    reader: Arc<dyn Fn(&World) -> String + Send + Sync>,
+    interval: Option<Timer>,
+    /// Computes the first text without waiting for the interval
+    pending: bool,
+}
+
+impl DerivedLabel {
+    /// Creates a label displaying the text returned by `reader`, recomputed every frame
+    pub fn new(reader: impl Fn(&World) -> String + Send + Sync + 'static) -> Self {
+        Self {
+            reader: Arc::new(reader),
+            interval: None,
+            pending: true,
+        }
+    }
+
+    /// Recomputes the text every `seconds` instead of every frame
+    pub fn with_interval(mut self, seconds: f32) -> Self {
+        self.interval = Some(Timer::from_seconds(seconds, TimerMode::Repeating));
+        self
+    }
+
+    /// Recomputes the text on the next update
+    pub const fn refresh(&mut self) {
+        self.pending = true;
+    }
+}
+
+impl fmt::Debug for DerivedLabel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DerivedLabel")
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+fn update_derived_labels(world: &mut World) {
+    let delta = world.resource::<Time>().delta();
+    let mut labels = world.query::<(Entity, &mut DerivedLabel)>();
+    let due: Vec<_> = labels
+        .iter_mut(world)
+        .filter_map(|(entity, mut label)| {
+            let label = label.bypass_change_detection();
+            let elapsed = label
+                .interval
+                .as_mut()
+                .is_none_or(|interval| interval.tick(delta).just_finished());
+            let due = std::mem::take(&mut label.pending) || elapsed;
+            due.then(|| (entity, label.reader.clone()))
+        })
+        .collect();
+
+    let texts: Vec<_> = due
+        .into_iter()
+        .map(|(entity, reader)| (entity, reader(world)))
+        .collect();
+    for (entity, value) in texts {
+        if let Some(mut text) = world.get_mut::<Text>(entity) {
+            if text.0 != value {
+                text.0 = value;
+            }
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use bevy::diagnostic::{
+    DiagnosticPath, DiagnosticsStore, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin,
+};
+use bevy::prelude::*;
+
+use crate::sparkline::{Sparkline, SparklineBuilder};
+
+const PANEL_BACKGROUND_COLOR: Color = Color::srgba(0.08, 0.08, 0.09, 0.9);
+const LABEL_COLOR: Color = Color::srgb(0.49, 0.53, 0.55);
+const FIELD_BACKGROUND_COLOR: Color = Color::srgb(0.8, 0.83, 0.85);
+const FIELD_TEXT_COLOR: Color = Color::srgb(0.29, 0.31, 0.33);
+const FONT_SIZE: f32 = 10.0;
+const FIELD_WIDTH: f32 = 72.0;
+const SPARKLINE_WIDTH: f32 = 160.0;
+const SPARKLINE_HEIGHT: f32 = 32.0;
+
+/// Optional plugin showing live diagnostics in [`DiagnosticsPanel`]s.
+///
+/// Adds [`FrameTimeDiagnosticsPlugin`] and [`EntityCountDiagnosticsPlugin`] when missing.
+pub struct DiagnosticsPanelPlugin;
+
+impl Plugin for DiagnosticsPanelPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+            app.add_plugins(FrameTimeDiagnosticsPlugin);
+        }
+        if !app.is_plugin_added::<EntityCountDiagnosticsPlugin>() {
+            app.add_plugins(EntityCountDiagnosticsPlugin);
+        }
+        app.add_observer(create_diagnostics_panel)
+            .add_systems(Update, (update_diagnostic_fields, update_diagnostic_graphs));
+    }
+}
+
+/// Panel listing FPS, frame time and entity count, with a graph of the FPS.
+#[derive(Component, Debug, Clone, Copy, Default)]
+#[require(Node)]
+pub struct DiagnosticsPanel;
+
+/// Read-only field displaying the smoothed value of a diagnostic
+#[derive(Component, Debug, Clone)]
+pub struct DiagnosticField {
+    /// Diagnostic displayed
+    pub path: DiagnosticPath,
+    /// Number of decimals shown
+    pub precision: usize,
+}
+
+/// Graph of the latest values of a diagnostic
+#[derive(Component, Debug, Clone)]
+#[require(Sparkline)]
+pub struct DiagnosticGraph {
+    /// Diagnostic displayed
+    pub path: DiagnosticPath,
+}
+
+fn create_diagnostics_panel(
+    trigger: Trigger<OnAdd, DiagnosticsPanel>,
+    mut commands: Commands,
+    mut nodes: Query<&mut Node>,
+) {
+    let entity = trigger.entity();
+    if let Ok(mut node) = nodes.get_mut(entity) {
+        node.flex_direction = FlexDirection::Column;
+        node.row_gap = Val::Px(4.);
+        node.padding = UiRect::all(Val::Px(8.));
+    }
+
+    let text_font = TextFont {
+        font_size: FONT_SIZE,
+        ..default()
+    };
+    commands
+        .entity(entity)
+        .insert(BackgroundColor(PANEL_BACKGROUND_COLOR))
+        .with_children(|parent| {
+            for (label, path, precision) in [
+                ("FPS", FrameTimeDiagnosticsPlugin::FPS, 0),
+                ("Frame time (ms)", FrameTimeDiagnosticsPlugin::FRAME_TIME, 2),
+                ("Entities", EntityCountDiagnosticsPlugin::ENTITY_COUNT, 0),
+            ] {
+                parent
+                    .spawn(Node {
+                        justify_content: JustifyContent::SpaceBetween,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(8.),
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn((Text::new(label), TextColor(LABEL_COLOR), text_font.clone()));
+                        row.spawn((
+                            Node {
+                                width: Val::Px(FIELD_WIDTH),
+                                padding: UiRect::axes(Val::Px(6.), Val::Px(2.)),
+                                justify_content: JustifyContent::FlexEnd,
+                                ..default()
+                            },
+                            BackgroundColor(FIELD_BACKGROUND_COLOR),
+                            BorderRadius::all(Val::Px(4.)),
+                        ))
+                        .with_child((
+                            DiagnosticField { path, precision },
+                            Text::new("-"),
+                            TextColor(FIELD_TEXT_COLOR),
+                            text_font.clone(),
+                        ));
+                    });
+            }
+            parent.spawn((
+                DiagnosticGraph {
+                    path: FrameTimeDiagnosticsPlugin::FPS,
+                },
+                SparklineBuilder::default()
+                    .with_size(Val::Px(SPARKLINE_WIDTH), Val::Px(SPARKLINE_HEIGHT))
+                    .with_labels()
+                    .build(),
+            ));
+        });
+}
+
+fn update_diagnostic_fields(
+    diagnostics: Res<DiagnosticsStore>,
+    mut fields: Query<(&DiagnosticField, &mut Text)>,
+) {
+    for (field, mut text) in &mut fields {
+        let Some(value) = diagnostics
+            .get(&field.path)
+            .and_then(|diagnostic| diagnostic.smoothed())
+        else {
+            continue;
+        };
+        let formatted = format!("{value:.*}", field.precision);
+        if text.0 != formatted {
+            text.0 = formatted;
+        }
+    }
+}
+
+fn update_diagnostic_graphs(
+    diagnostics: Res<DiagnosticsStore>,
+    mut graphs: Query<(&DiagnosticGraph, &mut Sparkline)>,
+) {
+    for (graph, mut sparkline) in &mut graphs {
+        if let Some(value) = diagnostics
+            .get(&graph.path)
+            .and_then(|diagnostic| diagnostic.value())
+        {
+            sparkline.push(value as f32);
+        }
+    }
+}
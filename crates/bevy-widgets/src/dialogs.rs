@@ -0,0 +1,370 @@
+use bevy::ecs::system::{IntoObserverSystem, SystemParam};
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+use crate::buttons::prelude::{ButtonBuilder, ButtonClickedEvent, ButtonType};
+use crate::focus::{Focus, FocusExt, FocusScope};
+use crate::input_fields::{
+    builder::TextInputBuilder, components::InputTextValue, InputFieldSize, InputFieldSubmitEvent,
+};
+use crate::overlay::{OverlayPlugin, UiLayer};
+use crate::WidgetsSystems;
+
+const BACKDROP_COLOR: Color = Color::srgba(0., 0., 0., 0.5);
+const PANEL_BACKGROUND_COLOR: Color = Color::srgb(0.13, 0.13, 0.14);
+const MESSAGE_COLOR: Color = Color::srgb(0.97, 0.97, 0.98);
+const FONT_SIZE: f32 = 13.0;
+const PANEL_WIDTH: f32 = 280.0;
+
+/// Plugin containing the confirmation and prompt dialogs logic
+pub struct DialogsPlugin;
+
+impl Plugin for DialogsPlugin {
+    fn build(&self, app: &mut App) {
+        WidgetsSystems::configure(app);
+        OverlayPlugin::configure(app);
+        app.init_resource::<DialogStack>()
+            .add_event::<DialogClosed>()
+            .add_observer(create_dialog)
+            .add_observer(block_backdrop_click)
+            .add_systems(
+                Update,
+                (
+                    dialog_keyboard.in_set(WidgetsSystems::Input),
+                    (on_dialog_button_click, on_prompt_submit).after(WidgetsSystems::Events),
+                ),
+            );
+    }
+}
+
+/// Extension trait for [`Commands`] opening modal dialogs.
+///
+/// The callback is a one-shot observer of the dialog, also closing sends a [`DialogClosed`] event.
+/// Dialogs keep the focus until they close, then give it back to the widget focused before.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::dialogs::{DialogConfirmed, DialogExt, PromptSubmitted};
+/// fn delete_selection(mut commands: Commands) {
+///     commands.confirm("Delete 3 entities?", |_: Trigger<DialogConfirmed>| {
+///         info!("Deleted");
+///     });
+///     commands.prompt("New name:", |trigger: Trigger<PromptSubmitted>| {
+///         info!("Renamed to {}", trigger.value);
+///     });
+/// }
+/// ```
+pub trait DialogExt {
+    /// Opens a dialog asking to confirm `message`, `on_confirm` observes its [`DialogConfirmed`] event.
+    /// Enter confirms and Escape cancels.
+    fn confirm<M>(
+        &mut self,
+        message: impl Into<String>,
+        on_confirm: impl IntoObserverSystem<DialogConfirmed, (), M>,
+    ) -> Entity;
+
+    /// Opens a dialog asking for a text after `message`, `on_submit` observes its [`PromptSubmitted`] event.
+    /// Enter submits and Escape cancels.
+    fn prompt<M>(
+        &mut self,
+        message: impl Into<String>,
+        on_submit: impl IntoObserverSystem<PromptSubmitted, (), M>,
+    ) -> Entity;
+}
+
+impl DialogExt for Commands<'_, '_> {
+    fn confirm<M>(
+        &mut self,
+        message: impl Into<String>,
+        on_confirm: impl IntoObserverSystem<DialogConfirmed, (), M>,
+    ) -> Entity {
+        self.spawn(Dialog::new(DialogKind::Confirm, message.into()))
+            .observe(on_confirm)
+            .id()
+    }
+
+    fn prompt<M>(
+        &mut self,
+        message: impl Into<String>,
+        on_submit: impl IntoObserverSystem<PromptSubmitted, (), M>,
+    ) -> Entity {
+        self.spawn(Dialog::new(DialogKind::Prompt, message.into()))
+            .observe(on_submit)
+            .id()
+    }
+}
+
+/// Event triggered on a confirmation dialog when it is confirmed
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DialogConfirmed;
+
+/// Event triggered on a prompt dialog when its text is submitted
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct PromptSubmitted {
+    /// Submitted text
+    pub value: String,
+}
+
+/// How a dialog was closed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DialogResult {
+    /// The confirmation dialog was confirmed
+    Confirmed,
+    /// The prompt dialog text was submitted
+    Submitted(String),
+    /// The dialog was cancelled
+    Cancelled,
+}
+
+/// Event sent when a dialog closes
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct DialogClosed {
+    /// Entity of the dialog, despawned
+    pub entity: Entity,
+    /// How the dialog was closed
+    pub result: DialogResult,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DialogKind {
+    Confirm,
+    Prompt,
+}
+
+/// Modal dialog opened with [`DialogExt`]
+#[derive(Component, Debug, Clone)]
+#[require(Node)]
+pub struct Dialog {
+    kind: DialogKind,
+    message: String,
+    field: Option<Entity>,
+    previous_focus: Option<Entity>,
+}
+
+impl Dialog {
+    const fn new(kind: DialogKind, message: String) -> Self {
+        Self {
+            kind,
+            message,
+            field: None,
+            previous_focus: None,
+        }
+    }
+
+    /// Message of the dialog
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// Open dialogs, the last one receives the keyboard
+#[derive(Resource, Debug, Default)]
+struct DialogStack(Vec<Entity>);
+
+#[derive(Component, Debug, Clone, Copy)]
+struct DialogButton {
+    dialog: Entity,
+    confirm: bool,
+}
+
+fn create_dialog(
+    trigger: Trigger<OnAdd, Dialog>,
+    mut commands: Commands,
+    mut dialogs: Query<(&mut Dialog, &mut Node)>,
+    focused: Query<Entity, With<Focus>>,
+    mut stack: ResMut<DialogStack>,
+) {
+    let entity = trigger.entity();
+    let Ok((mut dialog, mut node)) = dialogs.get_mut(entity) else {
+        return;
+    };
+    node.position_type = PositionType::Absolute;
+    node.width = Val::Percent(100.);
+    node.height = Val::Percent(100.);
+    node.justify_content = JustifyContent::Center;
+    node.align_items = AlignItems::Center;
+
+    let text_font = TextFont {
+        font_size: FONT_SIZE,
+        ..default()
+    };
+    let panel = commands
+        .spawn((
+            Node {
+                width: Val::Px(PANEL_WIDTH),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.),
+                padding: UiRect::all(Val::Px(16.)),
+                ..default()
+            },
+            BackgroundColor(PANEL_BACKGROUND_COLOR),
+            BorderRadius::all(Val::Px(6.)),
+        ))
+        .with_child((
+            Text::new(dialog.message.clone()),
+            TextColor(MESSAGE_COLOR),
+            text_font,
+        ))
+        .id();
+    let field = (dialog.kind == DialogKind::Prompt).then(|| {
+        commands
+            .spawn(
+                TextInputBuilder::default()
+                    .with_size(InputFieldSize::Medium)
+                    .build(),
+            )
+            .id()
+    });
+    let buttons = commands
+        .spawn(Node {
+            justify_content: JustifyContent::End,
+            column_gap: Val::Px(8.),
+            ..default()
+        })
+        .id();
+    let cancel = ButtonBuilder::new("Cancel".into())
+        .with_type(ButtonType::Secondary)
+        .spawn(&mut commands)
+        .root;
+    let ok = ButtonBuilder::new("OK".into()).spawn(&mut commands).root;
+    commands.entity(cancel).insert(DialogButton {
+        dialog: entity,
+        confirm: false,
+    });
+    commands.entity(ok).insert(DialogButton {
+        dialog: entity,
+        confirm: true,
+    });
+    commands.entity(buttons).add_children(&[cancel, ok]);
+    if let Some(field) = field {
+        commands.entity(panel).add_child(field);
+    }
+    commands.entity(panel).add_child(buttons);
+    commands
+        .entity(entity)
+        .insert((
+            Name::new("Dialog"),
+            BackgroundColor(BACKDROP_COLOR),
+            UiLayer::Modal,
+            FocusScope,
+        ))
+        .add_child(panel);
+
+    commands.set_focus(field.unwrap_or(ok));
+    dialog.field = field;
+    dialog.previous_focus = focused.iter().next();
+    stack.0.push(entity);
+}
+
+// Widgets under the backdrop can't be clicked while a dialog is open
+fn block_backdrop_click(mut trigger: Trigger<Pointer<Click>>, dialogs: Query<(), With<Dialog>>) {
+    if dialogs.contains(trigger.entity()) {
+        trigger.propagate(false);
+    }
+}
+
+/// Closes dialogs, triggering their result and giving the focus back
+#[derive(SystemParam)]
+struct DialogCloser<'w, 's> {
+    commands: Commands<'w, 's>,
+    dialogs: Query<'w, 's, &'static Dialog>,
+    existing: Query<'w, 's, ()>,
+    stack: ResMut<'w, DialogStack>,
+    event_writer: EventWriter<'w, DialogClosed>,
+}
+
+impl DialogCloser<'_, '_> {
+    fn close(&mut self, entity: Entity, result: DialogResult) {
+        let Ok(dialog) = self.dialogs.get(entity) else {
+            return;
+        };
+        match &result {
+            DialogResult::Confirmed => self.commands.trigger_targets(DialogConfirmed, entity),
+            DialogResult::Submitted(value) => self.commands.trigger_targets(
+                PromptSubmitted {
+                    value: value.clone(),
+                },
+                entity,
+            ),
+            DialogResult::Cancelled => {}
+        }
+        // Despawning the dialog also despawns its observer, after the result is triggered
+        self.commands.entity(entity).despawn_recursive();
+        self.stack.0.retain(|open| *open != entity);
+        match dialog
+            .previous_focus
+            .filter(|previous| self.existing.contains(*previous))
+        {
+            Some(previous) => self.commands.set_focus(previous),
+            None => self.commands.clear_focus(),
+        }
+        self.event_writer.send(DialogClosed { entity, result });
+    }
+}
+
+fn dialog_result(dialog: &Dialog, confirm: bool, values: &Query<&InputTextValue>) -> DialogResult {
+    match (confirm, dialog.field) {
+        (false, _) => DialogResult::Cancelled,
+        (true, None) => DialogResult::Confirmed,
+        (true, Some(field)) => DialogResult::Submitted(
+            values
+                .get(field)
+                .map(|value| value.0.clone())
+                .unwrap_or_default(),
+        ),
+    }
+}
+
+fn dialog_keyboard(mut input_reader: EventReader<KeyboardInput>, mut closer: DialogCloser) {
+    let keys: Vec<Key> = input_reader
+        .read()
+        .filter(|input| input.state.is_pressed())
+        .map(|input| input.logical_key.clone())
+        .collect();
+    let Some(&entity) = closer.stack.0.last() else {
+        return;
+    };
+    let Ok(dialog) = closer.dialogs.get(entity) else {
+        return;
+    };
+    let result = keys.iter().find_map(|key| match key {
+        Key::Escape => Some(DialogResult::Cancelled),
+        // Prompts submit from their field
+        Key::Enter if dialog.kind == DialogKind::Confirm => Some(DialogResult::Confirmed),
+        _ => None,
+    });
+    if let Some(result) = result {
+        closer.close(entity, result);
+    }
+}
+
+fn on_dialog_button_click(
+    mut events: EventReader<ButtonClickedEvent>,
+    buttons: Query<&DialogButton>,
+    values: Query<&InputTextValue>,
+    mut closer: DialogCloser,
+) {
+    for event in events.read() {
+        let Ok(button) = buttons.get(event.entity) else {
+            continue;
+        };
+        let Ok(dialog) = closer.dialogs.get(button.dialog) else {
+            continue;
+        };
+        let result = dialog_result(dialog, button.confirm, &values);
+        closer.close(button.dialog, result);
+    }
+}
+
+fn on_prompt_submit(mut events: EventReader<InputFieldSubmitEvent>, mut closer: DialogCloser) {
+    for event in events.read() {
+        let Some(entity) = closer.stack.0.iter().copied().find(|open| {
+            closer
+                .dialogs
+                .get(*open)
+                .is_ok_and(|dialog| dialog.field == Some(event.entity))
+        }) else {
+            continue;
+        };
+        closer.close(entity, DialogResult::Submitted(event.value.clone()));
+    }
+}
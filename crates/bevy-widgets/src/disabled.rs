@@ -0,0 +1,158 @@
+use bevy::prelude::*;
+
+use crate::{
+    buttons::DisableButton,
+    focus::Focus,
+    input_fields::{components::InputInactive, InputFieldState},
+};
+
+/// Plugin containing the disabled subtree logic
+pub struct DisabledPlugin;
+
+impl Plugin for DisabledPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<DisabledSubtree>()
+            .register_type::<InheritedDisabled>()
+            .add_observer(on_add_disabled_subtree)
+            .add_observer(on_remove_disabled_subtree)
+            .add_observer(refuse_focus)
+            .add_systems(PostUpdate, disable_new_descendants);
+    }
+}
+
+/// Disables every interactive descendant of the entity, and the entity itself.
+///
+/// Buttons get [`DisableButton`] and text and numeric fields the [`InputFieldState::Disabled`] state,
+/// none of them can be focused. Removing the component restores the previous state of each widget,
+/// unless another disabled subtree still contains it.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+#[reflect(Component)]
+pub struct DisabledSubtree;
+
+/// Added to widgets disabled by a [`DisabledSubtree`], keeping their state to restore
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub struct InheritedDisabled {
+    disabled_button: bool,
+    previous_state: Option<InputFieldState>,
+}
+
+/// Checks if `entity` is inside a [`DisabledSubtree`] other than `except`
+fn is_in_disabled_subtree(
+    entity: Entity,
+    except: Option<Entity>,
+    parents: &Query<&Parent>,
+    subtrees: &Query<(), With<DisabledSubtree>>,
+) -> bool {
+    std::iter::once(entity)
+        .chain(parents.iter_ancestors(entity))
+        .any(|ancestor| Some(ancestor) != except && subtrees.contains(ancestor))
+}
+
+fn disable(mut entity: EntityWorldMut) {
+    if entity.contains::<InheritedDisabled>() {
+        return;
+    }
+    let disabled_button = entity.contains::<Button>() && !entity.contains::<DisableButton>();
+    if !disabled_button && !entity.contains::<InputFieldState>() {
+        return;
+    }
+
+    // Losing focus resets the state of text fields, so it goes first
+    entity.remove::<Focus>();
+    let mut previous_state = None;
+    if let Some(mut state) = entity.get_mut::<InputFieldState>() {
+        previous_state = Some(*state);
+        *state = InputFieldState::Disabled;
+    }
+    if let Some(mut inactive) = entity.get_mut::<InputInactive>() {
+        inactive.inactive();
+    }
+    if disabled_button {
+        entity.insert(DisableButton);
+    }
+    if let Some(mut interaction) = entity.get_mut::<Interaction>() {
+        // Restyles buttons
+        interaction.set_changed();
+    }
+    entity.insert(InheritedDisabled {
+        disabled_button,
+        previous_state,
+    });
+}
+
+fn restore(mut entity: EntityWorldMut) {
+    let Some(disabled) = entity.take::<InheritedDisabled>() else {
+        return;
+    };
+    if let (Some(mut state), Some(previous_state)) =
+        (entity.get_mut::<InputFieldState>(), disabled.previous_state)
+    {
+        *state = previous_state;
+    }
+    if disabled.disabled_button {
+        entity.remove::<DisableButton>();
+    }
+    if let Some(mut interaction) = entity.get_mut::<Interaction>() {
+        interaction.set_changed();
+    }
+}
+
+fn on_add_disabled_subtree(
+    trigger: Trigger<OnAdd, DisabledSubtree>,
+    mut commands: Commands,
+    children: Query<&Children>,
+) {
+    let root = trigger.entity();
+    for entity in std::iter::once(root).chain(children.iter_descendants(root)) {
+        commands.entity(entity).queue(disable);
+    }
+}
+
+fn on_remove_disabled_subtree(
+    trigger: Trigger<OnRemove, DisabledSubtree>,
+    mut commands: Commands,
+    children: Query<&Children>,
+    parents: Query<&Parent>,
+    subtrees: Query<(), With<DisabledSubtree>>,
+    disabled: Query<(), With<InheritedDisabled>>,
+) {
+    let root = trigger.entity();
+    for entity in std::iter::once(root).chain(children.iter_descendants(root)) {
+        if disabled.contains(entity)
+            && !is_in_disabled_subtree(entity, Some(root), &parents, &subtrees)
+        {
+            commands.entity(entity).queue(restore);
+        }
+    }
+}
+
+fn refuse_focus(
+    trigger: Trigger<OnAdd, Focus>,
+    mut commands: Commands,
+    disabled: Query<(), With<InheritedDisabled>>,
+) {
+    let entity = trigger.entity();
+    if disabled.contains(entity) {
+        commands.entity(entity).remove::<Focus>();
+    }
+}
+
+fn disable_new_descendants(
+    mut commands: Commands,
+    added: Query<
+        Entity,
+        (
+            Without<InheritedDisabled>,
+            Or<(Added<Button>, Added<InputFieldState>)>,
+        ),
+    >,
+    parents: Query<&Parent>,
+    subtrees: Query<(), With<DisabledSubtree>>,
+) {
+    for entity in &added {
+        if is_in_disabled_subtree(entity, None, &parents, &subtrees) {
+            commands.entity(entity).queue(disable);
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use bevy::color::Color;
+
+pub(super) const TAB_BAR_HEIGHT: f32 = 24.0;
+pub(super) const TAB_FONT_SIZE: f32 = 10.0;
+pub(super) const SPLITTER_THICKNESS: f32 = 4.0;
+pub(super) const MIN_REGION_SIZE: f32 = 80.0;
+
+pub(super) const DEFAULT_SIDE_WIDTH: f32 = 280.0;
+pub(super) const DEFAULT_BOTTOM_HEIGHT: f32 = 200.0;
+pub(super) const FLOATING_WINDOW_WIDTH: f32 = 400.0;
+pub(super) const FLOATING_WINDOW_HEIGHT: f32 = 300.0;
+
+pub(super) const REGION_BACKGROUND_COLOR: Color = Color::srgb(0.93, 0.97, 1.0);
+pub(super) const TAB_BAR_COLOR: Color = Color::srgb(0.11, 0.29, 0.42);
+pub(super) const ACTIVE_TAB_COLOR: Color = Color::srgb(0.19, 0.49, 0.71);
+pub(super) const INACTIVE_TAB_COLOR: Color = Color::srgb(0.11, 0.29, 0.42);
+pub(super) const TAB_TEXT_COLOR: Color = Color::srgb(0.97, 0.97, 0.98);
+pub(super) const SPLITTER_COLOR: Color = Color::srgb(0.8, 0.83, 0.85);
@@ -0,0 +1,224 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::WidgetsSystems;
+
+mod constants;
+mod systems;
+
+use constants::{DEFAULT_BOTTOM_HEIGHT, DEFAULT_SIDE_WIDTH};
+use systems::*;
+
+/// Plugin containing the docking layout logic
+pub struct DockingPlugin;
+
+impl Plugin for DockingPlugin {
+    fn build(&self, app: &mut App) {
+        WidgetsSystems::configure(app);
+        app.init_resource::<DockLayout>()
+            .register_type::<DockSide>()
+            .register_type::<DockLayout>()
+            .register_type::<DockPanelLayout>()
+            .add_observer(create_dock_area)
+            .add_observer(on_tab_click)
+            .add_observer(on_splitter_drag)
+            .add_systems(
+                Update,
+                (
+                    restore_floating_panels,
+                    place_panels,
+                    update_regions,
+                    apply_layout_sizes,
+                )
+                    .chain()
+                    .in_set(WidgetsSystems::Layout),
+            );
+    }
+}
+
+/// Where a [`DockPanel`] is placed inside its [`DockArea`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect, Serialize, Deserialize)]
+pub enum DockSide {
+    /// Left column, resizable in width
+    Left,
+    /// Right column, resizable in width
+    #[default]
+    Right,
+    /// Bottom row, resizable in height
+    Bottom,
+    /// Remaining space in the middle
+    Center,
+    /// Separate OS window
+    Floating,
+}
+
+/// Root container for dockable panels.
+///
+/// Builds left, center, right and bottom regions separated by draggable splitters.
+/// [`DockPanel`] entities spawned as descendants of the area are moved into the region of their [`DockSide`].
+#[derive(Component, Debug, Default)]
+#[require(Node)]
+pub struct DockArea {
+    regions: Option<DockRegions>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DockRegions {
+    left: Entity,
+    right: Entity,
+    bottom: Entity,
+    center: Entity,
+}
+
+impl DockRegions {
+    const fn get(&self, side: DockSide) -> Option<Entity> {
+        match side {
+            DockSide::Left => Some(self.left),
+            DockSide::Right => Some(self.right),
+            DockSide::Bottom => Some(self.bottom),
+            DockSide::Center => Some(self.center),
+            DockSide::Floating => None,
+        }
+    }
+}
+
+/// A panel that can be docked in a [`DockArea`], tabbed with other panels of the same side or floated.
+///
+/// Change [`DockPanel::side`] to move the panel at runtime.
+#[derive(Component, Debug, Clone)]
+#[require(Node)]
+pub struct DockPanel {
+    /// Title shown in the panel tab, also used as key in [`DockLayout`]
+    pub title: String,
+    /// Where the panel is docked
+    pub side: DockSide,
+    area: Option<Entity>,
+    docked: Option<DockSide>,
+    tab: Option<Entity>,
+    floating: Option<FloatingPanel>,
+    last_docked: DockSide,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct FloatingPanel {
+    window: Entity,
+    camera: Entity,
+    root: Entity,
+}
+
+impl DockPanel {
+    /// Creates a panel docked at `side`
+    pub fn new(title: impl Into<String>, side: DockSide) -> Self {
+        Self {
+            title: title.into(),
+            side,
+            area: None,
+            docked: None,
+            tab: None,
+            floating: None,
+            last_docked: if side == DockSide::Floating {
+                DockSide::default()
+            } else {
+                side
+            },
+        }
+    }
+}
+
+/// A docking region of a [`DockArea`]
+#[derive(Component, Debug)]
+pub struct DockRegion {
+    side: DockSide,
+    splitter: Option<Entity>,
+    tab_bar: Entity,
+    content: Entity,
+    panels: Vec<Entity>,
+    active: Option<Entity>,
+}
+
+impl DockRegion {
+    /// Side of this region
+    pub const fn side(&self) -> DockSide {
+        self.side
+    }
+
+    /// Panels docked in this region, in tab order
+    pub fn panels(&self) -> &[Entity] {
+        &self.panels
+    }
+
+    /// Panel whose tab is selected
+    pub const fn active(&self) -> Option<Entity> {
+        self.active
+    }
+}
+
+/// Tab of a docked panel
+#[derive(Component, Debug)]
+pub struct DockTab {
+    panel: Entity,
+}
+
+/// Draggable divider resizing the region of its side
+#[derive(Component, Debug)]
+pub struct DockSplitter {
+    side: DockSide,
+}
+
+/// Placement of a panel inside [`DockLayout`]
+#[derive(Debug, Clone, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+pub struct DockPanelLayout {
+    /// Panel title
+    pub title: String,
+    /// Panel side
+    pub side: DockSide,
+}
+
+/// Serializable docking layout: region sizes and where each panel, identified by title, is docked.
+///
+/// Kept up to date by the docking systems. Panels spawned after this resource is modified
+/// are docked as described here, which allows restoring a saved layout.
+#[derive(Resource, Debug, Clone, PartialEq, Reflect, Serialize, Deserialize)]
+#[reflect(Resource)]
+pub struct DockLayout {
+    /// Width of the left region in logical pixels
+    pub left_width: f32,
+    /// Width of the right region in logical pixels
+    pub right_width: f32,
+    /// Height of the bottom region in logical pixels
+    pub bottom_height: f32,
+    /// Panel placements
+    pub panels: Vec<DockPanelLayout>,
+}
+
+impl Default for DockLayout {
+    fn default() -> Self {
+        Self {
+            left_width: DEFAULT_SIDE_WIDTH,
+            right_width: DEFAULT_SIDE_WIDTH,
+            bottom_height: DEFAULT_BOTTOM_HEIGHT,
+            panels: Vec::new(),
+        }
+    }
+}
+
+impl DockLayout {
+    /// Side stored for the panel with `title`
+    pub fn side_of(&self, title: &str) -> Option<DockSide> {
+        self.panels
+            .iter()
+            .find(|panel| panel.title == title)
+            .map(|panel| panel.side)
+    }
+
+    fn set_side(&mut self, title: &str, side: DockSide) {
+        if let Some(panel) = self.panels.iter_mut().find(|panel| panel.title == title) {
+            panel.side = side;
+        } else {
+            self.panels.push(DockPanelLayout {
+                title: title.to_string(),
+                side,
+            });
+        }
+    }
+}
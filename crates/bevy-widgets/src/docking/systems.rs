@@ -0,0 +1,450 @@
+use bevy::{
+    prelude::*,
+    render::camera::RenderTarget,
+    window::{WindowClosed, WindowRef, WindowResolution},
+};
+
+use crate::focus::{stop_propagation, EventPropagation};
+
+use super::{
+    constants::*, DockArea, DockLayout, DockPanel, DockRegion, DockRegions, DockSide, DockSplitter,
+    DockTab, FloatingPanel,
+};
+
+fn spawn_region(
+    commands: &mut Commands,
+    side: DockSide,
+    splitter: Option<Entity>,
+    node: Node,
+) -> Entity {
+    let tab_bar = commands
+        .spawn((
+            Name::new("DockTabBar"),
+            Node {
+                height: Val::Px(TAB_BAR_HEIGHT),
+                min_height: Val::Px(TAB_BAR_HEIGHT),
+                flex_direction: FlexDirection::Row,
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            BackgroundColor(TAB_BAR_COLOR),
+        ))
+        .id();
+    let content = commands
+        .spawn((
+            Name::new("DockRegionContent"),
+            Node {
+                flex_grow: 1.,
+                min_height: Val::Px(0.),
+                overflow: Overflow::clip(),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+        ))
+        .id();
+
+    commands
+        .spawn((
+            Name::new(format!("DockRegion{side:?}")),
+            DockRegion {
+                side,
+                splitter,
+                tab_bar,
+                content,
+                panels: Vec::new(),
+                active: None,
+            },
+            Node {
+                flex_direction: FlexDirection::Column,
+                ..node
+            },
+            BackgroundColor(REGION_BACKGROUND_COLOR),
+        ))
+        .add_children(&[tab_bar, content])
+        .id()
+}
+
+fn spawn_splitter(commands: &mut Commands, side: DockSide) -> Entity {
+    let horizontal = side == DockSide::Bottom;
+    commands
+        .spawn((
+            Name::new(format!("DockSplitter{side:?}")),
+            DockSplitter { side },
+            Node {
+                width: if horizontal {
+                    Val::Percent(100.)
+                } else {
+                    Val::Px(SPLITTER_THICKNESS)
+                },
+                height: if horizontal {
+                    Val::Px(SPLITTER_THICKNESS)
+                } else {
+                    Val::Percent(100.)
+                },
+                display: Display::None,
+                ..default()
+            },
+            BackgroundColor(SPLITTER_COLOR),
+        ))
+        .id()
+}
+
+pub(super) fn create_dock_area(
+    trigger: Trigger<OnAdd, DockArea>,
+    mut commands: Commands,
+    mut areas: Query<(&mut DockArea, &mut Node)>,
+    layout: Res<DockLayout>,
+) {
+    let entity = trigger.entity();
+    let Ok((mut area, mut node)) = areas.get_mut(entity) else {
+        return;
+    };
+    node.flex_direction = FlexDirection::Column;
+    if node.width == Val::Auto {
+        node.width = Val::Percent(100.);
+    }
+    if node.height == Val::Auto {
+        node.height = Val::Percent(100.);
+    }
+
+    let hidden_side = |size: Val, horizontal: bool| Node {
+        width: if horizontal { Val::Percent(100.) } else { size },
+        height: if horizontal { size } else { Val::Percent(100.) },
+        display: Display::None,
+        ..default()
+    };
+
+    let left_splitter = spawn_splitter(&mut commands, DockSide::Left);
+    let right_splitter = spawn_splitter(&mut commands, DockSide::Right);
+    let bottom_splitter = spawn_splitter(&mut commands, DockSide::Bottom);
+    let left = spawn_region(
+        &mut commands,
+        DockSide::Left,
+        Some(left_splitter),
+        hidden_side(Val::Px(layout.left_width), false),
+    );
+    let right = spawn_region(
+        &mut commands,
+        DockSide::Right,
+        Some(right_splitter),
+        hidden_side(Val::Px(layout.right_width), false),
+    );
+    let bottom = spawn_region(
+        &mut commands,
+        DockSide::Bottom,
+        Some(bottom_splitter),
+        hidden_side(Val::Px(layout.bottom_height), true),
+    );
+    let center = spawn_region(
+        &mut commands,
+        DockSide::Center,
+        None,
+        Node {
+            flex_grow: 1.,
+            min_width: Val::Px(0.),
+            height: Val::Percent(100.),
+            ..default()
+        },
+    );
+
+    let middle = commands
+        .spawn((
+            Name::new("DockAreaMiddle"),
+            Node {
+                flex_grow: 1.,
+                min_height: Val::Px(0.),
+                width: Val::Percent(100.),
+                flex_direction: FlexDirection::Row,
+                ..default()
+            },
+        ))
+        .add_children(&[left, left_splitter, center, right_splitter, right])
+        .id();
+
+    commands
+        .entity(entity)
+        .add_children(&[middle, bottom_splitter, bottom]);
+    area.regions = Some(DockRegions {
+        left,
+        right,
+        bottom,
+        center,
+    });
+}
+
+fn undock(
+    commands: &mut Commands,
+    panel_entity: Entity,
+    panel: &mut DockPanel,
+    region: Option<Mut<DockRegion>>,
+) {
+    if let Some(tab) = panel.tab.take() {
+        commands.entity(tab).despawn_recursive();
+    }
+    if let Some(floating) = panel.floating.take() {
+        commands
+            .entity(floating.root)
+            .remove_children(&[panel_entity]);
+        commands.entity(floating.root).despawn_recursive();
+        commands.entity(floating.camera).despawn_recursive();
+        if let Some(mut window) = commands.get_entity(floating.window) {
+            window.despawn();
+        }
+    }
+    if let Some(mut region) = region {
+        region.panels.retain(|panel| *panel != panel_entity);
+        if region.active == Some(panel_entity) {
+            region.active = region.panels.first().copied();
+        }
+    }
+}
+
+fn float(commands: &mut Commands, panel_entity: Entity, panel: &mut DockPanel, node: &mut Node) {
+    let window = commands
+        .spawn(Window {
+            title: panel.title.clone(),
+            resolution: WindowResolution::new(FLOATING_WINDOW_WIDTH, FLOATING_WINDOW_HEIGHT),
+            ..default()
+        })
+        .id();
+    let camera = commands
+        .spawn((
+            Camera2d,
+            Camera {
+                target: RenderTarget::Window(WindowRef::Entity(window)),
+                ..default()
+            },
+        ))
+        .id();
+    let root = commands
+        .spawn((
+            Name::new("DockFloatingRoot"),
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            BackgroundColor(REGION_BACKGROUND_COLOR),
+            TargetCamera(camera),
+        ))
+        .add_child(panel_entity)
+        .id();
+
+    node.display = Display::Flex;
+    panel.floating = Some(FloatingPanel {
+        window,
+        camera,
+        root,
+    });
+}
+
+fn dock(
+    commands: &mut Commands,
+    panel_entity: Entity,
+    panel: &mut DockPanel,
+    region: &mut DockRegion,
+) {
+    let tab = commands
+        .spawn((
+            Name::new("DockTab"),
+            DockTab {
+                panel: panel_entity,
+            },
+            Node {
+                height: Val::Percent(100.),
+                padding: UiRect::axes(Val::Px(8.), Val::Px(0.)),
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(ACTIVE_TAB_COLOR),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(panel.title.clone()),
+                TextColor(TAB_TEXT_COLOR),
+                TextFont {
+                    font_size: TAB_FONT_SIZE,
+                    ..default()
+                },
+                PickingBehavior::IGNORE,
+            ));
+        })
+        .id();
+    commands.entity(region.tab_bar).add_child(tab);
+    commands.entity(region.content).add_child(panel_entity);
+
+    region.panels.push(panel_entity);
+    region.active = Some(panel_entity);
+    panel.tab = Some(tab);
+    panel.last_docked = region.side;
+}
+
+pub(super) fn place_panels(
+    mut commands: Commands,
+    mut panels: Query<(Entity, &mut DockPanel, &mut Node), Changed<DockPanel>>,
+    areas: Query<&DockArea>,
+    parents: Query<&Parent>,
+    mut regions: Query<&mut DockRegion>,
+    mut layout: ResMut<DockLayout>,
+) {
+    for (entity, mut panel, mut node) in &mut panels {
+        // Internal bookkeeping must not retrigger this system.
+        let panel = panel.bypass_change_detection();
+
+        if panel.area.is_none() {
+            panel.area = parents
+                .iter_ancestors(entity)
+                .find(|ancestor| areas.contains(*ancestor));
+            if let Some(side) = layout.side_of(&panel.title) {
+                panel.side = side;
+            }
+        }
+        let Some(dock_regions) = panel
+            .area
+            .and_then(|area| areas.get(area).ok())
+            .and_then(|area| area.regions)
+        else {
+            continue;
+        };
+        if panel.docked == Some(panel.side) {
+            continue;
+        }
+
+        let previous_region = panel
+            .docked
+            .and_then(|side| dock_regions.get(side))
+            .and_then(|region| regions.get_mut(region).ok());
+        undock(&mut commands, entity, panel, previous_region);
+
+        match dock_regions.get(panel.side) {
+            Some(region) => {
+                let Ok(mut region) = regions.get_mut(region) else {
+                    continue;
+                };
+                dock(&mut commands, entity, panel, &mut region);
+            }
+            None => float(&mut commands, entity, panel, &mut node),
+        }
+
+        panel.docked = Some(panel.side);
+        layout.set_side(&panel.title, panel.side);
+    }
+}
+
+pub(super) fn update_regions(
+    regions: Query<(Entity, &DockRegion), Changed<DockRegion>>,
+    mut nodes: Query<&mut Node>,
+    mut tabs: Query<(&DockTab, &mut BackgroundColor)>,
+    children: Query<&Children>,
+) {
+    for (entity, region) in &regions {
+        let display = if region.panels.is_empty() && region.side != DockSide::Center {
+            Display::None
+        } else {
+            Display::Flex
+        };
+        for node_entity in std::iter::once(entity).chain(region.splitter) {
+            if let Ok(mut node) = nodes.get_mut(node_entity) {
+                node.display = display;
+            }
+        }
+
+        for panel in &region.panels {
+            if let Ok(mut node) = nodes.get_mut(*panel) {
+                node.display = if region.active == Some(*panel) {
+                    Display::Flex
+                } else {
+                    Display::None
+                };
+            }
+        }
+
+        let Ok(tab_entities) = children.get(region.tab_bar) else {
+            continue;
+        };
+        let mut iter = tabs.iter_many_mut(tab_entities);
+        while let Some((tab, mut color)) = iter.fetch_next() {
+            color.0 = if region.active == Some(tab.panel) {
+                ACTIVE_TAB_COLOR
+            } else {
+                INACTIVE_TAB_COLOR
+            };
+        }
+    }
+}
+
+pub(super) fn apply_layout_sizes(
+    layout: Res<DockLayout>,
+    mut regions: Query<(&DockRegion, &mut Node)>,
+) {
+    if !layout.is_changed() {
+        return;
+    }
+    for (region, mut node) in &mut regions {
+        match region.side {
+            DockSide::Left => node.width = Val::Px(layout.left_width),
+            DockSide::Right => node.width = Val::Px(layout.right_width),
+            DockSide::Bottom => node.height = Val::Px(layout.bottom_height),
+            DockSide::Center | DockSide::Floating => {}
+        }
+    }
+}
+
+pub(super) fn restore_floating_panels(
+    mut closed: EventReader<WindowClosed>,
+    mut panels: Query<&mut DockPanel>,
+) {
+    for event in closed.read() {
+        for mut panel in &mut panels {
+            if panel
+                .floating
+                .is_some_and(|floating| floating.window == event.window)
+            {
+                panel.side = panel.last_docked;
+            }
+        }
+    }
+}
+
+pub(super) fn on_tab_click(
+    mut trigger: Trigger<Pointer<Click>>,
+    tabs: Query<&DockTab>,
+    mut regions: Query<&mut DockRegion>,
+    propagation: Query<&EventPropagation>,
+) {
+    let Ok(tab) = tabs.get(trigger.entity()) else {
+        return;
+    };
+    stop_propagation(&mut trigger, &propagation);
+    for mut region in &mut regions {
+        if region.panels.contains(&tab.panel) && region.active != Some(tab.panel) {
+            region.active = Some(tab.panel);
+        }
+    }
+}
+
+pub(super) fn on_splitter_drag(
+    mut trigger: Trigger<Pointer<Drag>>,
+    splitters: Query<&DockSplitter>,
+    mut layout: ResMut<DockLayout>,
+    ui_scale: Res<UiScale>,
+    propagation: Query<&EventPropagation>,
+) {
+    let Ok(splitter) = splitters.get(trigger.entity()) else {
+        return;
+    };
+    stop_propagation(&mut trigger, &propagation);
+    // Pointer deltas are in window pixels, region sizes are scaled by `UiScale`
+    let delta = trigger.delta / ui_scale.0;
+    match splitter.side {
+        DockSide::Left => layout.left_width = (layout.left_width + delta.x).max(MIN_REGION_SIZE),
+        DockSide::Right => {
+            layout.right_width = (layout.right_width - delta.x).max(MIN_REGION_SIZE);
+        }
+        DockSide::Bottom => {
+            layout.bottom_height = (layout.bottom_height - delta.y).max(MIN_REGION_SIZE);
+        }
+        DockSide::Center | DockSide::Floating => {}
+    }
+}
@@ -0,0 +1,316 @@
+use std::any::{type_name, Any, TypeId};
+use std::fmt;
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+use crate::overlay::{OverlayPlugin, UiLayer};
+
+const GHOST_BACKGROUND_COLOR: Color = Color::srgba(0.19, 0.49, 0.71, 0.6);
+const GHOST_TEXT_COLOR: Color = Color::srgb(0.97, 0.97, 0.98);
+const GHOST_FONT_SIZE: f32 = 10.0;
+const GHOST_CURSOR_OFFSET: f32 = 8.0;
+
+/// Plugin containing the drag and drop logic between widgets
+pub struct DragAndDropPlugin;
+
+impl Plugin for DragAndDropPlugin {
+    fn build(&self, app: &mut App) {
+        OverlayPlugin::configure(app);
+        app.add_event::<DroppedEvent>()
+            .register_type::<DropHover>()
+            .add_observer(on_drag_start)
+            .add_observer(on_drag)
+            .add_observer(on_drag_end)
+            .add_observer(on_drag_over)
+            .add_observer(on_drag_leave)
+            .add_observer(on_drag_drop);
+    }
+}
+
+/// Type erased data carried by a [`Draggable`] widget.
+///
+/// Cheap to clone, the inner value is shared.
+#[derive(Clone)]
+pub struct DragPayload {
+    value: Arc<dyn Any + Send + Sync>,
+    type_name: &'static str,
+}
+
+impl DragPayload {
+    /// Wraps a value as a payload
+    pub fn new<T: Any + Send + Sync>(value: T) -> Self {
+        Self {
+            value: Arc::new(value),
+            type_name: type_name::<T>(),
+        }
+    }
+
+    /// [`TypeId`] of the wrapped value
+    pub fn type_id(&self) -> TypeId {
+        (*self.value).type_id()
+    }
+
+    /// Name of the wrapped value type, for debugging purposes
+    pub const fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Checks if the wrapped value is of type `T`
+    pub fn is<T: Any>(&self) -> bool {
+        self.value.is::<T>()
+    }
+
+    /// Returns the wrapped value if it is of type `T`
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.value.downcast_ref::<T>()
+    }
+}
+
+impl fmt::Debug for DragPayload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DragPayload").field(&self.type_name).finish()
+    }
+}
+
+/// Marks a widget as draggable, carrying a [`DragPayload`] to whatever [`DropTarget`] it is dropped on.
+#[derive(Component, Debug, Clone)]
+pub struct Draggable {
+    /// Data carried by the drag
+    pub payload: DragPayload,
+    /// Text shown in the ghost preview following the cursor
+    pub ghost_label: Option<String>,
+}
+
+impl Draggable {
+    /// Creates a draggable carrying `payload`
+    pub fn new<T: Any + Send + Sync>(payload: T) -> Self {
+        Self {
+            payload: DragPayload::new(payload),
+            ghost_label: None,
+        }
+    }
+
+    /// Sets the text shown in the ghost preview
+    pub fn with_ghost_label(mut self, label: impl Into<String>) -> Self {
+        self.ghost_label = Some(label.into());
+        self
+    }
+}
+
+/// Marks a widget as a place where [`Draggable`] widgets can be dropped.
+/// Only payloads of the accepted types trigger a [`DroppedEvent`].
+#[derive(Component, Debug, Clone, Default)]
+pub struct DropTarget {
+    accepts: Option<Vec<TypeId>>,
+}
+
+impl DropTarget {
+    /// Drop target accepting any payload
+    pub const fn any() -> Self {
+        Self { accepts: None }
+    }
+
+    /// Drop target accepting payloads of type `T`
+    pub fn accepting<T: Any>() -> Self {
+        Self {
+            accepts: Some(vec![TypeId::of::<T>()]),
+        }
+    }
+
+    /// Also accepts payloads of type `T`
+    pub fn and<T: Any>(mut self) -> Self {
+        if let Some(accepts) = self.accepts.as_mut() {
+            accepts.push(TypeId::of::<T>());
+        }
+        self
+    }
+
+    /// Checks if the payload can be dropped on this target
+    pub fn accepts(&self, payload: &DragPayload) -> bool {
+        self.accepts
+            .as_ref()
+            .is_none_or(|accepts| accepts.contains(&payload.type_id()))
+    }
+}
+
+/// Inserted on a [`DropTarget`] while an accepted payload is dragged over it.
+/// > Useful to style drop targets.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct DropHover;
+
+/// Marks the ghost preview of a drag
+#[derive(Component)]
+pub struct DragGhost {
+    source: Entity,
+}
+
+/// An event that is fired when a [`Draggable`] is dropped on a [`DropTarget`] accepting its payload.
+#[derive(Event, Debug, Clone)]
+pub struct DroppedEvent {
+    /// The dragged entity
+    pub source: Entity,
+    /// The drop target entity
+    pub target: Entity,
+    /// The payload carried by the dragged entity
+    pub payload: DragPayload,
+}
+
+/// Finds the closest entity, starting from `entity` itself, that has a [`Draggable`].
+fn draggable_ancestor(
+    entity: Entity,
+    draggables: &Query<&Draggable>,
+    parents: &Query<&Parent>,
+) -> Option<Entity> {
+    std::iter::once(entity)
+        .chain(parents.iter_ancestors(entity))
+        .find(|e| draggables.contains(*e))
+}
+
+fn on_drag_start(
+    mut trigger: Trigger<Pointer<DragStart>>,
+    mut commands: Commands,
+    draggables: Query<&Draggable>,
+    ui_scale: Res<UiScale>,
+) {
+    let source = trigger.entity();
+    let Ok(draggable) = draggables.get(source) else {
+        return;
+    };
+    trigger.propagate(false);
+
+    let position = trigger.pointer_location.position / ui_scale.0 + GHOST_CURSOR_OFFSET;
+    commands
+        .spawn((
+            DragGhost { source },
+            Name::new("DragGhost"),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(position.x),
+                top: Val::Px(position.y),
+                padding: UiRect::axes(Val::Px(8.), Val::Px(4.)),
+                ..default()
+            },
+            BackgroundColor(GHOST_BACKGROUND_COLOR),
+            BorderRadius::all(Val::Px(4.)),
+            UiLayer::DragGhost,
+            FocusPolicy::Pass,
+            PickingBehavior::IGNORE,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(
+                    draggable
+                        .ghost_label
+                        .clone()
+                        .unwrap_or_else(|| draggable.payload.type_name().to_string()),
+                ),
+                TextColor(GHOST_TEXT_COLOR),
+                TextFont {
+                    font_size: GHOST_FONT_SIZE,
+                    ..default()
+                },
+                PickingBehavior::IGNORE,
+            ));
+        });
+}
+
+fn on_drag(
+    trigger: Trigger<Pointer<Drag>>,
+    mut ghosts: Query<(&DragGhost, &mut Node)>,
+    ui_scale: Res<UiScale>,
+) {
+    let source = trigger.entity();
+    let position = trigger.pointer_location.position / ui_scale.0 + GHOST_CURSOR_OFFSET;
+    for (ghost, mut node) in &mut ghosts {
+        if ghost.source == source {
+            node.left = Val::Px(position.x);
+            node.top = Val::Px(position.y);
+        }
+    }
+}
+
+fn on_drag_end(
+    trigger: Trigger<Pointer<DragEnd>>,
+    mut commands: Commands,
+    ghosts: Query<(Entity, &DragGhost)>,
+    hovered: Query<Entity, With<DropHover>>,
+) {
+    let source = trigger.entity();
+    for (entity, ghost) in &ghosts {
+        if ghost.source == source {
+            commands.entity(entity).despawn_recursive();
+            for target in &hovered {
+                commands.entity(target).remove::<DropHover>();
+            }
+        }
+    }
+}
+
+fn on_drag_over(
+    trigger: Trigger<Pointer<DragOver>>,
+    mut commands: Commands,
+    targets: Query<&DropTarget, Without<DropHover>>,
+    draggables: Query<&Draggable>,
+    parents: Query<&Parent>,
+) {
+    let target = trigger.entity();
+    let Ok(drop_target) = targets.get(target) else {
+        return;
+    };
+    let Some(source) = draggable_ancestor(trigger.dragged, &draggables, &parents) else {
+        return;
+    };
+    if source == target {
+        return;
+    }
+    if let Ok(draggable) = draggables.get(source) {
+        if drop_target.accepts(&draggable.payload) {
+            commands.entity(target).insert(DropHover);
+        }
+    }
+}
+
+fn on_drag_leave(
+    trigger: Trigger<Pointer<DragLeave>>,
+    mut commands: Commands,
+    targets: Query<(), With<DropHover>>,
+) {
+    let target = trigger.entity();
+    if targets.contains(target) {
+        commands.entity(target).remove::<DropHover>();
+    }
+}
+
+fn on_drag_drop(
+    mut trigger: Trigger<Pointer<DragDrop>>,
+    mut commands: Commands,
+    targets: Query<&DropTarget>,
+    draggables: Query<&Draggable>,
+    parents: Query<&Parent>,
+    mut event_writer: EventWriter<DroppedEvent>,
+) {
+    let target = trigger.entity();
+    let Ok(drop_target) = targets.get(target) else {
+        return;
+    };
+    let Some(source) = draggable_ancestor(trigger.dropped, &draggables, &parents) else {
+        return;
+    };
+    let Ok(draggable) = draggables.get(source) else {
+        return;
+    };
+    if source == target || !drop_target.accepts(&draggable.payload) {
+        return;
+    }
+
+    trigger.propagate(false);
+    commands.entity(target).remove::<DropHover>();
+    event_writer.send(DroppedEvent {
+        source,
+        target,
+        payload: draggable.payload.clone(),
+    });
+}
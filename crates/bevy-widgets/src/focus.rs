@@ -1,9 +1,12 @@
 use bevy::app::{App, Plugin};
+use bevy::color::Color;
 use bevy::ecs::{
+    bundle::Bundle,
     observer::Trigger,
-    prelude::{Component, Entity, Event},
-    query::With,
-    system::{Commands, Query},
+    prelude::{Component, Entity, Event, OnAdd, OnRemove, ReflectComponent},
+    query::{With, Without},
+    schedule::IntoSystemConfigs,
+    system::{Commands, Query, Res, Resource},
 };
 use bevy::input::ButtonInput;
 use bevy::picking::{
@@ -11,6 +14,13 @@ use bevy::picking::{
     prelude::{Click, Pointer},
 };
 use bevy::reflect::Reflect;
+use bevy::ui::{Outline, Val};
+
+use crate::WidgetsSystems;
+
+const FOCUS_RING_COLOR: Color = Color::srgb(0.77, 0.9, 1.0);
+const FOCUS_RING_WIDTH: f32 = 2.0;
+const FOCUS_RING_OFFSET: f32 = 1.0;
 
 /// Plugin containing the focus system logic
 pub struct FocusPlugin;
@@ -20,20 +30,28 @@ impl Plugin for FocusPlugin {
         app.add_event::<SetFocus>()
             .add_event::<ClearFocus>()
             .add_event::<GotFocus>()
-            .add_event::<LostFocus>();
+            .add_event::<LostFocus>()
+            .init_resource::<FocusRingStyle>()
+            .register_type::<NoFocusRing>()
+            .register_type::<EventPropagation>()
+            .register_type::<FocusScope>();
 
         app.add_systems(
             bevy::app::Update,
-            |mut commands: Commands,
-             input: bevy::ecs::system::Res<ButtonInput<bevy::input::keyboard::KeyCode>>| {
+            (|mut commands: Commands,
+              input: bevy::ecs::system::Res<ButtonInput<bevy::input::keyboard::KeyCode>>| {
                 if input.just_pressed(bevy::input::keyboard::KeyCode::Escape) {
                     commands.trigger_targets(ClearFocus, Entity::PLACEHOLDER);
                 }
-            },
+            })
+            .in_set(WidgetsSystems::Input),
         );
+        WidgetsSystems::configure(app);
         app.add_observer(set_focus)
             .add_observer(clear_focus)
-            .add_observer(mouse_click);
+            .add_observer(mouse_click)
+            .add_observer(add_focus_ring)
+            .add_observer(remove_focus_ring);
     }
 }
 
@@ -45,6 +63,79 @@ pub struct Focus;
 #[derive(Component, Reflect, Default)]
 pub struct Clickable;
 
+/// Whether pointer events handled by a widget reach its ancestors.
+///
+/// Widgets stop the clicks and hovers they handle by default. Set [`EventPropagation::Bubble`]
+/// on a widget to let its containers, like a selectable list row around a button, see them too.
+/// > Bubbling clicks on a [`Clickable`] widget keep the focus on the widget.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component)]
+pub enum EventPropagation {
+    /// Events handled by the widget stop at the widget
+    #[default]
+    Stop,
+    /// Events handled by the widget continue to its ancestors
+    Bubble,
+}
+
+/// Stops `trigger` at its target, unless the target has [`EventPropagation::Bubble`]
+pub(crate) fn stop_propagation<E: Event, B: Bundle>(
+    trigger: &mut Trigger<E, B>,
+    propagation: &Query<&EventPropagation>,
+) {
+    let bubble = propagation
+        .get(trigger.entity())
+        .is_ok_and(|propagation| *propagation == EventPropagation::Bubble);
+    trigger.propagate(bubble);
+}
+
+/// Opts a widget out of the focus ring drawn by [`FocusPlugin`].
+/// > Useful for widgets that already express focus through their own styling.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct NoFocusRing;
+
+/// Groups the fields of a form.
+///
+/// `Tab` and `Shift` + `Tab` move focus between the text and numeric fields inside the scope, in hierarchy order,
+/// as does submitting a field built with `focus_next_on_submit`. Fields outside of any scope move focus within
+/// their root entity.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct FocusScope;
+
+/// Marks an [`Outline`] drawn by the focus ring, holding the widget's own outline replaced by it
+#[derive(Component, Debug, Clone, Copy)]
+struct FocusRingOutline(Option<Outline>);
+
+/// Style of the outline drawn around any focused widget.
+/// Defaults to the design system focus color with a 2px ring and 1px offset.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct FocusRingStyle {
+    /// Outline width
+    pub width: Val,
+    /// Space between the widget border and the outline
+    pub offset: Val,
+    /// Outline color
+    pub color: Color,
+}
+
+impl Default for FocusRingStyle {
+    fn default() -> Self {
+        Self {
+            width: Val::Px(FOCUS_RING_WIDTH),
+            offset: Val::Px(FOCUS_RING_OFFSET),
+            color: FOCUS_RING_COLOR,
+        }
+    }
+}
+
+impl From<FocusRingStyle> for Outline {
+    fn from(style: FocusRingStyle) -> Self {
+        Self::new(style.width, style.offset, style.color)
+    }
+}
+
 /// Event indicating that a widget has received focus event due to click.
 /// - Needs manual implementation to react to this triggered event.
 /// > Only works automatically if the widget has the [`Clickable`] component
@@ -119,6 +210,7 @@ fn mouse_click(
     mut commands: Commands,
     clickable_entities: Query<Entity, With<Clickable>>,
     focus_entities: Query<Entity, With<Focus>>,
+    propagation: Query<&EventPropagation>,
 ) {
     if click.event().button != PointerButton::Primary {
         return;
@@ -126,7 +218,12 @@ fn mouse_click(
 
     let entity = click.entity();
     if clickable_entities.contains(entity) {
-        click.propagate(false);
+        stop_propagation(&mut click, &propagation);
+        // Bubbling clicks keep the focus on the clicked widget
+        let target = click.event().target;
+        if target != entity && clickable_entities.contains(target) {
+            return;
+        }
 
         for e in focus_entities.iter() {
             if e == entity {
@@ -139,3 +236,36 @@ fn mouse_click(
         commands.trigger_targets(GotFocus(Some(click.event().clone())), entity);
     }
 }
+
+fn add_focus_ring(
+    trigger: Trigger<OnAdd, Focus>,
+    mut commands: Commands,
+    style: Res<FocusRingStyle>,
+    widgets: Query<Option<&Outline>, Without<NoFocusRing>>,
+) {
+    let entity = trigger.entity();
+    let Ok(outline) = widgets.get(entity) else {
+        return;
+    };
+    commands
+        .entity(entity)
+        .try_insert((Outline::from(*style), FocusRingOutline(outline.copied())));
+}
+
+// Only touches outlines drawn by the focus ring, restoring the widget's own outline
+fn remove_focus_ring(
+    trigger: Trigger<OnRemove, Focus>,
+    mut commands: Commands,
+    rings: Query<&FocusRingOutline>,
+) {
+    let entity = trigger.entity();
+    let Ok(ring) = rings.get(entity) else {
+        return;
+    };
+    let mut entity_commands = commands.entity(entity);
+    entity_commands.remove::<FocusRingOutline>();
+    match ring.0 {
+        Some(outline) => entity_commands.try_insert(outline),
+        None => entity_commands.remove::<Outline>(),
+    };
+}
@@ -0,0 +1,288 @@
+use bevy::image::ImageSampler;
+use bevy::prelude::*;
+use bevy::render::{
+    render_asset::RenderAssetUsages,
+    render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use bevy::ui::widget::NodeImageMode;
+
+use crate::focus::{stop_propagation, EventPropagation};
+use crate::overlay::{OverlayPlugin, UiLayer};
+
+const CHECKER_HANDLE: Handle<Image> = Handle::weak_from_u128(73120487529613340984);
+const CHECKER_LIGHT: [u8; 4] = [204, 204, 204, 255];
+const CHECKER_DARK: [u8; 4] = [153, 153, 153, 255];
+const CHECKER_TILE: f32 = 4.0;
+
+const THUMBNAIL_SIZE: f32 = 64.0;
+const MODAL_BACKDROP_COLOR: Color = Color::srgba(0., 0., 0., 0.7);
+const INFO_COLOR: Color = Color::srgb(0.49, 0.53, 0.55);
+const LINK_COLOR: Color = Color::srgb(0.19, 0.49, 0.71);
+const HOVERED_LINK_COLOR: Color = Color::srgb(0.51, 0.79, 1.);
+const FONT_SIZE: f32 = 10.0;
+
+/// Plugin containing the image preview logic
+pub struct ImagePreviewPlugin;
+
+impl Plugin for ImagePreviewPlugin {
+    fn build(&self, app: &mut App) {
+        OverlayPlugin::configure(app);
+        app.add_systems(Startup, insert_checker_image)
+            .add_observer(create_image_preview)
+            .add_observer(on_open_click)
+            .add_observer(on_modal_click)
+            .add_systems(
+                Update,
+                (update_image_previews, update_image_info, open_button_hover),
+            );
+    }
+}
+
+/// Thumbnail of an image over a checkerboard showing its transparency,
+/// with its dimensions and format, and a button opening a larger preview.
+#[derive(Component, Debug, Clone, Default)]
+#[require(Node)]
+pub struct ImagePreview {
+    /// Image previewed
+    pub image: Handle<Image>,
+}
+
+impl ImagePreview {
+    /// Creates a preview of `image`
+    pub const fn new(image: Handle<Image>) -> Self {
+        Self { image }
+    }
+}
+
+#[derive(Component, Debug)]
+struct ImagePreviewThumbnail;
+
+#[derive(Component, Debug)]
+struct ImagePreviewInfo;
+
+/// Button opening the [`ImagePreviewModal`] of an [`ImagePreview`]
+#[derive(Component, Debug)]
+#[require(Interaction)]
+pub struct ImagePreviewOpenButton {
+    preview: Entity,
+}
+
+/// Full window preview of an image, closed by clicking it
+#[derive(Component, Debug)]
+pub struct ImagePreviewModal;
+
+fn insert_checker_image(images: Option<ResMut<Assets<Image>>>) {
+    let Some(mut images) = images else {
+        return;
+    };
+    let data = [CHECKER_LIGHT, CHECKER_DARK, CHECKER_DARK, CHECKER_LIGHT].concat();
+    let mut image = Image::new(
+        Extent3d {
+            width: 2,
+            height: 2,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    );
+    image.sampler = ImageSampler::nearest();
+    images.insert(&CHECKER_HANDLE, image);
+}
+
+fn checkerboard() -> ImageNode {
+    ImageNode::new(CHECKER_HANDLE).with_mode(NodeImageMode::Tiled {
+        tile_x: true,
+        tile_y: true,
+        stretch_value: CHECKER_TILE,
+    })
+}
+
+fn create_image_preview(
+    trigger: Trigger<OnAdd, ImagePreview>,
+    mut commands: Commands,
+    mut previews: Query<(&ImagePreview, &mut Node)>,
+) {
+    let entity = trigger.entity();
+    let Ok((preview, mut node)) = previews.get_mut(entity) else {
+        return;
+    };
+    node.flex_direction = FlexDirection::Row;
+    node.column_gap = Val::Px(8.);
+    node.align_items = AlignItems::Center;
+
+    let text_font = TextFont {
+        font_size: FONT_SIZE,
+        ..default()
+    };
+    commands.entity(entity).with_children(|parent| {
+        parent
+            .spawn((
+                Node {
+                    width: Val::Px(THUMBNAIL_SIZE),
+                    height: Val::Px(THUMBNAIL_SIZE),
+                    flex_shrink: 0.,
+                    ..default()
+                },
+                checkerboard(),
+            ))
+            .with_child((
+                ImagePreviewThumbnail,
+                ImageNode::new(preview.image.clone()),
+                Node {
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    ..default()
+                },
+                PickingBehavior::IGNORE,
+            ));
+        parent
+            .spawn(Node {
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.),
+                ..default()
+            })
+            .with_children(|column| {
+                column.spawn((
+                    ImagePreviewInfo,
+                    Text::new("Loading..."),
+                    TextColor(INFO_COLOR),
+                    text_font.clone(),
+                ));
+                column.spawn((
+                    ImagePreviewOpenButton { preview: entity },
+                    Text::new("Open preview"),
+                    TextColor(LINK_COLOR),
+                    text_font,
+                ));
+            });
+    });
+}
+
+fn update_image_previews(
+    previews: Query<(Entity, &ImagePreview), Changed<ImagePreview>>,
+    children: Query<&Children>,
+    mut thumbnails: Query<&mut ImageNode, With<ImagePreviewThumbnail>>,
+) {
+    for (entity, preview) in &previews {
+        for descendant in children.iter_descendants(entity) {
+            if let Ok(mut image) = thumbnails.get_mut(descendant) {
+                if image.image != preview.image {
+                    image.image = preview.image.clone();
+                }
+            }
+        }
+    }
+}
+
+fn image_info(image: Option<&Image>) -> String {
+    image.map_or_else(
+        || "Loading...".to_string(),
+        |image| {
+            let size = image.size();
+            format!(
+                "{}x{} {:?}",
+                size.x, size.y, image.texture_descriptor.format
+            )
+        },
+    )
+}
+
+fn update_image_info(
+    images: Res<Assets<Image>>,
+    previews: Query<(Entity, &ImagePreview)>,
+    children: Query<&Children>,
+    mut infos: Query<&mut Text, With<ImagePreviewInfo>>,
+) {
+    for (entity, preview) in &previews {
+        let info = image_info(images.get(&preview.image));
+        for descendant in children.iter_descendants(entity) {
+            if let Ok(mut text) = infos.get_mut(descendant) {
+                if text.0 != info {
+                    text.0.clone_from(&info);
+                }
+            }
+        }
+    }
+}
+
+fn open_button_hover(
+    mut buttons: Query<
+        (&Interaction, &mut TextColor),
+        (Changed<Interaction>, With<ImagePreviewOpenButton>),
+    >,
+) {
+    for (interaction, mut color) in &mut buttons {
+        color.0 = if *interaction == Interaction::None {
+            LINK_COLOR
+        } else {
+            HOVERED_LINK_COLOR
+        };
+    }
+}
+
+fn on_open_click(
+    mut trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    buttons: Query<&ImagePreviewOpenButton>,
+    previews: Query<&ImagePreview>,
+    propagation: Query<&EventPropagation>,
+) {
+    let Ok(button) = buttons.get(trigger.entity()) else {
+        return;
+    };
+    stop_propagation(&mut trigger, &propagation);
+    let Ok(preview) = previews.get(button.preview) else {
+        return;
+    };
+
+    commands
+        .spawn((
+            ImagePreviewModal,
+            Name::new("ImagePreviewModal"),
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(MODAL_BACKDROP_COLOR),
+            UiLayer::Modal,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        max_width: Val::Percent(80.),
+                        max_height: Val::Percent(80.),
+                        ..default()
+                    },
+                    checkerboard(),
+                    PickingBehavior::IGNORE,
+                ))
+                .with_child((
+                    ImageNode::new(preview.image.clone()),
+                    Node {
+                        max_width: Val::Percent(100.),
+                        max_height: Val::Percent(100.),
+                        ..default()
+                    },
+                    PickingBehavior::IGNORE,
+                ));
+        });
+}
+
+fn on_modal_click(
+    mut trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    modals: Query<(), With<ImagePreviewModal>>,
+) {
+    let entity = trigger.entity();
+    if modals.contains(entity) {
+        trigger.propagate(false);
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,176 @@
+use bevy::prelude::*;
+
+use crate::focus::{Focus, FocusExt};
+use crate::input_fields::{
+    builder::TextInputBuilder, components::InputTextValue, InputFieldSize, InputFieldSubmitEvent,
+};
+
+/// Seconds between the two clicks of a double click
+const DOUBLE_CLICK_DURATION: f32 = 0.4;
+
+/// Plugin containing the inline renaming logic
+pub struct InlineEditPlugin;
+
+impl Plugin for InlineEditPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<InlineEditCommitted>()
+            .add_observer(on_label_click)
+            .add_observer(finish_inline_edit)
+            .add_systems(Update, blur_on_submit);
+    }
+}
+
+/// Lets a [`Text`] label be renamed in place.
+///
+/// Double-clicking the label swaps it for a text field filled with its text. Enter or leaving the field
+/// commits the new text, written to the label and as the [`Name`] of the target entity, and sends an
+/// [`InlineEditCommitted`] event. Escape cancels. Empty texts are not committed.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::inline_edit::InlineEdit;
+/// fn spawn_row(mut commands: Commands, entity: Entity) {
+///     commands.spawn((Text::new("Player"), InlineEdit::new(entity)));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+#[require(Text)]
+pub struct InlineEdit {
+    target: Entity,
+    last_click: Option<f32>,
+    /// Field replacing the label and the display of the label before editing
+    editing: Option<(Entity, Display)>,
+}
+
+impl InlineEdit {
+    /// Renames `target` from the label
+    pub const fn new(target: Entity) -> Self {
+        Self {
+            target,
+            last_click: None,
+            editing: None,
+        }
+    }
+
+    /// Entity renamed from the label
+    pub const fn target(&self) -> Entity {
+        self.target
+    }
+
+    /// Whether the label is being edited
+    pub const fn is_editing(&self) -> bool {
+        self.editing.is_some()
+    }
+}
+
+/// Event sent when the text of an [`InlineEdit`] label is committed
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct InlineEditCommitted {
+    /// Entity of the label
+    pub label: Entity,
+    /// Entity renamed from the label
+    pub target: Entity,
+    /// New text of the label and name of the target
+    pub value: String,
+}
+
+/// Text field editing an [`InlineEdit`] label
+#[derive(Component, Debug, Clone, Copy)]
+struct InlineEditField {
+    label: Entity,
+}
+
+fn on_label_click(
+    trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    mut labels: Query<(&mut InlineEdit, &Text, &mut Node)>,
+    parents: Query<&Parent>,
+    children: Query<&Children>,
+    time: Res<Time<Real>>,
+) {
+    let entity = trigger.entity();
+    let Ok((mut edit, text, mut node)) = labels.get_mut(entity) else {
+        return;
+    };
+    if trigger.event().button != PointerButton::Primary || edit.is_editing() {
+        return;
+    }
+    let now = time.elapsed_secs();
+    let double_click = edit
+        .last_click
+        .is_some_and(|last| now - last <= DOUBLE_CLICK_DURATION);
+    edit.last_click = (!double_click).then_some(now);
+    if !double_click {
+        return;
+    }
+
+    let field = commands
+        .spawn((
+            InlineEditField { label: entity },
+            TextInputBuilder::default()
+                .with_size(InputFieldSize::Small)
+                .with_initial_value(text.0.clone())
+                .build(),
+        ))
+        .id();
+    // Takes the place of the label
+    if let Ok(parent) = parents.get(entity) {
+        let index = children
+            .get(parent.get())
+            .ok()
+            .and_then(|children| children.iter().position(|child| *child == entity))
+            .map_or(0, |index| index + 1);
+        commands
+            .entity(parent.get())
+            .insert_children(index, &[field]);
+    }
+    commands.set_focus(field);
+    edit.editing = Some((field, node.display));
+    node.display = Display::None;
+}
+
+fn blur_on_submit(
+    mut commands: Commands,
+    mut submit_reader: EventReader<InputFieldSubmitEvent>,
+    fields: Query<(), (With<InlineEditField>, With<Focus>)>,
+) {
+    for event in submit_reader.read() {
+        if fields.contains(event.entity) {
+            commands.entity(event.entity).remove::<Focus>();
+        }
+    }
+}
+
+// Enter blurs the field, so every edit ends here
+fn finish_inline_edit(
+    trigger: Trigger<OnRemove, Focus>,
+    mut commands: Commands,
+    fields: Query<(&InlineEditField, &InputTextValue)>,
+    mut labels: Query<(&mut InlineEdit, &mut Text, &mut Node)>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut event_writer: EventWriter<InlineEditCommitted>,
+) {
+    let field = trigger.entity();
+    let Ok((edit_field, value)) = fields.get(field) else {
+        return;
+    };
+    commands.entity(field).despawn_recursive();
+    let Ok((mut edit, mut text, mut node)) = labels.get_mut(edit_field.label) else {
+        return;
+    };
+    if let Some((_, display)) = edit.editing.take() {
+        node.display = display;
+    }
+    let value = value.0.trim();
+    if keys.just_pressed(KeyCode::Escape) || value.is_empty() || text.0 == value {
+        return;
+    }
+    text.0 = value.to_string();
+    commands
+        .entity(edit.target)
+        .try_insert(Name::new(value.to_string()));
+    event_writer.send(InlineEditCommitted {
+        label: edit_field.label,
+        target: edit.target,
+        value: value.to_string(),
+    });
+}
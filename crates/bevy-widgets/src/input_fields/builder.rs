@@ -2,13 +2,19 @@ use std::ops::{Bound, RangeBounds};
 
 use super::{
     components::{
-        numeric::{NumericDelta, NumericField, NumericFieldValue},
-        text::{Placeholder, TextInputDescriptions},
+        numeric::{DragCursorMode, DragResponse, NumericDelta, NumericField, NumericFieldValue},
+        suggestions::{SuggestionProvider, TextInputSuggestions},
+        text::{Placeholder, TextInputDescriptions, TextInputParts},
         AllowedCharSet, InputFieldSize, InputFieldState,
     },
     InputFieldSettings, InputTextColor, InputTextFont, InputTextValue, NumericInput, TextInput,
 };
+use crate::{
+    localization::{LocalizedString, LocalizedTexts},
+    widget_ref::{WidgetRef, WidgetSpawner},
+};
 use bevy::prelude::*;
+use num_traits::NumCast;
 
 /// Text input validation callback
 pub type ValidationCallback = fn(&str) -> bool;
@@ -73,7 +79,10 @@ pub struct TextInputBuilder {
     hint_text: Option<String>,
     mask: Option<char>,
     retain_on_submit: bool,
+    focus_next_on_submit: bool,
     value: String,
+    suggestions: Option<SuggestionProvider>,
+    localized: LocalizedTexts,
 }
 
 impl Default for TextInputBuilder {
@@ -85,7 +94,10 @@ impl Default for TextInputBuilder {
             hint_text: None,
             mask: None,
             retain_on_submit: true,
+            focus_next_on_submit: false,
             value: String::new(),
+            suggestions: None,
+            localized: LocalizedTexts::default(),
         }
     }
 }
@@ -97,6 +109,12 @@ impl TextInputBuilder {
         self
     }
 
+    /// Moves focus to the next field of the [`FocusScope`](crate::focus::FocusScope) on submit
+    pub const fn focus_next_on_submit(mut self) -> Self {
+        self.focus_next_on_submit = true;
+        self
+    }
+
     /// Sets mask to password
     pub const fn password(mut self) -> Self {
         self.mask = Some('*');
@@ -115,9 +133,12 @@ impl TextInputBuilder {
         self
     }
 
-    /// Adds a label to the text field
-    pub fn with_label(mut self, label: String) -> Self {
-        self.label = Some(label);
+    /// Adds a label to the text field, a literal or a [`LocalizedString::Key`]
+    pub fn with_label(mut self, label: impl Into<LocalizedString>) -> Self {
+        self.label = Some(LocalizedTexts::track(
+            &mut self.localized.label,
+            label.into(),
+        ));
         self
     }
 
@@ -127,15 +148,27 @@ impl TextInputBuilder {
         self
     }
 
-    /// Adds a placeholder to the text field
-    pub fn with_placeholder(mut self, placeholder: String) -> Self {
-        self.placeholder = Some(placeholder);
+    /// Adds a placeholder to the text field, a literal or a [`LocalizedString::Key`]
+    pub fn with_placeholder(mut self, placeholder: impl Into<LocalizedString>) -> Self {
+        self.placeholder = Some(LocalizedTexts::track(
+            &mut self.localized.placeholder,
+            placeholder.into(),
+        ));
+        self
+    }
+
+    /// Adds a hint text to the text field, a literal or a [`LocalizedString::Key`]
+    pub fn with_hint_text(mut self, hint_text: impl Into<LocalizedString>) -> Self {
+        self.hint_text = Some(LocalizedTexts::track(
+            &mut self.localized.hint,
+            hint_text.into(),
+        ));
         self
     }
 
-    /// Adds a hint text to the text field
-    pub fn with_hint_text(mut self, hint_text: String) -> Self {
-        self.hint_text = Some(hint_text);
+    /// Lists completions of the typed value from `provider` in a popup under the text field
+    pub fn with_suggestions(mut self, provider: impl Into<SuggestionProvider>) -> Self {
+        self.suggestions = Some(provider.into());
         self
     }
 
@@ -156,10 +189,13 @@ impl TextInputBuilder {
         InputFieldSize,
         Placeholder,
         TextInputDescriptions,
+        TextInputSuggestions,
+        LocalizedTexts,
     ) {
         let settings = InputFieldSettings {
             retain_on_submit: self.retain_on_submit,
             mask_character: self.mask,
+            focus_next_on_submit: self.focus_next_on_submit,
         };
         let color = InputTextColor(self.size.default_text_color());
         let font = InputTextFont(self.size.default_text_font());
@@ -190,12 +226,129 @@ impl TextInputBuilder {
             self.size,
             placeholder,
             extras,
+            TextInputSuggestions {
+                provider: self.suggestions,
+                ..default()
+            },
+            self.localized,
         )
     }
+
+    /// Spawns the text field, returning its root, inner text, label and hint entities
+    ///
+    /// Works from [`Commands`] and from a [`ChildBuilder`].
+    pub fn spawn(self, spawner: &mut impl WidgetSpawner) -> WidgetRef {
+        let (label, hint) = (self.label.is_some(), self.hint_text.is_some());
+        let mut root = spawner.spawn_root();
+        let parts = TextInputParts::reserve(&mut root.commands(), label, hint);
+        root.insert((self.build(), parts));
+        WidgetRef {
+            root: root.id(),
+            text: parts.inner,
+            label: parts.label,
+            hint: parts.hint,
+        }
+    }
+}
+
+/// Components of a numeric field, built by [`NumericFieldBuilder`]
+pub type NumericFieldBundle<T> = (
+    NumericInput,
+    AllowedCharSet,
+    NumericField<T>,
+    Node,
+    BorderRadius,
+    BorderColor,
+    BackgroundColor,
+    InputFieldSettings,
+    InputTextColor,
+    InputTextFont,
+    InputTextValue,
+    InputFieldSize,
+    InputFieldState,
+    NumericDelta,
+);
+
+/// Invalid widget builder configuration, returned by `try_build` methods
+#[derive(Debug, Clone, PartialEq)]
+pub enum WidgetBuildError {
+    /// The range doesn't contain any value
+    EmptyRange {
+        /// Range start
+        min: String,
+        /// Range end
+        max: String,
+    },
+    /// The initial value is outside of the range
+    ValueOutOfRange {
+        /// Initial value
+        value: String,
+        /// Range start
+        min: Option<String>,
+        /// Range end
+        max: Option<String>,
+    },
+    /// Dragging wouldn't change the value
+    ZeroDragStep,
+    /// The snapping step isn't positive
+    InvalidStep,
+    /// The minimum width is larger than the maximum width
+    InvertedWidths {
+        /// Minimum width
+        min_width: f32,
+        /// Maximum width
+        max_width: f32,
+    },
+    /// The width is outside of the minimum and maximum widths
+    WidthOutOfBounds {
+        /// Width
+        width: f32,
+        /// Minimum width
+        min_width: Option<f32>,
+        /// Maximum width
+        max_width: Option<f32>,
+    },
 }
 
+impl std::fmt::Display for WidgetBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyRange { min, max } => {
+                write!(f, "range from {min} to {max} doesn't contain any value")
+            }
+            Self::ValueOutOfRange { value, min, max } => write!(
+                f,
+                "initial value {value} is outside of the range {}..{}",
+                min.as_deref().unwrap_or_default(),
+                max.as_deref().unwrap_or_default()
+            ),
+            Self::ZeroDragStep => {
+                f.write_str("drag step is zero, dragging wouldn't change the value")
+            }
+            Self::InvalidStep => f.write_str("snapping step must be positive"),
+            Self::InvertedWidths {
+                min_width,
+                max_width,
+            } => write!(
+                f,
+                "minimum width {min_width} is larger than the maximum width {max_width}"
+            ),
+            Self::WidthOutOfBounds {
+                width,
+                min_width,
+                max_width,
+            } => write!(
+                f,
+                "width {width} is outside of the bounds {min_width:?}..={max_width:?}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WidgetBuildError {}
+
 /// Numeric field Builder
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct NumericFieldBuilder<T: NumericFieldValue> {
     /// Current value
     pub(crate) value: Option<T>,
@@ -207,6 +360,12 @@ pub struct NumericFieldBuilder<T: NumericFieldValue> {
     pub(crate) end_inclusive: bool,
     /// Value change per logical pixel during mouse drag
     pub(crate) drag_step: Option<T>,
+    /// Committed values snap to multiples of the step from `min`
+    pub(crate) step: Option<T>,
+    /// Pointer behavior during mouse drag
+    pub(crate) drag_cursor: DragCursorMode,
+    /// Value change relative to the dragged distance
+    pub(crate) drag_response: DragResponse,
     /// Numeric field size
     pub(crate) size: InputFieldSize,
     /// Max allowed width for component
@@ -217,17 +376,22 @@ pub struct NumericFieldBuilder<T: NumericFieldValue> {
     width: Option<f32>,
     mask: Option<char>,
     retain_on_submit: bool,
+    focus_next_on_submit: bool,
 }
 
 impl<T: NumericFieldValue> Default for NumericFieldBuilder<T> {
     fn default() -> Self {
         Self {
             retain_on_submit: true,
+            focus_next_on_submit: false,
             value: None,
             min: None,
             max: None,
             end_inclusive: false,
             drag_step: None,
+            step: None,
+            drag_cursor: DragCursorMode::Free,
+            drag_response: DragResponse::Linear,
             size: InputFieldSize::Medium,
             mask: None,
             max_width: None,
@@ -249,7 +413,7 @@ impl<T: NumericFieldValue> NumericFieldBuilder<T> {
     /// # Returns
     ///
     /// Returns an updated instance of `NumericFieldBuilder` with the initial value set.
-    pub const fn with_initial_value(mut self, value: T) -> Self {
+    pub fn with_initial_value(mut self, value: T) -> Self {
         self.value = Some(value);
         self
     }
@@ -260,6 +424,12 @@ impl<T: NumericFieldValue> NumericFieldBuilder<T> {
         self
     }
 
+    /// Moves focus to the next field of the [`FocusScope`](crate::focus::FocusScope) on enter
+    pub const fn focus_next_on_submit(mut self) -> Self {
+        self.focus_next_on_submit = true;
+        self
+    }
+
     /// Sets the mask character
     pub const fn with_mask(mut self, mask: char) -> Self {
         self.mask = Some(mask);
@@ -267,6 +437,7 @@ impl<T: NumericFieldValue> NumericFieldBuilder<T> {
     }
 
     /// Sets the bounds of the numeric field, allowing you to specify a range of values which can be input into the field.
+    /// Without initial value, fields whose default value is outside of the range start at its minimum.
     ///
     /// The `range` parameter should be a type which implements the `RangeBounds` trait, such as `std::ops::Range<T>`
     /// or `std::ops::RangeInclusive<T>`.
@@ -280,11 +451,11 @@ impl<T: NumericFieldValue> NumericFieldBuilder<T> {
     /// - ..
     pub fn with_range(mut self, range: impl RangeBounds<T>) -> Self {
         self.min = match range.start_bound() {
-            Bound::Excluded(min) | Bound::Included(min) => Some(*min),
+            Bound::Excluded(min) | Bound::Included(min) => Some(min.clone()),
             Bound::Unbounded => None,
         };
         self.max = match range.end_bound() {
-            Bound::Excluded(max) | Bound::Included(max) => Some(*max),
+            Bound::Excluded(max) | Bound::Included(max) => Some(max.clone()),
             Bound::Unbounded => None,
         };
         self.end_inclusive = match range.end_bound() {
@@ -298,11 +469,35 @@ impl<T: NumericFieldValue> NumericFieldBuilder<T> {
     /// Sets the amount of change to the value when the user drags the numeric field (click and drag up or down).
     ///
     /// This is useful for creating a finer-grained control over the numeric field's value.
-    pub const fn with_drag_step(mut self, drag_step: T) -> Self {
+    pub fn with_drag_step(mut self, drag_step: T) -> Self {
         self.drag_step = Some(drag_step);
         self
     }
 
+    /// Snaps committed values to multiples of `step` counted from the range start, or from zero, e.g. `0.25` increments.
+    ///
+    /// Typed values snap once they parse and are displayed snapped when editing ends,
+    /// and drags move the value by whole steps.
+    pub fn with_step(mut self, step: T) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Sets the pointer behavior during mouse drags, so long drags aren't limited by the window size.
+    ///
+    /// [`DragCursorMode::Locked`] hides the pointer and restores its position on release,
+    /// [`DragCursorMode::Wrap`] moves it to the opposite edge of the window when it reaches one.
+    pub const fn with_drag_cursor(mut self, drag_cursor: DragCursorMode) -> Self {
+        self.drag_cursor = drag_cursor;
+        self
+    }
+
+    /// Sets how the value changes while dragged, see [`DragResponse::Logarithmic`] for large ranges
+    pub const fn with_drag_response(mut self, drag_response: DragResponse) -> Self {
+        self.drag_response = drag_response;
+        self
+    }
+
     /// Sets the size of the numeric field.
     ///
     /// The size determines the font size, height and minimum width of the numeric field.
@@ -353,49 +548,130 @@ impl<T: NumericFieldValue> NumericFieldBuilder<T> {
         self
     }
 
-    /// Builds the numeric field
-    pub fn build(
-        self,
-    ) -> (
-        NumericInput,
-        AllowedCharSet,
-        NumericField<T>,
-        Node,
-        BorderRadius,
-        BorderColor,
-        BackgroundColor,
-        InputFieldSettings,
-        InputTextColor,
-        InputTextFont,
-        InputTextValue,
-        InputFieldSize,
-        InputFieldState,
-        NumericDelta,
-    ) {
-        let numeric_field: NumericField<T> = self.into();
+    fn contains(&self, value: &T) -> bool {
+        let below = self.min.as_ref().is_some_and(|min| value < min);
+        let above = self
+            .max
+            .as_ref()
+            .is_some_and(|max| value > max || (!self.end_inclusive && value == max));
+        !below && !above
+    }
+
+    /// Initial value, defaulting to `T::default()`, or to the range minimum when the default is outside of the range
+    pub(crate) fn initial_value(&self) -> T {
+        match (&self.value, &self.min) {
+            (Some(value), _) => value.clone(),
+            (None, Some(min)) if !self.contains(&T::default()) => min.clone(),
+            (None, _) => T::default(),
+        }
+    }
+
+    /// Checks the configuration, see [`NumericFieldBuilder::try_build`]
+    pub fn validate(&self) -> Result<(), WidgetBuildError> {
+        if let (Some(min), Some(max)) = (&self.min, &self.max) {
+            if min > max || (!self.end_inclusive && min == max) {
+                return Err(WidgetBuildError::EmptyRange {
+                    min: min.to_string(),
+                    max: max.to_string(),
+                });
+            }
+        }
+        let value = self.initial_value();
+        if !self.contains(&value) {
+            return Err(WidgetBuildError::ValueOutOfRange {
+                value: value.to_string(),
+                min: self.min.as_ref().map(ToString::to_string),
+                max: self.max.as_ref().map(ToString::to_string),
+            });
+        }
+        if self
+            .drag_step
+            .as_ref()
+            .is_some_and(|step| <T as NumCast>::from(0).is_some_and(|zero: T| *step == zero))
+        {
+            return Err(WidgetBuildError::ZeroDragStep);
+        }
+        if self
+            .step
+            .as_ref()
+            .is_some_and(|step| <T as NumCast>::from(0).is_some_and(|zero: T| *step <= zero))
+        {
+            return Err(WidgetBuildError::InvalidStep);
+        }
+        if let (Some(min_width), Some(max_width)) = (self.min_width, self.max_width) {
+            if min_width > max_width {
+                return Err(WidgetBuildError::InvertedWidths {
+                    min_width,
+                    max_width,
+                });
+            }
+        }
+        if let Some(width) = self.width {
+            let narrow = self.min_width.is_some_and(|min_width| width < min_width);
+            let wide = self.max_width.is_some_and(|max_width| width > max_width);
+            if narrow || wide {
+                return Err(WidgetBuildError::WidthOutOfBounds {
+                    width,
+                    min_width: self.min_width,
+                    max_width: self.max_width,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds the numeric field, panicking on invalid configurations.
+    ///
+    /// Use [`NumericFieldBuilder::try_build`] to handle them.
+    pub fn build(self) -> NumericFieldBundle<T> {
+        self.try_build()
+            .unwrap_or_else(|error| panic!("Invalid numeric field configuration: {error}"))
+    }
+
+    /// Builds the numeric field, or returns why its configuration is invalid:
+    /// an empty range, an initial value outside of the range, a zero drag step, a snapping step
+    /// that isn't positive, or widths contradicting each other.
+    /// ```rust
+    /// # use bevy_widgets::input_fields::builder::{NumericFieldBuilder, WidgetBuildError};
+    /// let field = NumericFieldBuilder::<u8>::default()
+    ///     .with_range(0..=10)
+    ///     .with_initial_value(20)
+    ///     .try_build();
+    /// assert!(matches!(field, Err(WidgetBuildError::ValueOutOfRange { .. })));
+    /// // Without initial value, the field starts at the range minimum
+    /// assert!(NumericFieldBuilder::<u8>::default().with_range(1..10).try_build().is_ok());
+    /// let field = NumericFieldBuilder::<i8>::default().with_range(..=-5).try_build();
+    /// assert!(matches!(field, Err(WidgetBuildError::ValueOutOfRange { .. })));
+    /// ```
+    pub fn try_build(self) -> Result<NumericFieldBundle<T>, WidgetBuildError> {
+        self.validate()?;
         let field_size = self.size;
         let state = InputFieldState::default();
         let settings = InputFieldSettings {
             retain_on_submit: self.retain_on_submit,
             mask_character: self.mask,
+            focus_next_on_submit: self.focus_next_on_submit,
         };
         let color = InputTextColor(self.size.default_text_color());
         let font = InputTextFont(self.size.default_text_font());
-        let value = InputTextValue(self.value.unwrap_or_default().to_string());
+        let value = InputTextValue(self.initial_value().to_string());
         let min_width = Val::Px(self.min_width.unwrap_or(52.));
+        let max_width = Val::Px(
+            self.max_width
+                .unwrap_or_else(|| 3. * field_size.min_width()),
+        );
+        let width = self.width.map_or(min_width, Val::Px);
+        let numeric_field: NumericField<T> = self.into();
 
-        (
+        Ok((
             NumericInput,
             T::allowed_chars(),
             numeric_field,
             Node {
                 height: Val::Px(field_size.height()),
                 min_width,
-                max_width: Val::Px(
-                    self.max_width
-                        .unwrap_or_else(|| 3. * field_size.min_width()),
-                ),
-                width: self.width.map_or(min_width, Val::Px),
+                max_width,
+                width,
                 border: UiRect::all(Val::Px(1.0)),
                 padding: field_size.padding(false),
                 justify_content: JustifyContent::End,
@@ -411,6 +687,19 @@ impl<T: NumericFieldValue> NumericFieldBuilder<T> {
             field_size,
             state,
             NumericDelta::default(),
-        )
+        ))
+    }
+
+    /// Spawns the numeric field, returning its root and inner text entities
+    ///
+    /// Works from [`Commands`] and from a [`ChildBuilder`].
+    pub fn spawn(self, spawner: &mut impl WidgetSpawner) -> WidgetRef {
+        let mut root = spawner.spawn_root();
+        let parts = TextInputParts::reserve(&mut root.commands(), false, false);
+        root.insert((self.build(), parts));
+        WidgetRef {
+            text: parts.inner,
+            ..WidgetRef::new(root.id())
+        }
     }
 }
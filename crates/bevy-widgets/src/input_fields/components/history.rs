@@ -0,0 +1,147 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+/// Recently committed values of a numeric field, listed in a dropdown behind a clock button.
+///
+/// Values are recorded when the field is created, when editing ends and when a drag ends.
+/// Picking a value sets it like typed input. Only the last `capacity` distinct values are kept.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::input_fields::{builder::NumericFieldBuilder, NumericFieldHistory};
+/// fn spawn_field(mut commands: Commands) {
+///     commands.spawn((
+///         NumericFieldBuilder::<f32>::default().build(),
+///         NumericFieldHistory::new(8),
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct NumericFieldHistory {
+    pub(crate) values: VecDeque<String>,
+    pub(crate) capacity: usize,
+    pub(crate) popup: Option<Entity>,
+}
+
+impl NumericFieldHistory {
+    /// Creates an empty history keeping at most `capacity` values
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            values: VecDeque::with_capacity(capacity),
+            capacity,
+            popup: None,
+        }
+    }
+
+    /// Values formatted like the field text, most recent first
+    pub const fn values(&self) -> &VecDeque<String> {
+        &self.values
+    }
+
+    /// Maximum number of values kept
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Records `value` as the most recent one
+    pub fn push(&mut self, value: impl Into<String>) {
+        let value = value.into();
+        if self.values.front() == Some(&value) {
+            return;
+        }
+        self.values.retain(|recorded| *recorded != value);
+        self.values.push_front(value);
+        self.values.truncate(self.capacity);
+    }
+}
+
+/// Clock button of a [`NumericFieldHistory`], toggling its dropdown
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[require(Interaction)]
+pub(crate) struct HistoryButton {
+    pub(crate) input: Entity,
+}
+
+/// A value of a [`NumericFieldHistory`] in its dropdown
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[require(Interaction)]
+pub struct HistoryItem {
+    pub(crate) input: Entity,
+    pub(crate) index: usize,
+}
+
+/// Values submitted from a text field, cycled with Up and Down while it is focused, like a console.
+///
+/// Empty values and repeats of the last value aren't recorded, only the last `capacity` values are kept.
+/// Down past the most recent value restores the text typed before browsing.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::input_fields::{builder::TextInputBuilder, TextInputHistory};
+/// fn spawn_command_line(mut commands: Commands) {
+///     commands.spawn((TextInputBuilder::default().build(), TextInputHistory::new(32)));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct TextInputHistory {
+    pub(crate) values: VecDeque<String>,
+    pub(crate) capacity: usize,
+    /// Index of the displayed value while browsing
+    pub(crate) browsing: Option<usize>,
+    /// Text typed before browsing
+    pub(crate) draft: String,
+}
+
+impl TextInputHistory {
+    /// Creates an empty history keeping at most `capacity` values
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            values: VecDeque::with_capacity(capacity),
+            capacity,
+            browsing: None,
+            draft: String::new(),
+        }
+    }
+
+    /// Submitted values, oldest first
+    pub const fn values(&self) -> &VecDeque<String> {
+        &self.values
+    }
+
+    /// Records `value` as the most recent one and stops browsing
+    pub fn push(&mut self, value: impl Into<String>) {
+        let value = value.into();
+        self.browsing = None;
+        if value.is_empty() || self.values.back() == Some(&value) || self.capacity == 0 {
+            return;
+        }
+        if self.values.len() == self.capacity {
+            self.values.pop_front();
+        }
+        self.values.push_back(value);
+    }
+
+    /// Moves to the previous value, starting from the most recent one
+    pub(crate) fn browse_previous(&mut self, current: &str) -> Option<&str> {
+        let index = match self.browsing {
+            None => {
+                self.draft = current.to_string();
+                self.values.len().checked_sub(1)?
+            }
+            Some(index) => index.saturating_sub(1),
+        };
+        self.browsing = Some(index);
+        self.values.get(index).map(String::as_str)
+    }
+
+    /// Moves to the next value, back to the draft after the most recent one
+    pub(crate) fn browse_next(&mut self) -> Option<&str> {
+        let index = self.browsing? + 1;
+        if index < self.values.len() {
+            self.browsing = Some(index);
+            self.values.get(index).map(String::as_str)
+        } else {
+            self.browsing = None;
+            Some(&self.draft)
+        }
+    }
+}
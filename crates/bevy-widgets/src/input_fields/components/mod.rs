@@ -3,7 +3,11 @@ use std::collections::BTreeSet;
 use bevy::prelude::*;
 
 use super::constants::*;
+pub mod history;
 pub mod numeric;
+pub mod pattern;
+pub mod selection;
+pub mod suggestions;
 pub mod text;
 
 /// A wrapper for Bevy `TextFont` that will be used when creating the text input's inner Bevy `TextBundle`.
@@ -27,12 +31,12 @@ impl Default for InputInactive {
 
 impl InputInactive {
     /// Toggles the `TextInputInactive` component to be active
-    pub fn active(&mut self) {
+    pub const fn active(&mut self) {
         self.0 = false;
     }
 
     /// Toggles the `TextInputInactive` component to be inactive
-    pub fn inactive(&mut self) {
+    pub const fn inactive(&mut self) {
         self.0 = true;
     }
 }
@@ -61,6 +65,9 @@ pub struct InputFieldSettings {
     pub retain_on_submit: bool,
     /// Mask text with the provided character. Defaults to `None`, when calling `.password()` it defaults to `Some('*')`.
     pub mask_character: Option<char>,
+    /// If true, focus moves to the next field of the [`FocusScope`](crate::focus::FocusScope) after pressing enter.
+    /// Defaults to false.
+    pub focus_next_on_submit: bool,
 }
 
 impl Default for InputFieldSettings {
@@ -68,14 +75,23 @@ impl Default for InputFieldSettings {
         Self {
             retain_on_submit: true,
             mask_character: None,
+            focus_next_on_submit: false,
         }
     }
 }
 
 /// A component containing the current value of the text input.
+/// > Set it with [`TextFieldExt::set_text`](crate::input_fields::TextFieldExt::set_text).
 #[derive(Component, Default, Reflect)]
 pub struct InputTextValue(pub(crate) String);
 
+impl InputTextValue {
+    /// Current text of the field
+    pub fn get(&self) -> &str {
+        &self.0
+    }
+}
+
 /// A component containing the current text cursor position.
 #[derive(Component, Default, Reflect)]
 pub struct InputTextCursorPos(pub(crate) usize);
@@ -84,7 +100,7 @@ pub struct InputTextCursorPos(pub(crate) usize);
 pub(crate) struct TextInputInner;
 
 /// An event that is fired when the user presses the enter key.
-#[derive(Event, Debug, Reflect)]
+#[derive(Event, Debug, Clone, PartialEq, Eq, Reflect)]
 pub struct InputFieldSubmitEvent {
     /// The text input that triggered the event.
     pub entity: Entity,
@@ -92,6 +108,20 @@ pub struct InputFieldSubmitEvent {
     pub value: String,
 }
 
+/// An event that is fired when the text of a text or numeric field changes, typed or set by a system.
+#[derive(Event, Debug, Clone, PartialEq, Eq, Reflect)]
+pub struct InputFieldChangedEvent {
+    /// The text input that changed.
+    pub entity: Entity,
+    /// The new text of the text input.
+    pub value: String,
+}
+
+/// Replaces the text of the targeted text or numeric field.
+/// Event to be called with `commands.set_text(entity, value)`
+#[derive(Event, Debug, Clone, PartialEq, Eq, Reflect)]
+pub struct SetInputText(pub String);
+
 /// Text fields can be classified accordingly to their height:
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Component, Reflect)]
 #[reflect(Component)]
@@ -1,19 +1,26 @@
 use bevy::prelude::*;
-use num_traits::{clamp, Bounded, CheckedAdd, CheckedSub, NumCast};
+use num_traits::{clamp, Bounded, CheckedAdd, CheckedSub, NumCast, PrimInt};
 use std::cmp::PartialOrd;
 use std::ops::{Add, Bound, Div, Mul, RangeBounds, Sub};
 use std::str::FromStr;
 
-use crate::input_fields::builder::NumericFieldBuilder;
+use bevy::ecs::{component::ComponentId, world::DeferredWorld};
+
+use crate::input_fields::{builder::NumericFieldBuilder, RegisteredNumericFields};
 
 use super::AllowedCharSet;
 
 const SIGNED_CHAR_SET: &[char] = &['-', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
 const UNSIGNED_CHAR_SET: &[char] = &['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
-const FLOAT_CHAR_SET: &[char] = &['-', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '.'];
+const FLOAT_CHAR_SET: &[char] = &[
+    '-', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '.', ',',
+];
+/// Significant digits kept below the leading digit of the step when snapping floats
+const SNAP_DIGITS: i32 = 9;
 
 /// Represents a numeric field with optional constraints
 #[derive(Component, Reflect)]
+#[component(on_add = warn_unregistered::<T>)]
 pub struct NumericField<T: NumericFieldValue> {
     /// Current value
     pub(crate) value: T,
@@ -25,23 +32,113 @@ pub struct NumericField<T: NumericFieldValue> {
     pub(crate) end_inclusive: bool,
     /// Value change per logical pixel during mouse drag
     pub(crate) drag_step: Option<T>,
+    /// Committed values snap to multiples of the step from `min`
+    pub(crate) step: Option<T>,
+    /// Pointer behavior during mouse drag
+    pub(crate) drag_cursor: DragCursorMode,
+    /// Value change relative to the dragged distance
+    pub(crate) drag_response: DragResponse,
+}
+
+fn warn_unregistered<T: NumericFieldValue>(world: DeferredWorld, _: Entity, _: ComponentId) {
+    let registered = world
+        .get_resource::<RegisteredNumericFields>()
+        .is_some_and(|registered| registered.0.contains(&std::any::TypeId::of::<T>()));
+    if !registered {
+        warn!(
+            "Numeric field of `{0}` spawned without its systems, call `app.register_numeric_field::<{0}>()`",
+            std::any::type_name::<T>()
+        );
+    }
 }
 
 impl<T: NumericFieldValue> From<NumericFieldBuilder<T>> for NumericField<T> {
     fn from(value: NumericFieldBuilder<T>) -> Self {
-        Self {
-            value: value.value.unwrap_or_default(),
+        let initial_value = value.initial_value();
+        let mut field = Self {
+            value: T::default(),
             min: value.min,
             max: value.max,
             end_inclusive: value.end_inclusive,
             drag_step: value.drag_step.or_else(|| Some(T::default_drag_step())),
+            step: value.step,
+            drag_cursor: value.drag_cursor,
+            drag_response: value.drag_response,
+        };
+        field.value = field.snap_value(initial_value);
+        field
+    }
+}
+
+/// Decimal separator used to display the values of numeric fields.
+///
+/// Typed values accept both `.` and `,` as decimal separator, unless the text contains both.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Resource)]
+pub struct NumberLocale {
+    /// Decimal separator of displayed values. Defaults to `.`.
+    pub decimal_separator: char,
+}
+
+impl Default for NumberLocale {
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+        }
+    }
+}
+
+impl NumberLocale {
+    /// Locale using `,` as decimal separator
+    pub const fn decimal_comma() -> Self {
+        Self {
+            decimal_separator: ',',
         }
     }
+
+    /// Rewrites typed text with `.` as decimal separator, `None` when the separator is ambiguous
+    pub fn normalize(&self, text: &str) -> Option<String> {
+        let text = text.trim();
+        if text.contains('.') && text.contains(',') {
+            return None;
+        }
+        Some(text.replace(',', "."))
+    }
+
+    /// Rewrites a formatted number with the decimal separator of the locale
+    pub fn localize(&self, number: &str) -> String {
+        number.replace('.', &self.decimal_separator.to_string())
+    }
+}
+
+/// An event that is fired when a value typed in a [`NumericField<T>`] is out of its bounds and gets clamped.
+/// > The field also flashes in the [`InputFieldState::Warning`](super::InputFieldState::Warning) state.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfRangeEvent<T: NumericFieldValue> {
+    /// The numeric field that received the value
+    pub entity: Entity,
+    /// The typed value
+    pub attempted: T,
+    /// The value of the field after clamping
+    pub clamped: T,
 }
 
-/// Trait defining requirements for numeric field values
+/// Warning state shown on a numeric field after an out of range value, restoring `previous` when finished
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub(crate) struct OutOfRangeFlash {
+    pub(crate) timer: Timer,
+    pub(crate) previous: super::InputFieldState,
+}
+
+/// Trait defining requirements for numeric field values.
+///
+/// Implemented for the primitive integer and float types. Custom numeric types, like fixed-point
+/// numbers or a `Degrees(f32)` newtype, only need the operator and conversion traits: every method
+/// has a default, typing signed decimal numbers and dragging by steps of `1`.
+/// Register their systems with [`NumericFieldAppExt::register_numeric_field`](crate::input_fields::NumericFieldAppExt::register_numeric_field),
+/// primitive types are registered by [`InputFieldPlugin`](crate::input_fields::InputFieldPlugin).
 pub trait NumericFieldValue:
-    Copy
+    Clone
     + PartialOrd
     + Add<Output = Self>
     + Sub<Output = Self>
@@ -58,15 +155,73 @@ pub trait NumericFieldValue:
     + Default
 {
     /// Default change per logical pixel during dragging
-    fn default_drag_step() -> Self;
+    fn default_drag_step() -> Self {
+        <Self as NumCast>::from(1).unwrap_or_default()
+    }
 
     /// Chars allowed in text field for this type
-    fn allowed_chars() -> AllowedCharSet;
+    fn allowed_chars() -> AllowedCharSet {
+        AllowedCharSet::new(FLOAT_CHAR_SET.to_vec())
+    }
 
     /// Checked addition
-    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self.clone() + rhs.clone())
+    }
     /// Checked subtraction
-    fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(self.clone() - rhs.clone())
+    }
+
+    /// Parses a typed value, accepting the decimal separators of `locale`
+    fn parse_localized(text: &str, locale: &NumberLocale) -> Option<Self> {
+        locale.normalize(text)?.parse().ok()
+    }
+
+    /// Formats the value with the decimal separator of `locale`
+    fn format_localized(&self, locale: &NumberLocale) -> String {
+        locale.localize(&self.to_string())
+    }
+
+    /// Rounds the value to the nearest multiple of `step` counted from `base`, `step` is positive.
+    ///
+    /// Computed with `f64` by default, integer types snap with integer arithmetic.
+    fn snap_to_step(&self, base: &Self, step: &Self) -> Self {
+        let (Some(raw), Some(base), Some(step)) = (self.to_f64(), base.to_f64(), step.to_f64())
+        else {
+            return self.clone();
+        };
+        let snapped = ((raw - base) / step).round().mul_add(step, base);
+        // Drops the float error of the multiplication, like 0.30000000000000004
+        let precision = 10f64.powi(SNAP_DIGITS - step.log10().floor() as i32);
+        let cleaned = (snapped * precision).round() / precision;
+        let snapped = if cleaned.is_finite() {
+            cleaned
+        } else {
+            snapped
+        };
+        <Self as NumCast>::from(snapped).unwrap_or_else(|| self.clone())
+    }
+}
+
+/// Rounds `value` to the nearest multiple of `step` counted from `base`, halfway values away from `base`.
+/// `None` when the multiple overflows.
+fn snap_integer<T: PrimInt>(value: T, base: T, step: T) -> Option<T> {
+    let offset = if value >= base {
+        value.checked_sub(&base)?
+    } else {
+        base.checked_sub(&value)?
+    };
+    let remainder = offset % step;
+    let mut rounded = offset - remainder;
+    if remainder >= step - remainder {
+        rounded = rounded.checked_add(&step)?;
+    }
+    if value >= base {
+        base.checked_add(&rounded)
+    } else {
+        base.checked_sub(&rounded)
+    }
 }
 
 impl<T> NumericField<T>
@@ -74,58 +229,87 @@ where
     T: NumericFieldValue,
 {
     /// Returns the current value of the numeric field.
-    pub const fn get_value(&self) -> T {
-        self.value
+    pub fn get_value(&self) -> T {
+        self.value.clone()
     }
 
-    /// Sets the current value of the numeric field, clamping it to the bounds
-    /// specified by `min` and `max` if present. If `end_inclusive` is true, the
+    /// Sets the current value of the numeric field, snapping it to the step of the field if any,
+    /// and clamping it to the bounds specified by `min` and `max` if present. If `end_inclusive` is true, the
     /// value is inclusive of the bounds, otherwise it is exclusive. If no bounds
     /// are specified, the value is used as is.
     pub fn set_value(&mut self, value: T) {
-        let value = match (self.min, self.max, self.end_inclusive) {
-            (Some(min), Some(max), true) => clamp(value, min, max),
+        self.value = self.clamp_value(self.snap_value(value));
+    }
+
+    /// Step committed values snap to
+    pub const fn step(&self) -> Option<&T> {
+        self.step.as_ref()
+    }
+
+    /// Rounds `value` to the nearest multiple of the step counted from `min`, or from zero without `min`
+    /// ```rust
+    /// # use bevy_widgets::input_fields::{builder::NumericFieldBuilder, NumericField};
+    /// let field = NumericField::from(NumericFieldBuilder::<u64>::default().with_step(1));
+    /// assert_eq!(field.snap_value((1 << 53) + 1), (1 << 53) + 1);
+    /// let field = NumericField::from(NumericFieldBuilder::<i32>::default().with_range(-10..).with_step(4));
+    /// assert_eq!(field.snap_value(1), 2);
+    /// let field = NumericField::from(NumericFieldBuilder::<f64>::default().with_step(1e-12));
+    /// assert_eq!(field.snap_value(3.5e-12), 4e-12);
+    /// let field = NumericField::from(NumericFieldBuilder::<f64>::default().with_step(0.1));
+    /// assert_eq!(field.snap_value(0.31), 0.3);
+    /// ```
+    pub fn snap_value(&self, value: T) -> T {
+        let zero = <T as NumCast>::from(0).unwrap_or_default();
+        let Some(step) = self.step.as_ref().filter(|step| **step > zero) else {
+            return value;
+        };
+        let base = self.min.clone().unwrap_or(zero);
+        value.snap_to_step(&base, step)
+    }
+
+    /// Clamps `value` to the bounds of the numeric field, as [`NumericField::set_value`] does
+    pub fn clamp_value(&self, value: T) -> T {
+        match (&self.min, &self.max, self.end_inclusive) {
+            (Some(min), Some(max), true) => clamp(value, min.clone(), max.clone()),
             (Some(min), None, true) => {
-                if value >= min {
+                if value >= *min {
                     value
                 } else {
-                    min
+                    min.clone()
                 }
             }
             (Some(min), None, false) => {
-                if value > min {
+                if value > *min {
                     value
                 } else {
-                    min
+                    min.clone()
                 }
             }
             (None, Some(max), true) => {
-                if value <= max {
+                if value <= *max {
                     value
                 } else {
-                    max
+                    max.clone()
                 }
             }
             (None, Some(max), false) => {
-                if value < max {
+                if value < *max {
                     value
                 } else {
-                    max
+                    max.clone()
                 }
             }
             (None, None, _) => value,
             (Some(min), Some(max), false) => {
-                if value < min {
-                    min
-                } else if value >= max {
-                    max
+                if value < *min {
+                    min.clone()
+                } else if value >= *max {
+                    max.clone()
                 } else {
                     value
                 }
             }
-        };
-
-        self.value = value;
+        }
     }
 
     /// Sets the bounds of the numeric field, allowing you to specify a range of values which can be input into the field.
@@ -134,11 +318,11 @@ where
     /// or `std::ops::RangeInclusive<T>`.
     pub fn set_bounds(&mut self, range: impl RangeBounds<T>) {
         self.min = match range.start_bound() {
-            Bound::Excluded(min) | Bound::Included(min) => Some(*min),
+            Bound::Excluded(min) | Bound::Included(min) => Some(min.clone()),
             Bound::Unbounded => None,
         };
         self.max = match range.end_bound() {
-            Bound::Excluded(max) | Bound::Included(max) => Some(*max),
+            Bound::Excluded(max) | Bound::Included(max) => Some(max.clone()),
             Bound::Unbounded => None,
         };
         self.end_inclusive = match range.end_bound() {
@@ -165,6 +349,10 @@ macro_rules! impl_signed_numeric_field_value {
                 fn checked_sub(&self, rhs: &Self) -> Option<Self> {
                     <Self as CheckedSub>::checked_sub(self, rhs)
                 }
+
+                fn snap_to_step(&self, base: &Self, step: &Self) -> Self {
+                    snap_integer(*self, *base, *step).unwrap_or(*self)
+                }
             }
         )*
     }
@@ -187,6 +375,10 @@ macro_rules! impl_unsigned_numeric_field_value {
                 fn checked_sub(&self, rhs: &Self) -> Option<Self> {
                     <Self as CheckedSub>::checked_sub(self, rhs)
                 }
+
+                fn snap_to_step(&self, base: &Self, step: &Self) -> Self {
+                    snap_integer(*self, *base, *step).unwrap_or(*self)
+                }
             }
         )*
     }
@@ -196,48 +388,60 @@ macro_rules! impl_unsigned_numeric_field_value {
 impl_signed_numeric_field_value!(i8, i16, i32, i64, i128);
 impl_unsigned_numeric_field_value!(u8, u16, u32, u64, u128);
 
-// Implement NumericFieldValue for f32
+// Implement NumericFieldValue for f32, floats allow `.` and `,` and never overflow
 impl NumericFieldValue for f32 {
     fn default_drag_step() -> Self {
         0.1
     }
-
-    fn allowed_chars() -> AllowedCharSet {
-        AllowedCharSet::new(FLOAT_CHAR_SET.to_vec())
-    }
-
-    fn checked_add(&self, rhs: &Self) -> Option<Self> {
-        Some(*self + *rhs)
-    }
-
-    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
-        Some(*self - *rhs)
-    }
 }
 
-// Implement NumericFieldValue for f64
+// Implement NumericFieldValue for f64, floats allow `.` and `,` and never overflow
 impl NumericFieldValue for f64 {
     fn default_drag_step() -> Self {
         0.1
     }
+}
 
-    fn allowed_chars() -> AllowedCharSet {
-        AllowedCharSet::new(FLOAT_CHAR_SET.to_vec())
-    }
+/// Pointer behavior while a [`NumericField<T>`] is dragged with the mouse
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+pub enum DragCursorMode {
+    /// The pointer moves freely, drags stop at the window edges
+    #[default]
+    Free,
+    /// The pointer is hidden and locked in place, and returns to its position on release
+    Locked,
+    /// The pointer wraps to the opposite edge when it reaches a window edge
+    Wrap,
+}
 
-    fn checked_add(&self, rhs: &Self) -> Option<Self> {
-        Some(*self + *rhs)
-    }
+/// How the value of a [`NumericField<T>`] changes while it is dragged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+pub enum DragResponse {
+    /// Every logical pixel changes the value by the drag step
+    #[default]
+    Linear,
+    /// Every logical pixel changes the value by a fraction of its magnitude, and at least by the drag step.
+    ///
+    /// Fits fields with huge ranges, like `0.001..100000`: small values change finely, large values quickly.
+    Logarithmic,
+}
 
-    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
-        Some(*self - *rhs)
-    }
+/// Pointer state of a numeric field dragged with a [`DragCursorMode`] other than `Free`
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub(crate) struct NumericDragCursor {
+    pub(crate) mode: DragCursorMode,
+    /// Pointer position at drag start
+    pub(crate) restore: Option<Vec2>,
+    /// Ignores the drag event of the pointer jump after wrapping
+    pub(crate) wrapped: bool,
 }
 
 /// Accumulated delta during a mouse drag
 #[derive(Component, Reflect, Default)]
 pub struct NumericDelta {
     pub accumulated_delta: f64,
+    /// Drag change not applied yet to a field with a step, smaller than the step
+    pub(crate) step_remainder: f64,
 }
 
 /// Accumulated delta during a mouse drag
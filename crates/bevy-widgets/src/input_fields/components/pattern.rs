@@ -0,0 +1,256 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+
+use super::AllowedCharSet;
+use crate::input_fields::builder::ValidationCallback;
+
+const DIGITS: &str = "0123456789";
+const HEX_DIGITS: &str = "0123456789abcdefABCDEF";
+const LETTERS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PatternToken {
+    Literal(char),
+    Slot { symbol: char, segment: usize },
+}
+
+/// Template constraining the value of a text field, e.g. `###.###.###.###` for an IPv4 address.
+///
+/// Each slot symbol of the template accepts one character of its [`AllowedCharSet`]:
+/// `#` a digit, `X` a hexadecimal digit and `A` a letter. Other characters are literal separators,
+/// typed automatically when reached, and `\` escapes a slot symbol to use it as a literal.
+/// Consecutive slots of the same symbol form a segment, which typing the following separator ends early.
+///
+/// Spawn it along a [`crate::input_fields::TextInput`]:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::input_fields::{builder::TextInputBuilder, InputPattern};
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((TextInputBuilder::default().build(), InputPattern::ipv4()));
+/// }
+/// ```
+#[derive(Component, Debug, Clone)]
+pub struct InputPattern {
+    template: String,
+    slots: BTreeMap<char, AllowedCharSet>,
+    tokens: Vec<PatternToken>,
+    segment_validation: Option<ValidationCallback>,
+    pub(crate) previous_len: usize,
+}
+
+impl InputPattern {
+    /// Creates a pattern from `template`
+    pub fn new(template: impl Into<String>) -> Self {
+        let slots = [('#', DIGITS), ('X', HEX_DIGITS), ('A', LETTERS)]
+            .into_iter()
+            .map(|(symbol, chars)| (symbol, AllowedCharSet::new(chars.chars().collect())))
+            .collect();
+        let mut pattern = Self {
+            template: template.into(),
+            slots,
+            tokens: Vec::new(),
+            segment_validation: None,
+            previous_len: 0,
+        };
+        pattern.parse_template();
+        pattern
+    }
+
+    /// IPv4 address, `###.###.###.###` with segments up to `255`
+    pub fn ipv4() -> Self {
+        Self::new("###.###.###.###")
+            .with_segment_validation(|segment| segment.parse::<u8>().is_ok())
+    }
+
+    /// Hexadecimal color, `#RRGGBB`
+    pub fn hex_color() -> Self {
+        let hex = AllowedCharSet::new(HEX_DIGITS.chars().collect());
+        Self::new("\\#RRGGBB")
+            .with_slot('R', hex.clone())
+            .with_slot('G', hex.clone())
+            .with_slot('B', hex)
+    }
+
+    /// Makes `symbol` a slot accepting `chars`
+    pub fn with_slot(mut self, symbol: char, chars: AllowedCharSet) -> Self {
+        self.slots.insert(symbol, chars);
+        self.parse_template();
+        self
+    }
+
+    /// Rejects characters making a segment invalid for `validation`, which receives the partially typed segment
+    pub fn with_segment_validation(mut self, validation: ValidationCallback) -> Self {
+        self.segment_validation = Some(validation);
+        self
+    }
+
+    /// Template of the pattern
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+
+    /// Characters that can be typed in the field
+    pub fn allowed_chars(&self) -> AllowedCharSet {
+        AllowedCharSet(
+            self.tokens
+                .iter()
+                .flat_map(|token| match token {
+                    PatternToken::Literal(literal) => vec![*literal],
+                    PatternToken::Slot { symbol, .. } => self
+                        .slots
+                        .get(symbol)
+                        .map(|chars| chars.0.iter().copied().collect())
+                        .unwrap_or_default(),
+                })
+                .collect(),
+        )
+    }
+
+    /// Checks if `value` fits the pattern with every segment filled.
+    ///
+    /// Segments ended by a separator, or by the end of the template, may be shorter than their slots.
+    pub fn is_complete(&self, value: &str) -> bool {
+        let (conformed, lengths, _) = self.walk(value);
+        conformed == value
+            && lengths.iter().enumerate().all(|(segment, length)| {
+                *length == self.segment_size(segment)
+                    || (*length > 0 && !self.is_followed_by_slot(segment))
+            })
+    }
+
+    /// Fits `input` to the pattern, dropping rejected characters and inserting missing separators.
+    ///
+    /// When `advance` is set, separators following the last typed character are added too.
+    /// ```rust
+    /// # use bevy_widgets::input_fields::InputPattern;
+    /// let ip = InputPattern::ipv4();
+    /// assert_eq!(ip.conform("192168", true), "192.168.");
+    /// assert_eq!(ip.conform("10.0.300", false), "10.0.30");
+    /// assert!(ip.is_complete("10.0.0.1"));
+    /// assert_eq!(InputPattern::hex_color().conform("ff8800", false), "#ff8800");
+    /// ```
+    pub fn conform(&self, input: &str, advance: bool) -> String {
+        let (mut output, _, mut token) = self.walk(input);
+        if advance && !output.is_empty() {
+            while let Some(PatternToken::Literal(literal)) = self.tokens.get(token) {
+                output.push(*literal);
+                token += 1;
+            }
+        }
+        output
+    }
+
+    fn parse_template(&mut self) {
+        self.tokens.clear();
+        let mut segment = 0;
+        let mut previous_symbol = None;
+        let mut chars = self.template.chars();
+        while let Some(c) = chars.next() {
+            let token = if c == '\\' {
+                chars.next().map(PatternToken::Literal)
+            } else if self.slots.contains_key(&c) {
+                if previous_symbol.is_some_and(|symbol| symbol != c) {
+                    segment += 1;
+                }
+                previous_symbol = Some(c);
+                Some(PatternToken::Slot { symbol: c, segment })
+            } else {
+                Some(PatternToken::Literal(c))
+            };
+            if let Some(PatternToken::Literal(_)) = token {
+                if previous_symbol.take().is_some() {
+                    segment += 1;
+                }
+            }
+            self.tokens.extend(token);
+        }
+    }
+
+    fn segment_count(&self) -> usize {
+        self.tokens
+            .iter()
+            .filter_map(|token| match token {
+                PatternToken::Slot { segment, .. } => Some(segment + 1),
+                PatternToken::Literal(_) => None,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn segment_size(&self, segment: usize) -> usize {
+        self.tokens
+            .iter()
+            .filter(|token| matches!(token, PatternToken::Slot { segment: slot, .. } if *slot == segment))
+            .count()
+    }
+
+    fn is_followed_by_slot(&self, segment: usize) -> bool {
+        self.tokens
+            .iter()
+            .rposition(|token| matches!(token, PatternToken::Slot { segment: slot, .. } if *slot == segment))
+            .and_then(|last| self.tokens.get(last + 1))
+            .is_some_and(|next| matches!(next, PatternToken::Slot { .. }))
+    }
+
+    /// Index of the separator ending the segment of the slot at `token`
+    fn separator_after(&self, token: usize) -> Option<(usize, char)> {
+        let Some(PatternToken::Slot { segment, .. }) = self.tokens.get(token) else {
+            return None;
+        };
+        self.tokens[token..]
+            .iter()
+            .enumerate()
+            .find_map(|(offset, next)| match next {
+                PatternToken::Slot { segment: next, .. } if next == segment => None,
+                PatternToken::Literal(literal) => Some(Some((token + offset, *literal))),
+                PatternToken::Slot { .. } => Some(None),
+            })
+            .flatten()
+    }
+
+    /// Conformed value, length of each segment and index of the next token
+    fn walk(&self, input: &str) -> (String, Vec<usize>, usize) {
+        let mut output = String::new();
+        let mut lengths = vec![0; self.segment_count()];
+        let mut token = 0;
+        let mut segment_start = 0;
+        let mut chars = input.chars().peekable();
+        while let (Some(&c), Some(current)) = (chars.peek(), self.tokens.get(token)) {
+            match *current {
+                PatternToken::Literal(literal) => {
+                    output.push(literal);
+                    token += 1;
+                    if c == literal {
+                        chars.next();
+                    }
+                }
+                PatternToken::Slot { symbol, segment } => {
+                    if lengths[segment] == 0 {
+                        segment_start = output.len();
+                    }
+                    let accepted = self
+                        .slots
+                        .get(&symbol)
+                        .is_some_and(|chars| chars.0.contains(&c))
+                        && self.segment_validation.is_none_or(|validation| {
+                            validation(&format!("{}{c}", &output[segment_start..]))
+                        });
+                    if accepted {
+                        output.push(c);
+                        lengths[segment] += 1;
+                        token += 1;
+                    } else if let Some((separator, literal)) = self
+                        .separator_after(token)
+                        .filter(|(_, literal)| *literal == c && lengths[segment] > 0)
+                    {
+                        output.push(literal);
+                        token = separator + 1;
+                    }
+                    chars.next();
+                }
+            }
+        }
+        (output, lengths, token)
+    }
+}
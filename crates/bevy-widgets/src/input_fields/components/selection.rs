@@ -0,0 +1,67 @@
+use std::ops::Range;
+
+use bevy::prelude::*;
+
+use super::{InputTextCursorPos, InputTextValue};
+use crate::input_fields::systems::grapheme_len;
+
+/// Selected text of a text field, between the `anchor` and the cursor position.
+///
+/// Pressing the pointer on the field sets the anchor and dragging moves the cursor, selecting the text in between.
+/// Double clicking selects the word under the pointer, or the whole value of numeric fields, and triple clicking
+/// selects the whole value, like the select all key binding.
+///
+/// Systems can change the selection with [`Self::select_all`], [`Self::select_range`] and [`Self::collapse`].
+/// Typing or deleting replaces the selection, any other key clears it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Component, Reflect)]
+#[reflect(Component)]
+pub struct InputTextSelection {
+    pub(crate) anchor: Option<usize>,
+}
+
+impl InputTextSelection {
+    /// Selected grapheme range for the cursor position `cursor`, `None` when nothing is selected
+    pub fn range(&self, cursor: usize) -> Option<Range<usize>> {
+        let anchor = self.anchor?;
+        (anchor != cursor).then(|| anchor.min(cursor)..anchor.max(cursor))
+    }
+
+    /// Clears the selection
+    pub const fn clear(&mut self) {
+        self.anchor = None;
+    }
+
+    /// Selects the whole `value` of the field, placing the cursor at its end
+    pub fn select_all(&mut self, cursor: &mut InputTextCursorPos, value: &InputTextValue) {
+        self.select_range(cursor, value, 0..usize::MAX);
+    }
+
+    /// Selects the grapheme `range` of `value`, placing the cursor at its end.
+    ///
+    /// The range is clamped to the length of `value`.
+    pub fn select_range(
+        &mut self,
+        cursor: &mut InputTextCursorPos,
+        value: &InputTextValue,
+        range: Range<usize>,
+    ) {
+        let len = grapheme_len(&value.0);
+        self.anchor = Some(range.start.min(len));
+        cursor.0 = range.end.clamp(range.start.min(len), len);
+    }
+
+    /// Clears the selection, placing the cursor at the grapheme position `pos` of `value`
+    pub fn collapse(
+        &mut self,
+        cursor: &mut InputTextCursorPos,
+        value: &InputTextValue,
+        pos: usize,
+    ) {
+        self.anchor = None;
+        cursor.0 = pos.min(grapheme_len(&value.0));
+    }
+}
+
+/// Marks the node highlighting the selected text of a text field
+#[derive(Component, Reflect)]
+pub(crate) struct TextInputSelectionHighlight;
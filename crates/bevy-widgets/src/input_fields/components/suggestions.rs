@@ -0,0 +1,122 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+/// Maximum number of completions listed under a text field
+pub(crate) const MAX_SUGGESTIONS: usize = 8;
+
+/// Function listing the completions of the current value of a text field.
+///
+/// Created from any `Fn(&str) -> Vec<String>`, or from a fixed list with [`SuggestionProvider::from_list`].
+#[derive(Clone)]
+pub struct SuggestionProvider(Arc<dyn Fn(&str) -> Vec<String> + Send + Sync>);
+
+impl SuggestionProvider {
+    /// Creates a provider calling `provider` with the current value
+    pub fn new(provider: impl Fn(&str) -> Vec<String> + Send + Sync + 'static) -> Self {
+        Self(Arc::new(provider))
+    }
+
+    /// Creates a provider listing the entries of `list` containing the current value, case insensitive
+    pub fn from_list(list: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let list: Vec<String> = list.into_iter().map(Into::into).collect();
+        Self::new(move |value| {
+            let value = value.to_lowercase();
+            list.iter()
+                .filter(|entry| entry.to_lowercase().contains(&value))
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// Completions of `value`
+    pub fn suggest(&self, value: &str) -> Vec<String> {
+        (self.0)(value)
+    }
+}
+
+impl<F: Fn(&str) -> Vec<String> + Send + Sync + 'static> From<F> for SuggestionProvider {
+    fn from(provider: F) -> Self {
+        Self::new(provider)
+    }
+}
+
+impl fmt::Debug for SuggestionProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SuggestionProvider")
+    }
+}
+
+impl PartialEq for SuggestionProvider {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for SuggestionProvider {}
+
+impl Hash for SuggestionProvider {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).cast::<()>().hash(state);
+    }
+}
+
+/// Completions listed in a popup under a text field while typing.
+///
+/// Arrows move the highlighted completion, Tab or Enter accept it and send a [`SuggestionAccepted`] event.
+/// Should be added with [`crate::input_fields::builder::TextInputBuilder::with_suggestions`].
+#[derive(Component, Debug, Clone, Default)]
+pub struct TextInputSuggestions {
+    pub(crate) provider: Option<SuggestionProvider>,
+    pub(crate) items: Vec<String>,
+    pub(crate) highlighted: Option<usize>,
+    pub(crate) accepted: Option<String>,
+    pub(crate) popup: Option<Entity>,
+}
+
+impl TextInputSuggestions {
+    /// Creates completions listed by `provider`
+    pub const fn new(provider: SuggestionProvider) -> Self {
+        Self {
+            provider: Some(provider),
+            items: Vec::new(),
+            highlighted: None,
+            accepted: None,
+            popup: None,
+        }
+    }
+
+    /// Completions currently listed
+    pub fn items(&self) -> &[String] {
+        &self.items
+    }
+
+    /// Index of the highlighted completion
+    pub const fn highlighted(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    pub(crate) fn close(&mut self) {
+        self.items.clear();
+        self.highlighted = None;
+    }
+}
+
+/// A completion of [`TextInputSuggestions`] in its popup
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[require(Interaction)]
+pub struct SuggestionItem {
+    pub(crate) input: Entity,
+    pub(crate) index: usize,
+}
+
+/// Event sent when a completion is accepted into a text field
+#[derive(Event, Debug, Clone, PartialEq, Eq, Reflect)]
+pub struct SuggestionAccepted {
+    /// The text input that accepted the completion
+    pub entity: Entity,
+    /// The accepted completion, now the value of the text input
+    pub value: String,
+}
@@ -6,6 +6,7 @@ use super::InputFieldSize;
 pub(crate) struct TextInputPlaceholderInner;
 
 /// Textcomponent qualifying label and hint texts
+/// > Changing it after the field was spawned updates the label and hint entities.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Component, Reflect)]
 #[reflect(Component)]
 pub struct TextInputDescriptions {
@@ -13,7 +14,120 @@ pub struct TextInputDescriptions {
     pub(crate) hint: Option<String>,
 }
 
+impl TextInputDescriptions {
+    /// Label text of the field
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Hint text of the field
+    pub fn hint(&self) -> Option<&str> {
+        self.hint.as_deref()
+    }
+
+    /// Changes the label of a spawned field, `None` removes it
+    pub fn set_label(&mut self, label: Option<String>) {
+        self.label = label;
+    }
+
+    /// Changes the hint of a spawned field, `None` removes it
+    pub fn set_hint(&mut self, hint: Option<String>) {
+        self.hint = hint;
+    }
+}
+
+/// Entities of the parts of a text or numeric field, inserted on the field root when it is created.
+///
+/// Use the helper methods to change the texts of the parts after the field was spawned:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::input_fields::TextInputParts;
+/// fn rename(mut commands: Commands, fields: Query<&TextInputParts>) {
+///     for parts in &fields {
+///         parts.set_label(&mut commands, "New label");
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Component, Reflect)]
+#[reflect(Component)]
+pub struct TextInputParts {
+    pub(crate) inner: Option<Entity>,
+    pub(crate) label: Option<Entity>,
+    pub(crate) hint: Option<Entity>,
+    pub(crate) placeholder: Option<Entity>,
+}
+
+impl TextInputParts {
+    /// Reserves the entities of the field parts, populated when the field is created
+    pub(crate) fn reserve(commands: &mut Commands, label: bool, hint: bool) -> Self {
+        Self {
+            inner: Some(commands.spawn_empty().id()),
+            label: label.then(|| commands.spawn_empty().id()),
+            hint: hint.then(|| commands.spawn_empty().id()),
+            placeholder: None,
+        }
+    }
+
+    /// Spawns a part, in its reserved entity if any
+    pub(crate) fn spawn<'a>(
+        commands: &'a mut Commands,
+        reserved: Option<Entity>,
+        bundle: impl Bundle,
+    ) -> EntityCommands<'a> {
+        match reserved {
+            Some(entity) => {
+                let mut part = commands.entity(entity);
+                part.insert(bundle);
+                part
+            }
+            None => commands.spawn(bundle),
+        }
+    }
+
+    /// Entity of the text displaying the field value
+    pub const fn inner(&self) -> Option<Entity> {
+        self.inner
+    }
+
+    /// Entity of the label text, `None` when the field has no label
+    pub const fn label(&self) -> Option<Entity> {
+        self.label
+    }
+
+    /// Entity of the hint text, `None` when the field has no hint
+    pub const fn hint(&self) -> Option<Entity> {
+        self.hint
+    }
+
+    /// Entity of the placeholder text, `None` for numeric fields
+    pub const fn placeholder(&self) -> Option<Entity> {
+        self.placeholder
+    }
+
+    /// Changes the label text. Does nothing when the field has no label.
+    pub fn set_label(&self, commands: &mut Commands, label: impl Into<String>) {
+        Self::set_text(commands, self.label, label);
+    }
+
+    /// Changes the hint text. Does nothing when the field has no hint.
+    pub fn set_hint(&self, commands: &mut Commands, hint: impl Into<String>) {
+        Self::set_text(commands, self.hint, hint);
+    }
+
+    /// Changes the placeholder text. Does nothing for numeric fields.
+    pub fn set_placeholder(&self, commands: &mut Commands, placeholder: impl Into<String>) {
+        Self::set_text(commands, self.placeholder, placeholder);
+    }
+
+    fn set_text(commands: &mut Commands, part: Option<Entity>, text: impl Into<String>) {
+        if let Some(part) = part {
+            commands.entity(part).insert(Text::new(text));
+        }
+    }
+}
+
 /// Marks Text Field placeholder
+/// > Changing it after the field was spawned updates the placeholder text.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Component, Reflect, Default)]
 #[reflect(Component, Default)]
 pub struct Placeholder(pub String);
@@ -1,6 +1,13 @@
 use bevy::{asset::Handle, color::Color, text::Font};
 
 pub(super) const HINT_FONT_SIZE: f32 = 8.0;
+pub(super) const OUT_OF_RANGE_FLASH_DURATION: f32 = 0.6;
+pub(super) const DRAG_WRAP_MARGIN: f32 = 2.0;
+/// Fraction of the value changed per logical pixel by logarithmic drags
+pub(super) const LOGARITHMIC_DRAG_RATE: f64 = 0.01;
+pub(super) const HISTORY_ICON_SIZE: f32 = 9.0;
+pub(super) const DOUBLE_CLICK_DURATION: f32 = 0.4;
+pub(super) const DOUBLE_CLICK_SLOP: f32 = 4.0;
 pub(super) const LABEL_SMALL_FONT_SIZE: f32 = 8.0;
 pub(super) const LABEL_MEDIUM_LARGE_FONT_SIZE: f32 = 10.0;
 pub(super) const SMALL_FONT_SIZE: f32 = 10.0;
@@ -24,5 +31,6 @@ pub(super) const ERROR_BORDER_COLOR: Color = Color::srgb(1.0, 0.0, 0.);
 pub(super) const ERROR_BACKGROUND_COLOR: Color = Color::srgb(1., 0.9, 0.9);
 pub(super) const DISABLED_BACKGROUND_COLOR: Color = Color::srgb(0.8, 0.83, 0.85);
 pub(super) const HOVERED_BACKGROUND_COLOR: Color = Color::srgb(0.51, 0.79, 1.);
+pub(super) const SELECTION_BACKGROUND_COLOR: Color = Color::srgba(0.51, 0.79, 1., 0.5);
 
 pub const CURSOR_HANDLE: Handle<Font> = Handle::weak_from_u128(10482756907980398621);
@@ -1,15 +1,13 @@
 use bevy::{asset::load_internal_binary_asset, ecs::system::SystemParam, prelude::*};
 use builder::NumericFieldBuilder;
 use components::{
-    numeric::{NumericField, NumericFieldValue},
-    text::{Placeholder, TextInputDescriptions},
-    InputCursorTimer, InputFieldSettings, InputFieldState, InputInactive, InputTextColor,
-    InputTextCursorPos, InputTextFont, InputTextValue, TextInputInner,
+    InputCursorTimer, InputFieldSettings, InputInactive, InputTextColor, InputTextFont,
+    TextInputInner,
 };
 use constants::CURSOR_HANDLE;
 use systems::*;
 
-use crate::focus::Clickable;
+use crate::{focus::Clickable, overlay::OverlayPlugin, WidgetsSystems};
 
 /// Modelue containing auxiliary builder for text field widget
 pub mod builder;
@@ -17,17 +15,145 @@ pub(crate) mod components;
 pub(crate) mod constants;
 mod systems;
 
-pub use components::{InputFieldSize, InputFieldSubmitEvent};
+pub use components::{
+    history::{HistoryItem, NumericFieldHistory, TextInputHistory},
+    numeric::{
+        DragCursorMode, DragResponse, NumberLocale, NumericField, NumericFieldValue,
+        OutOfRangeEvent,
+    },
+    pattern::InputPattern,
+    selection::InputTextSelection,
+    suggestions::{SuggestionAccepted, SuggestionItem, SuggestionProvider, TextInputSuggestions},
+    text::{Placeholder, TextInputDescriptions, TextInputParts},
+    InputFieldChangedEvent, InputFieldSize, InputFieldState, InputFieldSubmitEvent,
+    InputTextCursorPos, InputTextValue, SetInputText,
+};
 
 /// A Bevy `Plugin` providing the systems and assets required to make a [`TextInput`] work.
+///
+/// Numeric fields of every primitive integer and float type are supported out of the box,
+/// custom types need [`NumericFieldAppExt::register_numeric_field`].
 pub struct InputFieldPlugin;
 
+/// Inserted before [`InputFieldPlugin`], only registers numeric fields of `f32` and `i32` by default.
+///
+/// Skips the systems of unused value types, others are registered with
+/// [`NumericFieldAppExt::register_numeric_field`].
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::input_fields::{InputFieldPlugin, MinimalNumericFields, NumericFieldAppExt};
+/// fn build_app(app: &mut App) {
+///     app.init_resource::<MinimalNumericFields>()
+///         .add_plugins(InputFieldPlugin)
+///         .register_numeric_field::<u8>();
+/// }
+/// ```
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct MinimalNumericFields;
+
+/// Extension trait for [`App`] adding the systems of numeric fields, per value type.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::input_fields::NumericFieldAppExt;
+/// App::new().register_numeric_field::<u8>();
+/// ```
+pub trait NumericFieldAppExt {
+    /// Adds the value parsing and dragging systems of [`NumericField<T>`], only once per type
+    fn register_numeric_field<T: NumericFieldValue>(&mut self) -> &mut Self;
+}
+
+/// Extension trait for [`Commands`] changing the text of fields from any system.
+///
+/// The cursor is kept inside the new text, the selection is cleared and an [`InputFieldChangedEvent`] is sent.
+/// Numeric fields parse the text like typed input.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::input_fields::{TextFieldExt, TextInput};
+/// fn reset(mut commands: Commands, fields: Query<Entity, With<TextInput>>) {
+///     for field in &fields {
+///         commands.set_text(field, "");
+///     }
+/// }
+/// ```
+pub trait TextFieldExt {
+    /// Replaces the text of the field `entity` with `value`
+    fn set_text(&mut self, entity: Entity, value: impl Into<String>);
+}
+
+impl TextFieldExt for Commands<'_, '_> {
+    fn set_text(&mut self, entity: Entity, value: impl Into<String>) {
+        self.trigger_targets(SetInputText(value.into()), entity);
+    }
+}
+
+/// Numeric field value types with registered systems
+#[derive(Resource, Default)]
+pub(crate) struct RegisteredNumericFields(pub(crate) bevy::utils::HashSet<std::any::TypeId>);
+
+impl NumericFieldAppExt for App {
+    fn register_numeric_field<T: NumericFieldValue>(&mut self) -> &mut Self {
+        let mut registered = self
+            .world_mut()
+            .get_resource_or_init::<RegisteredNumericFields>();
+        if !registered.0.insert(std::any::TypeId::of::<T>()) {
+            return self;
+        }
+        WidgetsSystems::configure(self);
+        self.init_resource::<NumberLocale>()
+            .add_event::<OutOfRangeEvent<T>>()
+            .add_systems(
+                Update,
+                (
+                    localize_numeric_text::<T>
+                        .in_set(WidgetsSystems::Input)
+                        .in_set(InputFieldSystemSet)
+                        .before(on_numeric_text_changed::<T>),
+                    on_numeric_text_changed::<T>
+                        .in_set(WidgetsSystems::Input)
+                        .in_set(InputFieldSystemSet)
+                        .after(keyboard),
+                    commit_numeric_text::<T>
+                        .in_set(WidgetsSystems::Input)
+                        .in_set(InputFieldSystemSet)
+                        .after(on_numeric_text_changed::<T>),
+                    record_numeric_history::<T>
+                        .in_set(WidgetsSystems::Input)
+                        .in_set(InputFieldSystemSet)
+                        .after(commit_numeric_text::<T>),
+                    on_drag_exit::<T>.in_set(WidgetsSystems::Input),
+                    drag_locked_cursor::<T>.in_set(WidgetsSystems::Input),
+                    update_numeric_field_accessibility::<T>.in_set(WidgetsSystems::Style),
+                ),
+            )
+            .add_observer(on_drag::<T>)
+            .add_observer(on_drag_start::<T>)
+            .add_observer(on_drag_end::<T>)
+    }
+}
+
 /// Label for systems that update text inputs.
+/// > Prefer ordering systems relative to [`WidgetsSystems`], which covers every widget.
 #[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
 pub struct InputFieldSystemSet;
 
 impl Plugin for InputFieldPlugin {
     fn build(&self, app: &mut App) {
+        WidgetsSystems::configure(app);
+        OverlayPlugin::configure(app);
+        app.register_numeric_field::<f32>()
+            .register_numeric_field::<i32>();
+        if !app.world().contains_resource::<MinimalNumericFields>() {
+            app.register_numeric_field::<f64>()
+                .register_numeric_field::<i8>()
+                .register_numeric_field::<i16>()
+                .register_numeric_field::<i64>()
+                .register_numeric_field::<i128>()
+                .register_numeric_field::<u8>()
+                .register_numeric_field::<u16>()
+                .register_numeric_field::<u32>()
+                .register_numeric_field::<u64>()
+                .register_numeric_field::<u128>();
+        }
         // This is a special font with a zero-width `|` glyph.
         load_internal_binary_asset!(
             app,
@@ -37,56 +163,77 @@ impl Plugin for InputFieldPlugin {
         );
 
         app.init_resource::<InputTextNavigationBindings>()
+            .register_type::<NumberLocale>()
+            .register_type::<DragCursorMode>()
+            .register_type::<DragResponse>()
             .add_event::<InputFieldSubmitEvent>()
+            .add_event::<InputFieldChangedEvent>()
+            .add_event::<bevy::a11y::ActionRequest>()
+            .add_event::<SuggestionAccepted>()
             .add_observer(create_text_field)
             .add_observer(create_numeric_field)
+            .add_observer(on_add_pattern)
+            .add_observer(on_suggestion_click)
+            .add_observer(close_suggestions_on_blur)
+            .add_observer(on_history_button_click)
+            .add_observer(on_history_item_click)
+            .add_observer(close_history_on_remove)
             .add_observer(on_add_focus)
             .add_observer(on_remove_focus)
             .add_observer(mouse_over)
             .add_observer(mouse_out)
             .add_observer(mouse_move)
+            .add_observer(cursor_to_pointer)
+            .add_observer(select_on_drag)
+            .add_observer(select_word_on_long_press)
+            .add_observer(release_drag_cursor)
+            .add_observer(on_set_input_text)
             .add_systems(
                 Update,
                 (
-                    keyboard,
-                    update_value.after(keyboard),
-                    blink_cursor,
-                    show_hide_cursor,
-                    update_style,
-                    show_hide_placeholder,
-                    scroll_with_cursor,
+                    (
+                        keyboard,
+                        suggestion_navigation
+                            .after(keyboard)
+                            .before(conform_to_pattern),
+                        conform_to_pattern.after(keyboard),
+                        text_input_history
+                            .after(keyboard)
+                            .after(suggestion_navigation),
+                        field_accessibility_actions.before(keyboard),
+                        focus_next_field.after(keyboard),
+                    )
+                        .in_set(WidgetsSystems::Input),
+                    (
+                        update_value,
+                        scroll_with_cursor.after(update_value),
+                        update_placeholder_text,
+                        update_descriptions,
+                        apply_field_size.after(update_descriptions),
+                        create_history_button,
+                    )
+                        .in_set(WidgetsSystems::Layout),
+                    (
+                        refresh_suggestions,
+                        render_suggestions.after(refresh_suggestions),
+                        suggestion_hover,
+                        history_item_hover,
+                        blink_cursor,
+                        show_hide_cursor,
+                        update_style,
+                        update_selection_highlight,
+                        finish_out_of_range_flash,
+                        show_hide_placeholder,
+                        on_state_changed_text,
+                        on_state_changed_numeric,
+                        update_text_field_accessibility,
+                    )
+                        .in_set(WidgetsSystems::Style),
+                    send_changed_value.in_set(WidgetsSystems::Events),
                 )
                     .in_set(InputFieldSystemSet),
             )
             .add_systems(PostUpdate, (on_error_validation, on_warning_validation))
-            .add_systems(
-                Update,
-                (
-                    on_numeric_text_changed::<i8>,
-                    on_numeric_text_changed::<i16>,
-                    on_numeric_text_changed::<i32>,
-                    on_numeric_text_changed::<i64>,
-                    on_numeric_text_changed::<i128>,
-                    on_numeric_text_changed::<u8>,
-                    on_numeric_text_changed::<u16>,
-                    on_numeric_text_changed::<u32>,
-                    on_numeric_text_changed::<u64>,
-                    on_numeric_text_changed::<u128>,
-                    on_numeric_text_changed::<f32>,
-                    on_numeric_text_changed::<f64>,
-                ),
-            )
-            .add_systems(
-                Update,
-                (
-                    on_state_changed_text,
-                    on_state_changed_numeric
-                        .after(mouse_out)
-                        .after(mouse_move)
-                        .after(mouse_over),
-                ),
-            )
-            .add_plugins(DragNumericPlugin)
             .register_type::<InputFieldSettings>()
             .register_type::<InputTextColor>()
             .register_type::<InputTextFont>()
@@ -97,8 +244,10 @@ impl Plugin for InputFieldPlugin {
             .register_type::<InputFieldState>()
             .register_type::<TextInputDescriptions>()
             .register_type::<Placeholder>()
+            .register_type::<TextInputParts>()
             .register_type::<InputFieldSize>()
             .register_type::<InputTextCursorPos>()
+            .register_type::<InputTextSelection>()
             .register_type::<NumericField<f32>>()
             .register_type::<NumericField<f64>>()
             .register_type::<NumericField<u8>>()
@@ -155,6 +304,7 @@ impl Plugin for InputFieldPlugin {
     Placeholder,
     Clickable,
     TextInputDescriptions,
+    InputTextSelection,
     Interaction
 )]
 pub struct TextInput;
@@ -197,6 +347,7 @@ pub struct TextInput;
     InputFieldState,
     InputFieldSize,
     Clickable,
+    InputTextSelection,
     Interaction
 )]
 pub struct NumericInput;
@@ -224,8 +375,14 @@ pub enum InputTextAction {
     DeletePrev,
     /// Removes the char right of the cursor.
     DeleteNext,
+    /// Removes the word left of the cursor.
+    DeleteWordLeft,
+    /// Removes the word right of the cursor.
+    DeleteWordRight,
     /// Triggers a `TextInputSubmitEvent`, optionally clearing the text input.
     Submit,
+    /// Selects the whole text, placing the cursor at its end.
+    SelectAll,
 }
 
 /// A resource in which key bindings can be specified. Bindings are given as a tuple of (`TextInputAction`, `TextInputBinding`).
@@ -266,6 +423,24 @@ impl Default for InputTextNavigationBindings {
             (WordLeft, TextInputBinding::new(ArrowLeft, [ControlRight])),
             (WordRight, TextInputBinding::new(ArrowRight, [ControlLeft])),
             (WordRight, TextInputBinding::new(ArrowRight, [ControlRight])),
+            (
+                DeleteWordLeft,
+                TextInputBinding::new(Backspace, [ControlLeft]),
+            ),
+            (
+                DeleteWordLeft,
+                TextInputBinding::new(Backspace, [ControlRight]),
+            ),
+            (
+                DeleteWordRight,
+                TextInputBinding::new(Delete, [ControlLeft]),
+            ),
+            (
+                DeleteWordRight,
+                TextInputBinding::new(Delete, [ControlRight]),
+            ),
+            (SelectAll, TextInputBinding::new(KeyA, [ControlLeft])),
+            (SelectAll, TextInputBinding::new(KeyA, [ControlRight])),
             (CharLeft, TextInputBinding::new(ArrowLeft, [])),
             (CharRight, TextInputBinding::new(ArrowRight, [])),
             (DeletePrev, TextInputBinding::new(Backspace, [])),
@@ -291,6 +466,12 @@ impl Default for InputTextNavigationBindings {
             (WordLeft, TextInputBinding::new(ArrowLeft, [AltRight])),
             (WordRight, TextInputBinding::new(ArrowRight, [AltLeft])),
             (WordRight, TextInputBinding::new(ArrowRight, [AltRight])),
+            (DeleteWordLeft, TextInputBinding::new(Backspace, [AltLeft])),
+            (DeleteWordLeft, TextInputBinding::new(Backspace, [AltRight])),
+            (DeleteWordRight, TextInputBinding::new(Delete, [AltLeft])),
+            (DeleteWordRight, TextInputBinding::new(Delete, [AltRight])),
+            (SelectAll, TextInputBinding::new(KeyA, [SuperLeft])),
+            (SelectAll, TextInputBinding::new(KeyA, [SuperRight])),
             (CharLeft, TextInputBinding::new(ArrowLeft, [])),
             (CharRight, TextInputBinding::new(ArrowRight, [])),
             (DeletePrev, TextInputBinding::new(Backspace, [])),
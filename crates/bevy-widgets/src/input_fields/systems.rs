@@ -1,42 +1,189 @@
-use crate::focus::Focus;
+use crate::{
+    accessibility::{action_target, action_value},
+    disabled::InheritedDisabled,
+    focus::{stop_propagation, EventPropagation, Focus, FocusExt, FocusScope},
+    overlay::{PopupAnchor, UiLayer},
+    theme::WidgetTheme,
+    touch::LongPress,
+    transition::Transition,
+};
 
-use super::constants::CURSOR_HANDLE;
+use super::constants::{
+    CURSOR_HANDLE, DOUBLE_CLICK_DURATION, DOUBLE_CLICK_SLOP, DRAG_WRAP_MARGIN, HISTORY_ICON_SIZE,
+    LOGARITHMIC_DRAG_RATE, OUT_OF_RANGE_FLASH_DURATION,
+};
 use super::*;
 use bevy::{
+    a11y::{AccessibilityNode, ActionRequest},
     ecs::event::EventCursor,
-    input::keyboard::{Key, KeyboardInput},
+    input::{
+        keyboard::{Key, KeyboardInput},
+        mouse::AccumulatedMouseMotion,
+    },
+    picking::pointer::PointerId,
     render::camera::RenderTarget,
     text::TextLayoutInfo,
     ui::FocusPolicy,
-    window::{PrimaryWindow, Window, WindowRef},
+    window::{CursorGrabMode, PrimaryWindow, Window, WindowRef},
 };
+use unicode_segmentation::UnicodeSegmentation;
 
 use builder::{ErrorValidationCallback, WarningValidationCallback};
 use components::{
-    numeric::{NumericDelta, NumericDeltaInitialValue, NumericField, NumericFieldValue},
-    text::TextInputPlaceholderInner,
+    history::{HistoryButton, HistoryItem, NumericFieldHistory, TextInputHistory},
+    numeric::{
+        DragCursorMode, DragResponse, NumberLocale, NumericDelta, NumericDeltaInitialValue,
+        NumericDragCursor, NumericField, NumericFieldValue, OutOfRangeEvent, OutOfRangeFlash,
+    },
+    pattern::InputPattern,
+    selection::{InputTextSelection, TextInputSelectionHighlight},
+    suggestions::{SuggestionAccepted, SuggestionItem, TextInputSuggestions, MAX_SUGGESTIONS},
+    text::{TextInputParts, TextInputPlaceholderInner},
     AllowedCharSet,
 };
 use constants::{
     DEFAULT_BACKGROUND_COLOR, DISABLED_BACKGROUND_COLOR, ERROR_BACKGROUND_COLOR,
     ERROR_BORDER_COLOR, HOVERED_BACKGROUND_COLOR, SELECTED_BACKGROUND_COLOR, SELECTED_BORDER_COLOR,
-    WARNING_BACKGROUND_COLOR, WARNING_BORDER_COLOR,
+    SELECTION_BACKGROUND_COLOR, WARNING_BACKGROUND_COLOR, WARNING_BORDER_COLOR,
 };
 
-/// For custom numeric fields, you need to call this method after SystemSet [`InputFieldSystemSet`]
+/// For custom numeric fields, you need to call this method in [`crate::WidgetsSystems::Input`]
+///
+/// Partial input that doesn't parse yet, like `-` or an empty text, is kept while the field is being edited
+/// and reverted by [`commit_numeric_text`] once editing ends.
 pub fn on_numeric_text_changed<T: NumericFieldValue>(
+    mut commands: Commands,
     mut text_input_query: Query<
-        (&mut InputTextValue, &mut NumericField<T>),
+        (
+            Entity,
+            &mut InputTextValue,
+            &mut NumericField<T>,
+            &mut InputFieldState,
+            &InputInactive,
+            Option<&mut OutOfRangeFlash>,
+        ),
         (Changed<InputTextValue>, With<AllowedCharSet>),
     >,
+    mut out_of_range_writer: EventWriter<OutOfRangeEvent<T>>,
+    locale: Res<NumberLocale>,
 ) {
-    for (mut text, mut numeric) in text_input_query.iter_mut() {
-        let current_numeric_value = numeric.value;
-        if let Ok(numeric_value) = text.0.trim().parse() {
-            numeric.set_value(numeric_value);
+    for (entity, mut text, mut numeric, mut state, inactive, flash) in text_input_query.iter_mut() {
+        let current_numeric_value = numeric.value.clone();
+        let Some(numeric_value) = T::parse_localized(&text.0, &locale) else {
+            if inactive.0 {
+                text.0 = current_numeric_value.format_localized(&locale);
+            }
+            continue;
+        };
+        numeric.set_value(numeric_value.clone());
+        if inactive.0 && numeric.step.is_some() && numeric.value != numeric_value {
+            text.0 = numeric.value.format_localized(&locale);
+        }
+        // Snapping to the step isn't out of range
+        let in_range = numeric.clamp_value(numeric_value.clone()) == numeric_value;
+        if in_range || *state == InputFieldState::Disabled {
+            continue;
+        }
+
+        out_of_range_writer.send(OutOfRangeEvent {
+            entity,
+            attempted: numeric_value,
+            clamped: numeric.value.clone(),
+        });
+        if let Some(mut flash) = flash {
+            flash.timer.reset();
         } else {
-            text.0 = current_numeric_value.to_string();
+            commands.entity(entity).insert(OutOfRangeFlash {
+                timer: Timer::from_seconds(OUT_OF_RANGE_FLASH_DURATION, TimerMode::Once),
+                previous: *state,
+            });
+            *state = InputFieldState::Warning;
+        }
+    }
+}
+
+/// Reverts partial input of numeric fields to their value when editing ends, on blur or submit.
+/// For custom numeric fields, you need to call this method in [`crate::WidgetsSystems::Input`]
+pub fn commit_numeric_text<T: NumericFieldValue>(
+    mut submit_reader: EventReader<InputFieldSubmitEvent>,
+    mut fields: Query<(
+        Entity,
+        Ref<InputInactive>,
+        &mut InputTextValue,
+        &NumericField<T>,
+    )>,
+    locale: Res<NumberLocale>,
+) {
+    let submitted: Vec<Entity> = submit_reader.read().map(|event| event.entity).collect();
+    for (entity, inactive, mut text, numeric) in &mut fields {
+        let editing_ended = (inactive.is_changed() && inactive.0) || submitted.contains(&entity);
+        if !editing_ended {
+            continue;
+        }
+        let typed = T::parse_localized(&text.0, &locale);
+        // Snapped values are displayed once editing ends
+        let snapped = numeric.step.is_some() && typed.as_ref() != Some(&numeric.value);
+        if typed.is_none() || snapped {
+            text.0 = numeric.value.format_localized(&locale);
+        }
+    }
+}
+
+/// Records the values of numeric fields with a [`NumericFieldHistory`] once editing ends.
+/// For custom numeric fields, you need to call this method in [`crate::WidgetsSystems::Input`]
+pub fn record_numeric_history<T: NumericFieldValue>(
+    mut submit_reader: EventReader<InputFieldSubmitEvent>,
+    mut fields: Query<(
+        Entity,
+        Ref<InputInactive>,
+        &NumericField<T>,
+        &mut NumericFieldHistory,
+    )>,
+    locale: Res<NumberLocale>,
+) {
+    let submitted: Vec<Entity> = submit_reader.read().map(|event| event.entity).collect();
+    for (entity, inactive, numeric, mut history) in &mut fields {
+        let editing_ended = (inactive.is_changed() && inactive.0) || submitted.contains(&entity);
+        // The value before the first edit can be restored too
+        if editing_ended || history.is_added() {
+            history.push(numeric.value.format_localized(&locale));
+        }
+    }
+}
+
+/// Displays the values of new numeric fields, and of all of them when the [`NumberLocale`] changes,
+/// with the locale decimal separator.
+/// For custom numeric fields, you need to call this method in [`crate::WidgetsSystems::Input`]
+pub fn localize_numeric_text<T: NumericFieldValue>(
+    mut fields: Query<(Ref<NumericField<T>>, &mut InputTextValue)>,
+    locale: Res<NumberLocale>,
+) {
+    for (numeric, mut text) in &mut fields {
+        if !locale.is_changed() && !numeric.is_added() {
+            continue;
+        }
+        let formatted = numeric.value.format_localized(&locale);
+        if text.0 != formatted {
+            text.0 = formatted;
+        }
+    }
+}
+
+// Restores the state of numeric fields once the out of range warning is over.
+pub(super) fn finish_out_of_range_flash(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut fields: Query<(Entity, &mut OutOfRangeFlash, &mut InputFieldState)>,
+) {
+    for (entity, mut flash, mut state) in &mut fields {
+        if !flash.timer.tick(time.delta()).finished() {
+            continue;
+        }
+        // Keep states set by other systems in the meantime
+        if *state == InputFieldState::Warning {
+            *state = flash.previous;
         }
+        commands.entity(entity).remove::<OutOfRangeFlash>();
     }
 }
 
@@ -53,6 +200,8 @@ pub(super) fn keyboard(
             &mut InputTextCursorPos,
             &mut InputCursorTimer,
             Option<&AllowedCharSet>,
+            Option<&TextInputSuggestions>,
+            Option<&mut InputTextSelection>,
         ),
         (Without<FixedTextLabel>, With<Focus>),
     >,
@@ -80,6 +229,8 @@ pub(super) fn keyboard(
         mut cursor_pos,
         mut cursor_timer,
         char_set,
+        suggestions,
+        mut selection,
     ) in &mut text_input_query
     {
         if inactive.0 {
@@ -94,39 +245,36 @@ pub(super) fn keyboard(
             };
 
             let pos = cursor_pos.bypass_change_detection().0;
+            let selected = selection
+                .as_ref()
+                .and_then(|selection| selection.range(pos));
 
             if let Some((_, action)) = valid_actions
                 .clone()
                 .find(|(key, _)| *key == input.key_code)
             {
                 use InputTextAction::*;
+                clear_selection(&mut selection);
                 let mut timer_should_reset = true;
                 match action {
-                    CharLeft => cursor_pos.0 = cursor_pos.0.saturating_sub(1),
-                    CharRight => cursor_pos.0 = (cursor_pos.0 + 1).min(text_input.0.len()),
-                    LineStart => cursor_pos.0 = 0,
-                    LineEnd => cursor_pos.0 = text_input.0.len(),
-                    WordLeft => {
-                        cursor_pos.0 = text_input
-                            .0
-                            .char_indices()
-                            .rev()
-                            .skip(text_input.0.len() - cursor_pos.0 + 1)
-                            .skip_while(|c| c.1.is_ascii_whitespace())
-                            .find(|c| c.1.is_ascii_whitespace())
-                            .map(|(ix, _)| ix + 1)
-                            .unwrap_or(0)
+                    // Deleting removes the selected text instead
+                    DeletePrev | DeleteNext | DeleteWordLeft | DeleteWordRight
+                        if selected.is_some() =>
+                    {
+                        if let Some(range) = selected {
+                            remove_graphemes(&mut text_input.0, range.clone());
+                            cursor_pos.0 = range.start;
+                            cursor_pos.set_changed();
+                        }
                     }
-                    WordRight => {
-                        cursor_pos.0 = text_input
-                            .0
-                            .char_indices()
-                            .skip(cursor_pos.0)
-                            .skip_while(|c| !c.1.is_ascii_whitespace())
-                            .find(|c| !c.1.is_ascii_whitespace())
-                            .map(|(ix, _)| ix)
-                            .unwrap_or(text_input.0.len())
+                    CharLeft => cursor_pos.0 = cursor_pos.0.saturating_sub(1),
+                    CharRight => {
+                        cursor_pos.0 = (cursor_pos.0 + 1).min(grapheme_len(&text_input.0));
                     }
+                    LineStart => cursor_pos.0 = 0,
+                    LineEnd => cursor_pos.0 = grapheme_len(&text_input.0),
+                    WordLeft => cursor_pos.0 = word_left(&text_input.0, cursor_pos.0),
+                    WordRight => cursor_pos.0 = word_right(&text_input.0, cursor_pos.0),
                     DeletePrev => {
                         if pos > 0 {
                             cursor_pos.0 -= 1;
@@ -134,13 +282,40 @@ pub(super) fn keyboard(
                         }
                     }
                     DeleteNext => {
-                        if pos < text_input.0.len() {
+                        if pos < grapheme_len(&text_input.0) {
                             text_input.0 = remove_char_at(&text_input.0, cursor_pos.0);
 
                             // Ensure that the cursor isn't reset
                             cursor_pos.set_changed();
                         }
                     }
+                    DeleteWordLeft => {
+                        let start = word_left(&text_input.0, pos);
+                        if start < pos {
+                            let range =
+                                byte_pos(&text_input.0, start)..byte_pos(&text_input.0, pos);
+                            text_input.0.replace_range(range, "");
+                            cursor_pos.0 = start;
+                        }
+                    }
+                    DeleteWordRight => {
+                        let end = word_right(&text_input.0, pos);
+                        if end > pos {
+                            remove_graphemes(&mut text_input.0, pos..end);
+
+                            // Ensure that the cursor isn't reset
+                            cursor_pos.set_changed();
+                        }
+                    }
+                    SelectAll => {
+                        if let Some(selection) = selection.as_mut() {
+                            selection.select_all(&mut cursor_pos, &text_input);
+                        }
+                    }
+                    // Enter accepts the highlighted suggestion instead
+                    Submit
+                        if suggestions
+                            .is_some_and(|suggestions| suggestions.highlighted.is_some()) => {}
                     Submit => {
                         if settings.retain_on_submit {
                             submitted_value = Some(text_input.0.clone());
@@ -156,28 +331,30 @@ pub(super) fn keyboard(
                 continue;
             }
 
-            match input.logical_key {
-                Key::Space => {
-                    if char_set.map_or(true, |chars| !chars.has_invalid_char(' ')) {
-                        let byte_pos = byte_pos(&text_input.0, pos);
-                        text_input.0.insert(byte_pos, ' ');
-                        cursor_pos.0 += 1;
-
-                        cursor_timer.should_reset = true;
-                    }
+            let typed = match input.logical_key {
+                Key::Space if char_set.is_none_or(|chars| !chars.has_invalid_char(' ')) => " ",
+                Key::Character(ref s)
+                    if char_set.is_none_or(|chars| !chars.has_invalid_chars(s)) =>
+                {
+                    s.as_str()
                 }
-                Key::Character(ref s) => {
-                    if char_set.map_or(true, |chars| !chars.has_invalid_chars(s)) {
-                        let byte_pos = byte_pos(&text_input.0, pos);
-                        text_input.0.insert_str(byte_pos, s.as_str());
+                _ => continue,
+            };
 
-                        cursor_pos.0 += 1;
+            // Typing replaces the selected text
+            let pos = selected.map_or(pos, |range| {
+                remove_graphemes(&mut text_input.0, range.clone());
+                range.start
+            });
+            clear_selection(&mut selection);
 
-                        cursor_timer.should_reset = true;
-                    }
-                }
-                _ => (),
-            }
+            let byte_pos = byte_pos(&text_input.0, pos);
+            text_input.0.insert_str(byte_pos, typed);
+
+            // Combining characters join the previous grapheme instead of adding one
+            cursor_pos.0 = grapheme_len(&text_input.0[..byte_pos + typed.len()]);
+
+            cursor_timer.should_reset = true;
         }
 
         if let Some(value) = submitted_value {
@@ -215,11 +392,11 @@ pub(super) fn update_value(
         // Reset the cursor to the end of the input when the value is changed by
         // a user manipulating the value component.
         if text_input.is_changed() && !cursor_pos.is_changed() {
-            cursor_pos.0 = text_input.0.chars().count();
+            cursor_pos.0 = grapheme_len(&text_input.0);
         }
 
         if cursor_pos.is_changed() {
-            cursor_pos.0 = cursor_pos.0.clamp(0, text_input.0.chars().count());
+            cursor_pos.0 = cursor_pos.0.clamp(0, grapheme_len(&text_input.0));
         }
 
         let values = get_section_values(
@@ -341,16 +518,23 @@ pub(super) fn create_numeric_field(
         Option<&InputTextCursorPos>,
         &InputInactive,
         &InputFieldSettings,
+        Option<&TextInputParts>,
     )>,
 ) {
-    if let Ok((entity, font, color, text_input, maybe_cursor_pos, inactive, settings)) =
+    if let Ok((entity, font, color, text_input, maybe_cursor_pos, inactive, settings, reserved)) =
         &query.get(trigger.entity())
     {
+        let reserved = reserved.copied().unwrap_or_default();
+
+        commands
+            .entity(*entity)
+            .insert_if_new(Transition::default());
+
         #[expect(clippy::option_if_let_else)]
         // Internal mutation
         let cursor_pos = match maybe_cursor_pos {
             None => {
-                let len = text_input.0.len();
+                let len = grapheme_len(&text_input.0);
                 commands.entity(*entity).insert(InputTextCursorPos(len));
                 len
             }
@@ -362,32 +546,35 @@ pub(super) fn create_numeric_field(
             cursor_pos,
         );
 
-        let text = commands
-            .spawn((
+        let text = TextInputParts::spawn(
+            &mut commands,
+            reserved.inner,
+            (
                 Text::default(),
                 TextLayout::new_with_linebreak(LineBreak::NoWrap),
                 Name::new("NumericInputInner"),
                 TextInputInner,
-            ))
-            .with_children(|parent| {
-                parent.spawn((TextSpan::new(values.0), font.0.clone(), color.0));
-
-                parent.spawn((
-                    TextSpan::new(values.1),
-                    TextFont {
-                        font: CURSOR_HANDLE,
-                        ..font.0.clone()
-                    },
-                    if inactive.0 {
-                        Color::NONE.into()
-                    } else {
-                        color.0
-                    },
-                ));
+            ),
+        )
+        .with_children(|parent| {
+            parent.spawn((TextSpan::new(values.0), font.0.clone(), color.0));
+
+            parent.spawn((
+                TextSpan::new(values.1),
+                TextFont {
+                    font: CURSOR_HANDLE,
+                    ..font.0.clone()
+                },
+                if inactive.0 {
+                    Color::NONE.into()
+                } else {
+                    color.0
+                },
+            ));
 
-                parent.spawn((TextSpan::new(values.2), font.0.clone(), color.0));
-            })
-            .id();
+            parent.spawn((TextSpan::new(values.2), font.0.clone(), color.0));
+        })
+        .id();
 
         let overflow_container = commands
             .spawn((
@@ -404,12 +591,22 @@ pub(super) fn create_numeric_field(
             ))
             .id();
 
-        commands.entity(overflow_container).add_child(text);
+        let selection_highlight = commands.spawn(selection_highlight_bundle()).id();
+
+        commands
+            .entity(overflow_container)
+            .add_children(&[selection_highlight, text]);
         commands
             .entity(trigger.entity())
             .add_child(overflow_container);
         // Prevent clicks from registering on UI elements underneath the text input.
-        commands.entity(trigger.entity()).insert(FocusPolicy::Block);
+        commands.entity(trigger.entity()).insert((
+            FocusPolicy::Block,
+            TextInputParts {
+                inner: Some(text),
+                ..default()
+            },
+        ));
     }
 }
 
@@ -428,6 +625,7 @@ pub(super) fn create_text_field(
         &InputFieldSize,
         &InputFieldState,
         &TextInputDescriptions,
+        Option<&TextInputParts>,
     )>,
 ) {
     if let Ok((
@@ -442,13 +640,20 @@ pub(super) fn create_text_field(
         text_input_size,
         text_state,
         extras,
+        reserved,
     )) = &query.get(trigger.entity())
     {
+        let reserved = reserved.copied().unwrap_or_default();
+
+        commands
+            .entity(*entity)
+            .insert_if_new(Transition::default());
+
         #[expect(clippy::option_if_let_else)]
         // Internal mutation
         let cursor_pos = match maybe_cursor_pos {
             None => {
-                let len = text_input.0.len();
+                let len = grapheme_len(&text_input.0);
                 commands.entity(*entity).insert(InputTextCursorPos(len));
                 len
             }
@@ -460,32 +665,35 @@ pub(super) fn create_text_field(
             cursor_pos,
         );
 
-        let text = commands
-            .spawn((
+        let text = TextInputParts::spawn(
+            &mut commands,
+            reserved.inner,
+            (
                 Text::default(),
                 TextLayout::new_with_linebreak(LineBreak::NoWrap),
                 Name::new("TextInputInner"),
                 TextInputInner,
-            ))
-            .with_children(|parent| {
-                parent.spawn((TextSpan::new(values.0), font.0.clone(), color.0));
-
-                parent.spawn((
-                    TextSpan::new(values.1),
-                    TextFont {
-                        font: CURSOR_HANDLE,
-                        ..font.0.clone()
-                    },
-                    if inactive.0 {
-                        Color::NONE.into()
-                    } else {
-                        color.0
-                    },
-                ));
+            ),
+        )
+        .with_children(|parent| {
+            parent.spawn((TextSpan::new(values.0), font.0.clone(), color.0));
+
+            parent.spawn((
+                TextSpan::new(values.1),
+                TextFont {
+                    font: CURSOR_HANDLE,
+                    ..font.0.clone()
+                },
+                if inactive.0 {
+                    Color::NONE.into()
+                } else {
+                    color.0
+                },
+            ));
 
-                parent.spawn((TextSpan::new(values.2), font.0.clone(), color.0));
-            })
-            .id();
+            parent.spawn((TextSpan::new(values.2), font.0.clone(), color.0));
+        })
+        .id();
 
         let placeholder_visible = inactive.0 && text_input.0.is_empty();
 
@@ -527,61 +735,238 @@ pub(super) fn create_text_field(
             ))
             .id();
 
-        commands.entity(overflow_container).add_child(text);
+        let selection_highlight = commands.spawn(selection_highlight_bundle()).id();
+
+        commands
+            .entity(overflow_container)
+            .add_children(&[selection_highlight, text]);
         commands
             .entity(trigger.entity())
             .add_children(&[overflow_container, placeholder_text]);
         // Prevent clicks from registering on UI elements underneath the text input.
         commands.entity(trigger.entity()).insert(FocusPolicy::Block);
 
-        if let Some(hint) = &&extras.hint {
-            let hint_id = commands
-                .spawn((
-                    Text::new(hint),
-                    TextLayout::new_with_linebreak(LineBreak::NoWrap),
-                    Name::new("TextInputHint"),
-                    TextColor(text_state.hint_color()),
-                    FixedTextLabel,
-                    TextFont {
-                        font_size: text_input_size.hint_font_size(),
-                        ..default()
-                    },
-                    Node {
-                        position_type: PositionType::Absolute,
-                        left: Val::Px(0.),
-                        top: Val::Px(
-                            text_input_size.height() + text_input_size.hint_text_spacing(),
-                        ),
-                        ..default()
-                    },
-                ))
-                .id();
+        let mut parts = TextInputParts {
+            inner: Some(text),
+            placeholder: Some(placeholder_text),
+            ..default()
+        };
+        if let Some(hint) = &extras.hint {
+            let hint_id = TextInputParts::spawn(
+                &mut commands,
+                reserved.hint,
+                hint_bundle(hint, text_input_size, text_state),
+            )
+            .id();
             commands.entity(trigger.entity()).add_child(hint_id);
+            parts.hint = Some(hint_id);
         };
-        if let Some(label) = &&extras.label {
-            let label_id = commands
-                .spawn((
-                    Text::new(label),
-                    TextLayout::new_with_linebreak(LineBreak::NoWrap),
-                    Name::new("TextInputLabel"),
-                    TextColor(text_state.label_color()),
-                    FixedTextLabel,
-                    PickingBehavior::IGNORE,
-                    FocusPolicy::Pass,
-                    TextFont {
-                        font_size: text_input_size.label_font_size(),
-                        ..default()
-                    },
-                    Node {
-                        position_type: PositionType::Absolute,
-                        left: Val::Px(16.),
-                        top: Val::Px(if text_input_size.is_large() { 4. } else { 2. }),
-                        ..default()
-                    },
-                ))
-                .id();
+        if let Some(label) = &extras.label {
+            let label_id = TextInputParts::spawn(
+                &mut commands,
+                reserved.label,
+                label_bundle(label, text_input_size, text_state),
+            )
+            .id();
             commands.entity(trigger.entity()).add_child(label_id);
+            parts.label = Some(label_id);
+        };
+        commands.entity(trigger.entity()).insert(parts);
+    }
+}
+
+fn selection_highlight_bundle() -> impl Bundle {
+    (
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(0.),
+            height: Val::Percent(100.),
+            ..default()
+        },
+        BackgroundColor(SELECTION_BACKGROUND_COLOR),
+        Visibility::Hidden,
+        ZIndex(-1),
+        FocusPolicy::Pass,
+        PickingBehavior::IGNORE,
+        Name::new("TextInputSelectionHighlight"),
+        TextInputSelectionHighlight,
+    )
+}
+
+fn hint_bundle(hint: &str, size: &InputFieldSize, state: &InputFieldState) -> impl Bundle {
+    (
+        Text::new(hint),
+        TextLayout::new_with_linebreak(LineBreak::NoWrap),
+        Name::new("TextInputHint"),
+        TextColor(state.hint_color()),
+        FixedTextLabel,
+        TextFont {
+            font_size: size.hint_font_size(),
+            ..default()
+        },
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.),
+            top: Val::Px(size.height() + size.hint_text_spacing()),
+            ..default()
+        },
+    )
+}
+
+fn label_bundle(label: &str, size: &InputFieldSize, state: &InputFieldState) -> impl Bundle {
+    (
+        Text::new(label),
+        TextLayout::new_with_linebreak(LineBreak::NoWrap),
+        Name::new("TextInputLabel"),
+        TextColor(state.label_color()),
+        FixedTextLabel,
+        PickingBehavior::IGNORE,
+        FocusPolicy::Pass,
+        TextFont {
+            font_size: size.label_font_size(),
+            ..default()
+        },
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(16.),
+            top: Val::Px(if size.is_large() { 4. } else { 2. }),
+            ..default()
+        },
+    )
+}
+
+// Updates the placeholder text of spawned fields when their [`Placeholder`] changes.
+pub(super) fn update_placeholder_text(
+    fields: Query<(&Placeholder, &TextInputParts), Changed<Placeholder>>,
+    mut texts: Query<&mut Text>,
+) {
+    for (placeholder, parts) in &fields {
+        let Some(mut text) = parts.placeholder.and_then(|part| texts.get_mut(part).ok()) else {
+            continue;
+        };
+        if text.0 != placeholder.0 {
+            text.0.clone_from(&placeholder.0);
+        }
+    }
+}
+
+// Updates, spawns or despawns the label and hint of spawned fields when their [`TextInputDescriptions`] change.
+pub(super) fn update_descriptions(
+    mut commands: Commands,
+    mut fields: Query<
+        (
+            Entity,
+            &TextInputDescriptions,
+            &mut TextInputParts,
+            &InputFieldSize,
+            &InputFieldState,
+            &mut Node,
+        ),
+        Changed<TextInputDescriptions>,
+    >,
+    mut texts: Query<&mut Text>,
+) {
+    for (entity, descriptions, mut parts, size, state, mut node) in &mut fields {
+        let parts = &mut *parts;
+        parts.hint = update_description(
+            &mut commands,
+            entity,
+            parts.hint,
+            descriptions.hint.as_deref(),
+            &mut texts,
+            |hint| hint_bundle(hint, size, state),
+        );
+        parts.label = update_description(
+            &mut commands,
+            entity,
+            parts.label,
+            descriptions.label.as_deref(),
+            &mut texts,
+            |label| label_bundle(label, size, state),
+        );
+
+        let padding = size.padding(descriptions.label.is_some());
+        if node.padding != padding {
+            node.padding = padding;
+        }
+    }
+}
+
+// Re-applies the fonts, paddings and heights of spawned fields when their [`InputFieldSize`] changes.
+pub(super) fn apply_field_size(
+    mut fields: Query<
+        (
+            Ref<InputFieldSize>,
+            &mut Node,
+            &mut InputTextFont,
+            Option<&TextInputParts>,
+            Option<&TextInputDescriptions>,
+            Has<NumericInput>,
+        ),
+        Changed<InputFieldSize>,
+    >,
+    mut parts: Query<(&mut TextFont, &mut Node), Without<InputFieldSize>>,
+) {
+    for (size, mut node, mut font, field_parts, descriptions, numeric) in &mut fields {
+        if size.is_added() {
+            continue;
+        }
+        let has_label = descriptions.is_some_and(|descriptions| descriptions.label.is_some());
+        node.height = Val::Px(size.height());
+        node.padding = size.padding(has_label);
+        // Numeric fields keep their own width constraints
+        if !numeric {
+            node.min_width = Val::Px(size.min_width());
+        }
+        font.0.font_size = size.font_size();
+
+        let Some(field_parts) = field_parts else {
+            continue;
         };
+        if let Some((mut font, mut node)) = field_parts.label.and_then(|e| parts.get_mut(e).ok()) {
+            font.font_size = size.label_font_size();
+            node.top = Val::Px(if size.is_large() { 4. } else { 2. });
+        }
+        if let Some((_, mut node)) = field_parts.hint.and_then(|e| parts.get_mut(e).ok()) {
+            node.top = Val::Px(size.height() + size.hint_text_spacing());
+        }
+        if let Some((mut font, mut node)) =
+            field_parts.placeholder.and_then(|e| parts.get_mut(e).ok())
+        {
+            font.font_size = size.font_size();
+            node.bottom = size.padding(has_label).bottom;
+        }
+    }
+}
+
+/// Syncs a description part with its text, returning the entity of the part
+fn update_description<B: Bundle>(
+    commands: &mut Commands,
+    field: Entity,
+    part: Option<Entity>,
+    description: Option<&str>,
+    texts: &mut Query<&mut Text>,
+    bundle: impl FnOnce(&str) -> B,
+) -> Option<Entity> {
+    match (part, description) {
+        (Some(part), Some(description)) => {
+            if let Ok(mut text) = texts.get_mut(part) {
+                if text.0 != description {
+                    description.clone_into(&mut text.0);
+                }
+            }
+            Some(part)
+        }
+        (None, Some(description)) => {
+            let part = commands.spawn(bundle(description)).id();
+            commands.entity(field).add_child(part);
+            Some(part)
+        }
+        (Some(part), None) => {
+            commands.entity(part).despawn_recursive();
+            None
+        }
+        (None, None) => None,
     }
 }
 
@@ -624,13 +1009,25 @@ pub(super) fn blink_cursor(
     )>,
     inner_text: InnerText,
     mut writer: TextUiWriter,
-    time: Res<Time>,
+    time: Res<Time<Real>>,
+    theme: Option<Res<WidgetTheme>>,
 ) {
+    let solid = theme.is_some_and(|theme| theme.reduced_motion);
     for (entity, color, mut cursor_timer, inactive) in &mut input_query {
         if inactive.0 {
             continue;
         }
 
+        // With reduced motion, the cursor stays visible instead of blinking
+        if solid {
+            if let Some(inner) = inner_text.inner_entity(entity) {
+                if writer.color(inner, 1).0 == Color::NONE {
+                    *writer.color(inner, 1) = color.0;
+                }
+            }
+            continue;
+        }
+
         if cursor_timer.is_changed() && cursor_timer.should_reset {
             cursor_timer.timer.reset();
             cursor_timer.should_reset = false;
@@ -680,7 +1077,7 @@ pub(super) fn show_hide_placeholder(
 pub(super) fn update_style(
     mut input_query: Query<
         (Entity, &InputTextFont, &InputTextColor, &InputInactive),
-        (Changed<InputTextFont>, Changed<InputTextColor>),
+        Or<(Changed<InputTextFont>, Changed<InputTextColor>)>,
     >,
     inner_text: InnerText,
     mut writer: TextUiWriter,
@@ -707,39 +1104,92 @@ pub(super) fn update_style(
 }
 
 pub(super) fn get_section_values(value: &str, cursor_pos: usize) -> (String, String, String) {
-    let before = value.chars().take(cursor_pos).collect();
-    let after = value.chars().skip(cursor_pos).collect();
+    let byte_pos = byte_pos(value, cursor_pos);
+    let (before, after) = value.split_at(byte_pos);
 
-    // If the cursor is between two characters, use the zero-width cursor.
-    let cursor = if cursor_pos >= value.chars().count() {
+    // If the cursor is between two graphemes, use the zero-width cursor.
+    let cursor = if after.is_empty() {
         "}".to_string()
     } else {
         "|".to_string()
     };
 
-    (before, cursor, after)
+    (before.to_string(), cursor, after.to_string())
+}
+
+/// Number of grapheme clusters, the unit of cursor positions
+pub(crate) fn grapheme_len(input: &str) -> usize {
+    input.graphemes(true).count()
 }
 
+/// Removes the grapheme cluster at `index`, e.g. a whole emoji or a letter with its accents
 pub(crate) fn remove_char_at(input: &str, index: usize) -> String {
     input
-        .chars()
+        .graphemes(true)
         .enumerate()
-        .filter_map(|(i, c)| if i != index { Some(c) } else { None })
+        .filter_map(|(i, g)| if i != index { Some(g) } else { None })
         .collect()
 }
 
-pub(crate) fn byte_pos(input: &str, char_pos: usize) -> usize {
-    let mut char_indices = input.char_indices();
-    char_indices
-        .nth(char_pos)
-        .map(|(pos, _)| pos)
-        .unwrap_or(input.len())
+/// Byte offset of the grapheme cluster at `grapheme_pos`
+pub(crate) fn byte_pos(input: &str, grapheme_pos: usize) -> usize {
+    input
+        .grapheme_indices(true)
+        .nth(grapheme_pos)
+        .map_or(input.len(), |(pos, _)| pos)
+}
+
+/// Removes the grapheme clusters in `range`
+pub(crate) fn remove_graphemes(input: &mut String, range: std::ops::Range<usize>) {
+    let range = byte_pos(input, range.start)..byte_pos(input, range.end);
+    input.replace_range(range, "");
+}
+
+fn clear_selection(selection: &mut Option<Mut<InputTextSelection>>) {
+    if let Some(selection) = selection.as_mut().filter(|s| s.anchor.is_some()) {
+        selection.clear();
+    }
+}
+
+fn is_whitespace(grapheme: &str) -> bool {
+    grapheme.chars().all(char::is_whitespace)
+}
+
+/// Start of the word before `cursor_pos`
+pub(crate) fn word_left(input: &str, cursor_pos: usize) -> usize {
+    let graphemes: Vec<&str> = input.graphemes(true).take(cursor_pos).collect();
+    graphemes
+        .iter()
+        .rposition(|g| !is_whitespace(g))
+        .and_then(|end| graphemes[..end].iter().rposition(|g| is_whitespace(g)))
+        .map_or(0, |space| space + 1)
+}
+
+/// Range of the word at `pos`
+pub(crate) fn word_at(input: &str, pos: usize) -> std::ops::Range<usize> {
+    let start = word_left(input, pos + 1);
+    let end = input
+        .graphemes(true)
+        .enumerate()
+        .skip(start)
+        .find(|(_, g)| is_whitespace(g))
+        .map_or_else(|| grapheme_len(input), |(ix, _)| ix);
+    start..end
+}
+
+/// Start of the word after `cursor_pos`
+pub(crate) fn word_right(input: &str, cursor_pos: usize) -> usize {
+    let mut graphemes = input.graphemes(true).enumerate().skip(cursor_pos);
+    graphemes
+        .find(|(_, g)| is_whitespace(g))
+        .and_then(|_| graphemes.find(|(_, g)| !is_whitespace(g)))
+        .map_or_else(|| grapheme_len(input), |(ix, _)| ix)
 }
 
 pub(super) fn masked_value(value: &str, mask: Option<char>) -> String {
     mask.map_or_else(
         || value.to_string(),
-        |c| value.chars().map(|_| c).collect::<String>(),
+        |c| value.graphemes(true).map(|_| c).collect::<String>(),
     )
 }
 
@@ -748,9 +1198,101 @@ pub(super) fn placeholder_color(color: &TextColor) -> TextColor {
     TextColor(color)
 }
 
+/// Order of the text and numeric fields focused with `Tab`
+#[derive(SystemParam)]
+pub(super) struct FieldFocusOrder<'w, 's> {
+    fields: Query<
+        'w,
+        's,
+        &'static InputFieldState,
+        (With<Clickable>, Or<(With<TextInput>, With<NumericInput>)>),
+    >,
+    parents: Query<'w, 's, &'static Parent>,
+    children: Query<'w, 's, &'static Children>,
+    scopes: Query<'w, 's, (), With<FocusScope>>,
+}
+
+impl FieldFocusOrder<'_, '_> {
+    /// Closest focus scope of `entity`, or its root
+    fn scope(&self, entity: Entity) -> Entity {
+        self.parents
+            .iter_ancestors(entity)
+            .find(|ancestor| self.scopes.contains(*ancestor))
+            .or_else(|| self.parents.iter_ancestors(entity).last())
+            .unwrap_or(entity)
+    }
+
+    /// Text and numeric fields under `scope`, in depth first hierarchy order
+    fn scope_fields(&self, scope: Entity) -> Vec<Entity> {
+        let mut ordered = Vec::new();
+        let mut stack = vec![scope];
+        while let Some(entity) = stack.pop() {
+            if self
+                .fields
+                .get(entity)
+                .is_ok_and(|state| *state != InputFieldState::Disabled)
+            {
+                ordered.push(entity);
+            }
+            if let Ok(entity_children) = self.children.get(entity) {
+                stack.extend(entity_children.iter().rev());
+            }
+        }
+        ordered
+    }
+}
+
+// Moves focus to the next field of the focus scope on `Tab`, or on submit with `focus_next_on_submit`,
+// and to the previous one on `Shift` + `Tab`.
+pub(super) fn focus_next_field(
+    mut commands: Commands,
+    key_input: Res<ButtonInput<KeyCode>>,
+    mut submit_reader: EventReader<InputFieldSubmitEvent>,
+    focused: Query<
+        (Entity, &InputFieldSettings),
+        (With<Focus>, Or<(With<TextInput>, With<NumericInput>)>),
+    >,
+    mut selections: Query<(
+        &InputTextValue,
+        &mut InputTextCursorPos,
+        &mut InputTextSelection,
+    )>,
+    focus_order: FieldFocusOrder,
+) {
+    let submitted: Vec<Entity> = submit_reader.read().map(|event| event.entity).collect();
+    let tab = key_input.just_pressed(KeyCode::Tab);
+    let backwards = key_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    for (entity, settings) in &focused {
+        let chained = settings.focus_next_on_submit && submitted.contains(&entity);
+        if !tab && !chained {
+            continue;
+        }
+        let ordered = focus_order.scope_fields(focus_order.scope(entity));
+        let Some(index) = ordered.iter().position(|field| *field == entity) else {
+            continue;
+        };
+        let len = ordered.len();
+        let next = if tab && backwards {
+            ordered[(index + len - 1) % len]
+        } else {
+            ordered[(index + 1) % len]
+        };
+        if next == entity {
+            continue;
+        }
+        // The focused field is selected, ready to be overwritten
+        if let Ok((value, mut cursor_pos, mut selection)) = selections.get_mut(next) {
+            selection.select_all(&mut cursor_pos, value);
+        }
+        commands.set_focus(next);
+        // Only one field has focus
+        break;
+    }
+}
+
 pub(super) fn on_add_focus(
     trigger: Trigger<OnAdd, Focus>,
-    mut commands: Commands,
     mut interaction_query: Query<
         (&mut InputInactive, &mut InputFieldState),
         Or<(With<TextInput>, With<NumericInput>)>,
@@ -758,27 +1300,301 @@ pub(super) fn on_add_focus(
 ) {
     let entity = trigger.entity();
     if let Ok((mut inactive, mut state)) = interaction_query.get_mut(entity) {
+        if *state == InputFieldState::Disabled {
+            return;
+        }
         inactive.active();
         *state = InputFieldState::Selected;
-    } else {
-        commands.entity(entity).remove::<Focus>();
     }
 }
 
 pub(super) fn on_remove_focus(
     trigger: Trigger<OnRemove, Focus>,
-    mut commands: Commands,
     mut interaction_query: Query<
-        (&mut InputInactive, &mut InputFieldState),
-        Or<(With<TextInput>, With<NumericInput>)>,
+        (
+            &mut InputInactive,
+            &mut InputFieldState,
+            Option<&mut InputTextSelection>,
+        ),
+        Or<(With<TextInput>, With<NumericInput>)>,
     >,
 ) {
     let entity = trigger.entity();
-    if let Ok((mut inactive, mut state)) = interaction_query.get_mut(entity) {
+    if let Ok((mut inactive, mut state, mut selection)) = interaction_query.get_mut(entity) {
         inactive.inactive();
-        *state = InputFieldState::Default;
-    } else {
-        commands.entity(entity).remove::<Focus>();
+        clear_selection(&mut selection);
+        if *state != InputFieldState::Disabled {
+            *state = InputFieldState::Default;
+        }
+    }
+}
+
+/// Pointer position in physical pixels from the left edge of the text node, `pointer` being in UI pixels
+fn pointer_to_text(pointer: Vec2, node: &ComputedNode, transform: &GlobalTransform) -> f32 {
+    pointer.x / node.inverse_scale_factor() - (transform.translation().x - node.size().x / 2.)
+}
+
+/// Grapheme position closest to `x`, in physical pixels from the left edge of the text
+fn grapheme_at(displayed: &str, layout: &TextLayoutInfo, x: f32) -> usize {
+    // One glyph per char, the cursor span doesn't count
+    let char_pos = layout
+        .glyphs
+        .iter()
+        .filter(|glyph| glyph.span_index != 1)
+        .take_while(|glyph| glyph.position.x < x)
+        .count();
+    let byte_pos = displayed
+        .char_indices()
+        .nth(char_pos)
+        .map_or(displayed.len(), |(pos, _)| pos);
+    grapheme_len(&displayed[..byte_pos])
+}
+
+/// Left edge of the grapheme at `grapheme_pos`, in physical pixels from the left edge of the text
+fn grapheme_edge(displayed: &str, layout: &TextLayoutInfo, grapheme_pos: usize) -> f32 {
+    let char_pos = displayed[..byte_pos(displayed, grapheme_pos)]
+        .chars()
+        .count();
+    let mut glyphs = layout.glyphs.iter().filter(|glyph| glyph.span_index != 1);
+    glyphs.clone().nth(char_pos).map_or_else(
+        || {
+            glyphs
+                .next_back()
+                .map_or(0., |glyph| glyph.position.x + glyph.size.x / 2.)
+        },
+        |glyph| glyph.position.x - glyph.size.x / 2.,
+    )
+}
+
+/// Grapheme position of a text field under the pointer
+fn pointer_grapheme(
+    entity: Entity,
+    pointer: Vec2,
+    displayed: &str,
+    inner_text: &InnerText,
+    texts: &Query<(&TextLayoutInfo, &ComputedNode, &GlobalTransform)>,
+) -> Option<usize> {
+    let (layout, node, transform) = texts.get(inner_text.inner_entity(entity)?).ok()?;
+    Some(grapheme_at(
+        displayed,
+        layout,
+        pointer_to_text(pointer, node, transform),
+    ))
+}
+
+/// Last primary press on a field, counting consecutive clicks
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct FieldClick {
+    entity: Entity,
+    time: f32,
+    position: Vec2,
+    count: u8,
+}
+
+// Moves the cursor under the pointer and starts a selection.
+// A double click selects the word under the pointer, or the whole value of numeric fields, and a triple click the whole value.
+pub(super) fn cursor_to_pointer(
+    trigger: Trigger<Pointer<Down>>,
+    mut fields: Query<
+        (
+            &InputTextValue,
+            &InputFieldSettings,
+            &InputFieldState,
+            &mut InputTextCursorPos,
+            &mut InputTextSelection,
+            Has<NumericInput>,
+        ),
+        Or<(With<TextInput>, With<NumericInput>)>,
+    >,
+    inner_text: InnerText,
+    texts: Query<(&TextLayoutInfo, &ComputedNode, &GlobalTransform)>,
+    ui_scale: Res<UiScale>,
+    time: Res<Time<Real>>,
+    mut last_click: Local<Option<FieldClick>>,
+) {
+    if trigger.event().button != PointerButton::Primary {
+        return;
+    }
+    let entity = trigger.entity();
+    let Ok((value, settings, state, mut cursor_pos, mut selection, numeric)) =
+        fields.get_mut(entity)
+    else {
+        return;
+    };
+    if *state == InputFieldState::Disabled {
+        return;
+    }
+
+    let now = time.elapsed_secs();
+    let position = trigger.pointer_location.position;
+    let count = match *last_click {
+        Some(last)
+            if last.entity == entity
+                && now - last.time <= DOUBLE_CLICK_DURATION
+                && last.position.distance(position) <= DOUBLE_CLICK_SLOP =>
+        {
+            last.count % 3 + 1
+        }
+        _ => 1,
+    };
+    *last_click = Some(FieldClick {
+        entity,
+        time: now,
+        position,
+        count,
+    });
+
+    let displayed = masked_value(&value.0, settings.mask_character);
+    let pos = pointer_grapheme(
+        entity,
+        position / ui_scale.0,
+        &displayed,
+        &inner_text,
+        &texts,
+    );
+    let range = match (count, pos) {
+        (1, Some(pos)) if numeric => {
+            // Numeric fields are dragged instead of selected
+            selection.clear();
+            cursor_pos.0 = pos;
+            return;
+        }
+        (1, Some(pos)) => pos..pos,
+        (2, Some(pos)) if !numeric => word_at(&displayed, pos),
+        (2 | 3, _) => 0..grapheme_len(&displayed),
+        _ => return,
+    };
+    selection.anchor = Some(range.start);
+    cursor_pos.0 = range.end;
+}
+
+// Moves the cursor under the pointer while dragging, selecting the text from the anchor.
+pub(super) fn select_on_drag(
+    trigger: Trigger<Pointer<Drag>>,
+    mut fields: Query<
+        (
+            &InputTextValue,
+            &InputFieldSettings,
+            &mut InputTextCursorPos,
+            &InputTextSelection,
+        ),
+        With<TextInput>,
+    >,
+    inner_text: InnerText,
+    texts: Query<(&TextLayoutInfo, &ComputedNode, &GlobalTransform)>,
+    ui_scale: Res<UiScale>,
+) {
+    if trigger.event().button != PointerButton::Primary {
+        return;
+    }
+    let entity = trigger.entity();
+    let Ok((value, settings, mut cursor_pos, selection)) = fields.get_mut(entity) else {
+        return;
+    };
+    if selection.anchor.is_none() {
+        return;
+    }
+    let displayed = masked_value(&value.0, settings.mask_character);
+    if let Some(pos) = pointer_grapheme(
+        entity,
+        trigger.pointer_location.position / ui_scale.0,
+        &displayed,
+        &inner_text,
+        &texts,
+    ) {
+        if cursor_pos.0 != pos {
+            cursor_pos.0 = pos;
+        }
+    }
+}
+
+// Selects the word under a long pressed touch.
+pub(super) fn select_word_on_long_press(
+    mut trigger: Trigger<LongPress>,
+    mut fields: Query<
+        (
+            &InputTextValue,
+            &InputFieldSettings,
+            &mut InputTextCursorPos,
+            &mut InputTextSelection,
+        ),
+        (With<TextInput>, Without<InheritedDisabled>),
+    >,
+    inner_text: InnerText,
+    texts: Query<(&TextLayoutInfo, &ComputedNode, &GlobalTransform)>,
+    ui_scale: Res<UiScale>,
+) {
+    let entity = trigger.entity();
+    let Ok((value, settings, mut cursor_pos, mut selection)) = fields.get_mut(entity) else {
+        return;
+    };
+    trigger.propagate(false);
+    let displayed = masked_value(&value.0, settings.mask_character);
+    let Some(pos) = pointer_grapheme(
+        entity,
+        trigger.event().position / ui_scale.0,
+        &displayed,
+        &inner_text,
+        &texts,
+    ) else {
+        return;
+    };
+    let word = word_at(&displayed, pos);
+    selection.anchor = Some(word.start);
+    cursor_pos.0 = word.end;
+}
+
+// Places the highlight node behind the selected text.
+pub(super) fn update_selection_highlight(
+    fields: Query<(
+        Entity,
+        &InputTextValue,
+        &InputFieldSettings,
+        &InputTextCursorPos,
+        &InputTextSelection,
+        &InputInactive,
+    )>,
+    inner_text: InnerText,
+    texts: Query<(&TextLayoutInfo, &ComputedNode, &GlobalTransform, &Parent)>,
+    containers: Query<(&ComputedNode, &GlobalTransform, &Children)>,
+    mut highlights: Query<(&mut Node, &mut Visibility), With<TextInputSelectionHighlight>>,
+) {
+    for (entity, value, settings, cursor_pos, selection, inactive) in &fields {
+        let Some((layout, node, transform, parent)) = inner_text
+            .inner_entity(entity)
+            .and_then(|inner| texts.get(inner).ok())
+        else {
+            continue;
+        };
+        let Ok((container_node, container_transform, children)) = containers.get(parent.get())
+        else {
+            continue;
+        };
+        let Some(highlight) = children.iter().find(|child| highlights.contains(**child)) else {
+            continue;
+        };
+        let Ok((mut highlight_node, mut visibility)) = highlights.get_mut(*highlight) else {
+            continue;
+        };
+
+        let Some(range) = selection.range(cursor_pos.0).filter(|_| !inactive.0) else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        let displayed = masked_value(&value.0, settings.mask_character);
+        let start = grapheme_edge(&displayed, layout, range.start);
+        let end = grapheme_edge(&displayed, layout, range.end);
+        let text_left = transform.translation().x - node.size().x / 2.;
+        let container_left = container_transform.translation().x - container_node.size().x / 2.;
+        let scale = node.inverse_scale_factor();
+
+        let left = Val::Px((text_left - container_left + start) * scale);
+        let width = Val::Px((end - start) * scale);
+        if highlight_node.left != left || highlight_node.width != width {
+            highlight_node.left = left;
+            highlight_node.width = width;
+        }
+        visibility.set_if_neq(Visibility::Inherited);
     }
 }
 
@@ -914,12 +1730,16 @@ pub(super) fn mouse_over(
     mut commands: Commands,
     clickable_entities: Query<Entity, With<Clickable>>,
     mut interaction_query: Query<(&mut InputFieldState, &InputInactive), Without<Focus>>,
+    propagation: Query<&EventPropagation>,
 ) {
     let entity = click.entity();
     if clickable_entities.contains(entity) {
-        click.propagate(false);
+        stop_propagation(&mut click, &propagation);
 
         if let Ok((mut state, &InputInactive(true))) = interaction_query.get_mut(entity) {
+            if *state == InputFieldState::Disabled {
+                return;
+            }
             if *state != InputFieldState::Hovered {
                 commands.entity(entity).insert(PreviousInputState(*state));
             }
@@ -933,12 +1753,16 @@ pub(super) fn mouse_move(
     mut commands: Commands,
     clickable_entities: Query<Entity, With<Clickable>>,
     mut interaction_query: Query<(&mut InputFieldState, &InputInactive), Without<Focus>>,
+    propagation: Query<&EventPropagation>,
 ) {
     let entity = click.entity();
     if clickable_entities.contains(entity) {
-        click.propagate(false);
+        stop_propagation(&mut click, &propagation);
 
         if let Ok((mut state, &InputInactive(true))) = interaction_query.get_mut(entity) {
+            if *state == InputFieldState::Disabled {
+                return;
+            }
             if *state != InputFieldState::Hovered {
                 commands.entity(entity).insert(PreviousInputState(*state));
             } else {
@@ -953,12 +1777,16 @@ pub(super) fn mouse_out(
     mut commands: Commands,
     clickable_entities: Query<Entity, With<Clickable>>,
     mut interaction_query: Query<(&mut InputFieldState, Option<&PreviousInputState>)>,
+    propagation: Query<&EventPropagation>,
 ) {
     let entity = click.entity();
     if clickable_entities.contains(entity) {
-        click.propagate(false);
+        stop_propagation(&mut click, &propagation);
 
         if let Ok((mut state, previous_state)) = interaction_query.get_mut(entity) {
+            if *state == InputFieldState::Disabled {
+                return;
+            }
             if let Some(previous_state) = previous_state {
                 *state = previous_state.0;
                 commands.entity(entity).remove::<PreviousInputState>();
@@ -969,21 +1797,135 @@ pub(super) fn mouse_out(
     }
 }
 
+/// Steps the value of a dragged numeric field once along the direction of `pointer_delta`,
+/// by whole steps for fields with a step
+fn apply_drag<T: NumericFieldValue>(
+    field: &mut NumericField<T>,
+    delta: &mut NumericDelta,
+    value: &mut InputTextValue,
+    pointer_delta: Vec2,
+    locale: &NumberLocale,
+) {
+    let event_delta = pointer_delta.normalize();
+    let drag_step = field.drag_step.clone().unwrap_or_default();
+    let (Some(drag_delta), Some(current)) = (drag_step.to_f64(), field.value.to_f64()) else {
+        return;
+    };
+    delta.accumulated_delta +=
+        drag_delta.mul_add(event_delta.x as f64, drag_delta * event_delta.y as f64);
+    let direction = f64::from(event_delta.x - event_delta.y);
+    let change = match field.drag_response {
+        DragResponse::Linear => drag_delta * direction,
+        DragResponse::Logarithmic => {
+            current.abs().max(drag_delta) * LOGARITHMIC_DRAG_RATE * direction
+        }
+    };
+    let step = field
+        .step
+        .as_ref()
+        .and_then(|step| step.to_f64())
+        .filter(|step| *step > 0.);
+    let new_value = if let Some(step) = step {
+        delta.step_remainder += change;
+        let steps = (delta.step_remainder / step).trunc();
+        if steps == 0. {
+            return;
+        }
+        delta.step_remainder -= steps * step;
+        let Some(new_value) = T::from(steps.mul_add(step, current)) else {
+            return;
+        };
+        new_value
+    } else if field.drag_response == DragResponse::Logarithmic {
+        // Integers keep the fraction they can't hold for the next drag events
+        let target = current + delta.step_remainder + change;
+        let Some(new_value) = T::from(target) else {
+            return;
+        };
+        delta.step_remainder = target - new_value.to_f64().unwrap_or(target);
+        new_value
+    } else {
+        field.value.clone() - drag_step.clone() * T::from(event_delta.y).unwrap_or_default()
+            + drag_step * T::from(event_delta.x).unwrap_or_default()
+    };
+    field.set_value(new_value);
+    value.0 = field.value.format_localized(locale);
+}
+
 pub fn on_drag<T: NumericFieldValue>(
     trigger: Trigger<Pointer<Drag>>,
-    mut q_fields: Query<(&mut NumericField<T>, &mut NumericDelta, &mut InputTextValue)>,
+    mut q_fields: Query<
+        (
+            &mut NumericField<T>,
+            &mut NumericDelta,
+            &mut InputTextValue,
+            Option<&mut NumericDragCursor>,
+        ),
+        Without<InheritedDisabled>,
+    >,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    locale: Res<NumberLocale>,
 ) {
-    let event_delta = trigger.delta.normalize();
     let entity = trigger.entity();
-    if let Ok((mut field, mut delta, mut value)) = q_fields.get_mut(entity) {
-        if let Some(drag_delta) = field.drag_step.unwrap_or_default().to_f64() {
-            delta.accumulated_delta +=
-                drag_delta.mul_add(event_delta.x as f64, drag_delta * event_delta.y as f64);
-            let new_value = field.value
-                - field.drag_step.unwrap_or_default() * T::from(event_delta.y).unwrap_or_default()
-                + field.drag_step.unwrap_or_default() * T::from(event_delta.x).unwrap_or_default();
-            field.set_value(new_value);
-            value.0 = field.value.to_string();
+    let Ok((mut field, mut delta, mut value, drag_cursor)) = q_fields.get_mut(entity) else {
+        return;
+    };
+    let Some(mut drag_cursor) = drag_cursor else {
+        apply_drag(&mut field, &mut delta, &mut value, trigger.delta, &locale);
+        return;
+    };
+    match drag_cursor.mode {
+        // Locked pointers are driven by raw mouse motion in `drag_locked_cursor`
+        DragCursorMode::Locked | DragCursorMode::Free => {}
+        DragCursorMode::Wrap if drag_cursor.wrapped => drag_cursor.wrapped = false,
+        DragCursorMode::Wrap => {
+            apply_drag(&mut field, &mut delta, &mut value, trigger.delta, &locale);
+            let Ok(mut window) = windows.get_single_mut() else {
+                return;
+            };
+            let size = window.size();
+            let position = trigger.pointer_location.position;
+            let wrap = |coordinate: f32, length: f32| {
+                if coordinate <= DRAG_WRAP_MARGIN {
+                    length - DRAG_WRAP_MARGIN - 1.
+                } else if coordinate >= length - DRAG_WRAP_MARGIN {
+                    DRAG_WRAP_MARGIN + 1.
+                } else {
+                    coordinate
+                }
+            };
+            let wrapped = Vec2::new(wrap(position.x, size.x), wrap(position.y, size.y));
+            if wrapped != position {
+                window.set_cursor_position(Some(wrapped));
+                drag_cursor.wrapped = true;
+            }
+        }
+    }
+}
+
+/// Drags numeric fields with a locked pointer, which doesn't send pointer moves
+pub fn drag_locked_cursor<T: NumericFieldValue>(
+    motion: Res<AccumulatedMouseMotion>,
+    mut q_fields: Query<
+        (
+            &mut NumericField<T>,
+            &mut NumericDelta,
+            &mut InputTextValue,
+            &NumericDragCursor,
+        ),
+        (
+            With<NumericDeltaInitialValue<T>>,
+            Without<InheritedDisabled>,
+        ),
+    >,
+    locale: Res<NumberLocale>,
+) {
+    if motion.delta == Vec2::ZERO {
+        return;
+    }
+    for (mut field, mut delta, mut value, drag_cursor) in &mut q_fields {
+        if drag_cursor.mode == DragCursorMode::Locked {
+            apply_drag(&mut field, &mut delta, &mut value, motion.delta, &locale);
         }
     }
 }
@@ -991,14 +1933,58 @@ pub fn on_drag<T: NumericFieldValue>(
 pub fn on_drag_start<T: NumericFieldValue>(
     trigger: Trigger<Pointer<DragStart>>,
     mut commands: Commands,
-    q_fields: Query<&NumericField<T>, (With<NumericDelta>, With<InputTextValue>)>,
+    mut q_fields: Query<
+        (&NumericField<T>, &mut InputTextSelection),
+        (With<NumericDelta>, With<InputTextValue>),
+    >,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
 ) {
     let entity = trigger.entity();
-    if let Ok(field) = q_fields.get(entity) {
+    if let Ok((field, mut selection)) = q_fields.get_mut(entity) {
         let initial_value = NumericDeltaInitialValue {
-            initial_value: field.value,
+            initial_value: field.value.clone(),
         };
         commands.entity(entity).insert(initial_value);
+        // Dragging rewrites the value
+        selection.clear();
+
+        if field.drag_cursor == DragCursorMode::Free || trigger.pointer_id != PointerId::Mouse {
+            return;
+        }
+        let Ok(mut window) = windows.get_single_mut() else {
+            return;
+        };
+        if field.drag_cursor == DragCursorMode::Locked {
+            window.cursor_options.grab_mode = CursorGrabMode::Locked;
+            window.cursor_options.visible = false;
+        }
+        commands.entity(entity).insert(NumericDragCursor {
+            mode: field.drag_cursor,
+            restore: window.cursor_position(),
+            wrapped: false,
+        });
+    }
+}
+
+/// Releases the pointer of a numeric field dragged with a [`DragCursorMode`], also after `Escape`
+pub(super) fn release_drag_cursor(
+    trigger: Trigger<Pointer<DragEnd>>,
+    mut commands: Commands,
+    q_fields: Query<&NumericDragCursor>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let entity = trigger.entity();
+    let Ok(drag_cursor) = q_fields.get(entity) else {
+        return;
+    };
+    commands.entity(entity).remove::<NumericDragCursor>();
+    let Ok(mut window) = windows.get_single_mut() else {
+        return;
+    };
+    if drag_cursor.mode == DragCursorMode::Locked {
+        window.cursor_options.grab_mode = CursorGrabMode::None;
+        window.cursor_options.visible = true;
+        window.set_cursor_position(drag_cursor.restore);
     }
 }
 
@@ -1006,17 +1992,22 @@ pub fn on_drag_end<T: NumericFieldValue>(
     trigger: Trigger<Pointer<DragEnd>>,
     mut commands: Commands,
     mut q_fields: Query<
-        &mut NumericDelta,
         (
-            With<NumericDeltaInitialValue<T>>,
-            With<NumericField<T>>,
-            With<InputTextValue>,
+            &mut NumericDelta,
+            &NumericField<T>,
+            Option<&mut NumericFieldHistory>,
         ),
+        (With<NumericDeltaInitialValue<T>>, With<InputTextValue>),
     >,
+    locale: Res<NumberLocale>,
 ) {
     let entity = trigger.entity();
-    if let Ok(mut delta) = q_fields.get_mut(entity) {
+    if let Ok((mut delta, field, history)) = q_fields.get_mut(entity) {
         delta.accumulated_delta = 0.0;
+        delta.step_remainder = 0.0;
+        if let Some(mut history) = history {
+            history.push(field.value.format_localized(&locale));
+        }
         commands
             .entity(entity)
             .remove::<NumericDeltaInitialValue<T>>();
@@ -1033,74 +2024,682 @@ pub fn on_drag_exit<T: NumericFieldValue>(
         &mut InputTextValue,
         &NumericDeltaInitialValue<T>,
     )>,
+    locale: Res<NumberLocale>,
 ) {
     if keyboard_input.just_pressed(KeyCode::Escape) {
         for (entity, mut field, mut delta, mut text, initial_value) in q_fields.iter_mut() {
             delta.accumulated_delta = 0.0;
+            delta.step_remainder = 0.0;
             commands
                 .entity(entity)
                 .remove::<NumericDeltaInitialValue<T>>();
-            field.set_value(initial_value.initial_value);
-            text.0 = field.value.to_string();
+            field.set_value(initial_value.initial_value.clone());
+            text.0 = field.value.format_localized(&locale);
         }
     }
 }
 
-pub struct DragNumericPlugin;
-impl Plugin for DragNumericPlugin {
-    fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (
-                on_drag_exit::<f32>,
-                on_drag_exit::<f64>,
-                on_drag_exit::<u8>,
-                on_drag_exit::<u16>,
-                on_drag_exit::<u32>,
-                on_drag_exit::<u64>,
-                on_drag_exit::<u128>,
-                on_drag_exit::<i8>,
-                on_drag_exit::<i16>,
-                on_drag_exit::<i32>,
-                on_drag_exit::<i64>,
-                on_drag_exit::<i128>,
-            ),
-        )
-        .add_observer(on_drag::<f32>)
-        .add_observer(on_drag_start::<f32>)
-        .add_observer(on_drag_end::<f32>)
-        .add_observer(on_drag::<f64>)
-        .add_observer(on_drag_start::<f64>)
-        .add_observer(on_drag_end::<f64>)
-        .add_observer(on_drag::<u8>)
-        .add_observer(on_drag_start::<u8>)
-        .add_observer(on_drag_end::<u8>)
-        .add_observer(on_drag::<u16>)
-        .add_observer(on_drag_start::<u16>)
-        .add_observer(on_drag_end::<u16>)
-        .add_observer(on_drag::<u32>)
-        .add_observer(on_drag_start::<u32>)
-        .add_observer(on_drag_end::<u32>)
-        .add_observer(on_drag::<u64>)
-        .add_observer(on_drag_start::<u64>)
-        .add_observer(on_drag_end::<u64>)
-        .add_observer(on_drag::<u128>)
-        .add_observer(on_drag_start::<u128>)
-        .add_observer(on_drag_end::<u128>)
-        .add_observer(on_drag::<i8>)
-        .add_observer(on_drag_start::<i8>)
-        .add_observer(on_drag_end::<i8>)
-        .add_observer(on_drag::<i16>)
-        .add_observer(on_drag_start::<i16>)
-        .add_observer(on_drag_end::<i16>)
-        .add_observer(on_drag::<i32>)
-        .add_observer(on_drag_start::<i32>)
-        .add_observer(on_drag_end::<i32>)
-        .add_observer(on_drag::<i64>)
-        .add_observer(on_drag_start::<i64>)
-        .add_observer(on_drag_end::<i64>)
-        .add_observer(on_drag::<i128>)
-        .add_observer(on_drag_start::<i128>)
-        .add_observer(on_drag_end::<i128>);
+pub(super) fn on_set_input_text(
+    trigger: Trigger<SetInputText>,
+    mut fields: Query<(
+        &mut InputTextValue,
+        &mut InputTextCursorPos,
+        Option<&mut InputTextSelection>,
+    )>,
+) {
+    let Ok((mut value, mut cursor_pos, selection)) = fields.get_mut(trigger.entity()) else {
+        return;
+    };
+    let text = &trigger.event().0;
+    if value.0 != *text {
+        value.0.clone_from(text);
+    }
+    let len = grapheme_len(&value.0);
+    if cursor_pos.0 > len {
+        cursor_pos.0 = len;
+    }
+    if let Some(mut selection) = selection {
+        selection.clear();
+    }
+}
+
+// Sends the text of fields changed since the last frame.
+pub(super) fn send_changed_value(
+    fields: Query<(Entity, Ref<InputTextValue>)>,
+    mut changed_writer: EventWriter<InputFieldChangedEvent>,
+) {
+    for (entity, value) in &fields {
+        if value.is_changed() && !value.is_added() {
+            changed_writer.send(InputFieldChangedEvent {
+                entity,
+                value: value.0.clone(),
+            });
+        }
+    }
+}
+
+pub(super) fn on_add_pattern(
+    trigger: Trigger<OnAdd, InputPattern>,
+    mut commands: Commands,
+    mut query: Query<(&mut InputPattern, Option<&mut InputTextValue>)>,
+) {
+    let entity = trigger.entity();
+    let Ok((mut pattern, value)) = query.get_mut(entity) else {
+        return;
+    };
+    if let Some(mut value) = value {
+        let conformed = pattern.conform(&value.0, false);
+        pattern.previous_len = grapheme_len(&conformed);
+        if conformed != value.0 {
+            value.0 = conformed;
+        }
+    }
+    commands.entity(entity).insert(pattern.allowed_chars());
+}
+
+/// Fits typed text to the [`InputPattern`], moving the cursor past inserted separators
+pub(super) fn conform_to_pattern(
+    mut query: Query<
+        (
+            &mut InputPattern,
+            &mut InputTextValue,
+            &mut InputTextCursorPos,
+        ),
+        Changed<InputTextValue>,
+    >,
+) {
+    for (mut pattern, mut value, mut cursor_pos) in &mut query {
+        let len = grapheme_len(&value.0);
+        let at_end = cursor_pos.0 >= len;
+        let conformed = pattern.conform(&value.0, len > pattern.previous_len);
+        pattern.previous_len = grapheme_len(&conformed);
+        if conformed == value.0 {
+            continue;
+        }
+        value.0 = conformed;
+        if at_end {
+            cursor_pos.0 = pattern.previous_len;
+        }
+    }
+}
+
+/// Moves the highlighted suggestion with the arrows and accepts it with Tab or Enter
+pub(super) fn suggestion_navigation(
+    mut input_reader: EventReader<KeyboardInput>,
+    mut query: Query<
+        (
+            Entity,
+            &InputInactive,
+            &mut TextInputSuggestions,
+            &mut InputTextValue,
+        ),
+        With<Focus>,
+    >,
+    mut accepted_writer: EventWriter<SuggestionAccepted>,
+) {
+    let keys: Vec<KeyCode> = input_reader
+        .read()
+        .filter(|input| input.state.is_pressed())
+        .map(|input| input.key_code)
+        .collect();
+    if keys.is_empty() {
+        return;
+    }
+
+    for (entity, inactive, mut suggestions, mut value) in &mut query {
+        if inactive.0 || suggestions.items.is_empty() {
+            continue;
+        }
+        for key in &keys {
+            let count = suggestions.items.len();
+            let accept = match key {
+                KeyCode::ArrowDown => {
+                    suggestions.highlighted = Some(
+                        suggestions
+                            .highlighted
+                            .map_or(0, |index| (index + 1) % count),
+                    );
+                    None
+                }
+                KeyCode::ArrowUp => {
+                    suggestions.highlighted = Some(
+                        suggestions
+                            .highlighted
+                            .map_or(count - 1, |index| (index + count - 1) % count),
+                    );
+                    None
+                }
+                KeyCode::Tab => Some(suggestions.highlighted.unwrap_or(0)),
+                KeyCode::Enter | KeyCode::NumpadEnter => suggestions.highlighted,
+                _ => None,
+            };
+            if let Some(index) = accept {
+                accept_suggestion(
+                    entity,
+                    index,
+                    &mut suggestions,
+                    &mut value,
+                    &mut accepted_writer,
+                );
+                break;
+            }
+        }
+    }
+}
+
+fn accept_suggestion(
+    entity: Entity,
+    index: usize,
+    suggestions: &mut TextInputSuggestions,
+    value: &mut InputTextValue,
+    accepted_writer: &mut EventWriter<SuggestionAccepted>,
+) {
+    let Some(item) = suggestions.items.get(index).cloned() else {
+        return;
+    };
+    suggestions.close();
+    suggestions.accepted = Some(item.clone());
+    value.0.clone_from(&item);
+    accepted_writer.send(SuggestionAccepted {
+        entity,
+        value: item,
+    });
+}
+
+/// Lists the completions of the typed value
+pub(super) fn refresh_suggestions(
+    mut query: Query<
+        (&InputTextValue, &mut TextInputSuggestions),
+        (Changed<InputTextValue>, With<Focus>),
+    >,
+) {
+    for (value, mut suggestions) in &mut query {
+        let Some(provider) = &suggestions.provider else {
+            continue;
+        };
+        if suggestions.accepted.as_ref() == Some(&value.0) {
+            continue;
+        }
+        let mut items = if value.0.is_empty() {
+            Vec::new()
+        } else {
+            provider.suggest(&value.0)
+        };
+        items.truncate(MAX_SUGGESTIONS);
+        suggestions.accepted = None;
+        suggestions.items = items;
+        suggestions.highlighted = None;
+    }
+}
+
+/// Respawns the suggestion popup under the text field
+pub(super) fn render_suggestions(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &mut TextInputSuggestions,
+            &InputTextFont,
+            &InputTextColor,
+        ),
+        Changed<TextInputSuggestions>,
+    >,
+) {
+    for (entity, mut suggestions, font, color) in &mut query {
+        let suggestions = suggestions.bypass_change_detection();
+        if let Some(popup) = suggestions.popup.take() {
+            commands.entity(popup).despawn_recursive();
+        }
+        if suggestions.items.is_empty() {
+            continue;
+        }
+
+        let popup = commands
+            .spawn((
+                Name::new("TextInputSuggestions"),
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    border: UiRect::all(Val::Px(1.)),
+                    padding: UiRect::vertical(Val::Px(4.)),
+                    ..default()
+                },
+                BackgroundColor(DEFAULT_BACKGROUND_COLOR),
+                BorderColor(SELECTED_BORDER_COLOR),
+                BorderRadius::all(Val::Px(4.)),
+                UiLayer::Popup,
+                PopupAnchor::below(entity).with_anchor_width(),
+            ))
+            .with_children(|parent| {
+                for (index, item) in suggestions.items.iter().enumerate() {
+                    let background = if suggestions.highlighted == Some(index) {
+                        HOVERED_BACKGROUND_COLOR
+                    } else {
+                        Color::NONE
+                    };
+                    parent
+                        .spawn((
+                            SuggestionItem {
+                                input: entity,
+                                index,
+                            },
+                            Node {
+                                padding: UiRect::axes(Val::Px(8.), Val::Px(2.)),
+                                ..default()
+                            },
+                            BackgroundColor(background),
+                        ))
+                        .with_child((Text::new(item.clone()), font.0.clone(), color.0));
+                }
+            })
+            .id();
+        suggestions.popup = Some(popup);
+    }
+}
+
+pub(super) fn suggestion_hover(
+    mut query: Query<(&Interaction, &SuggestionItem, &mut BackgroundColor), Changed<Interaction>>,
+    suggestions: Query<&TextInputSuggestions>,
+) {
+    for (interaction, item, mut background) in &mut query {
+        let highlighted = suggestions
+            .get(item.input)
+            .is_ok_and(|suggestions| suggestions.highlighted == Some(item.index));
+        background.0 = if highlighted || *interaction != Interaction::None {
+            HOVERED_BACKGROUND_COLOR
+        } else {
+            Color::NONE
+        };
+    }
+}
+
+pub(super) fn on_suggestion_click(
+    mut trigger: Trigger<Pointer<Click>>,
+    items: Query<&SuggestionItem>,
+    mut inputs: Query<(&mut TextInputSuggestions, &mut InputTextValue)>,
+    mut accepted_writer: EventWriter<SuggestionAccepted>,
+) {
+    let Ok(item) = items.get(trigger.entity()) else {
+        return;
+    };
+    trigger.propagate(false);
+    if trigger.event().button != PointerButton::Primary {
+        return;
+    }
+    if let Ok((mut suggestions, mut value)) = inputs.get_mut(item.input) {
+        accept_suggestion(
+            item.input,
+            item.index,
+            &mut suggestions,
+            &mut value,
+            &mut accepted_writer,
+        );
+    }
+}
+
+pub(super) fn close_suggestions_on_blur(
+    trigger: Trigger<OnRemove, Focus>,
+    mut query: Query<&mut TextInputSuggestions>,
+) {
+    if let Ok(mut suggestions) = query.get_mut(trigger.entity()) {
+        if !suggestions.items.is_empty() {
+            suggestions.close();
+        }
+    }
+}
+
+/// Describes a field on its accessibility node
+fn describe_field(
+    node: &mut accesskit::Node,
+    label: Option<&str>,
+    placeholder: Option<&str>,
+    value: String,
+    state: &InputFieldState,
+) {
+    match label {
+        Some(label) => node.set_label(label),
+        None => node.clear_label(),
+    }
+    match placeholder.filter(|placeholder| !placeholder.is_empty()) {
+        Some(placeholder) => node.set_placeholder(placeholder),
+        None => node.clear_placeholder(),
+    }
+    node.set_value(value);
+    node.add_action(accesskit::Action::Focus);
+    if *state == InputFieldState::Disabled {
+        node.set_disabled();
+        node.remove_action(accesskit::Action::SetValue);
+    } else {
+        node.clear_disabled();
+        node.add_action(accesskit::Action::SetValue);
+    }
+}
+
+/// Publishes the role, name, value and state of text fields to assistive technologies.
+pub(super) fn update_text_field_accessibility(
+    mut commands: Commands,
+    fields: Query<
+        (
+            Entity,
+            &InputTextValue,
+            &InputFieldSettings,
+            &InputFieldState,
+            &Placeholder,
+            &TextInputDescriptions,
+            Option<&AccessibilityNode>,
+        ),
+        (
+            With<TextInput>,
+            Or<(
+                Changed<InputTextValue>,
+                Changed<InputFieldState>,
+                Changed<Placeholder>,
+                Changed<TextInputDescriptions>,
+                Without<AccessibilityNode>,
+            )>,
+        ),
+    >,
+) {
+    for (entity, value, settings, state, placeholder, descriptions, accessible) in &fields {
+        let role = if settings.mask_character.is_some() {
+            accesskit::Role::PasswordInput
+        } else {
+            accesskit::Role::TextInput
+        };
+        let mut node = accessible.map_or_else(
+            || accesskit::Node::new(role),
+            |accessible| accessible.0.clone(),
+        );
+        node.set_role(role);
+        describe_field(
+            &mut node,
+            descriptions.label.as_deref(),
+            Some(&placeholder.0),
+            masked_value(&value.0, settings.mask_character),
+            state,
+        );
+        commands.entity(entity).insert(AccessibilityNode(node));
+    }
+}
+
+/// Publishes the value, bounds and state of numeric fields to assistive technologies.
+/// For custom numeric fields, you need to call this method in [`crate::WidgetsSystems::Style`]
+pub fn update_numeric_field_accessibility<T: NumericFieldValue>(
+    mut commands: Commands,
+    fields: Query<
+        (
+            Entity,
+            &NumericField<T>,
+            &InputTextValue,
+            &InputFieldState,
+            Option<&AccessibilityNode>,
+        ),
+        Or<(
+            Changed<NumericField<T>>,
+            Changed<InputTextValue>,
+            Changed<InputFieldState>,
+            Without<AccessibilityNode>,
+        )>,
+    >,
+) {
+    for (entity, field, value, state, accessible) in &fields {
+        let mut node = accessible.map_or_else(
+            || accesskit::Node::new(accesskit::Role::SpinButton),
+            |accessible| accessible.0.clone(),
+        );
+        node.set_role(accesskit::Role::SpinButton);
+        describe_field(&mut node, None, None, value.0.clone(), state);
+        if let Some(number) = field.value.to_f64() {
+            node.set_numeric_value(number);
+        }
+        match field.min.as_ref().and_then(|min| min.to_f64()) {
+            Some(min) => node.set_min_numeric_value(min),
+            None => node.clear_min_numeric_value(),
+        }
+        match field.max.as_ref().and_then(|max| max.to_f64()) {
+            Some(max) => node.set_max_numeric_value(max),
+            None => node.clear_max_numeric_value(),
+        }
+        match field.drag_step.as_ref().and_then(|step| step.to_f64()) {
+            Some(step) => node.set_numeric_value_step(step),
+            None => node.clear_numeric_value_step(),
+        }
+        commands.entity(entity).insert(AccessibilityNode(node));
+    }
+}
+
+/// Sets the value of text and numeric fields on the set value action of assistive technologies,
+/// like [`TextFieldExt::set_text`] does.
+pub(super) fn field_accessibility_actions(
+    mut commands: Commands,
+    mut requests: EventReader<ActionRequest>,
+    fields: Query<&InputFieldState>,
+) {
+    for request in requests.read() {
+        let Some(value) = action_value(request) else {
+            continue;
+        };
+        let Some(entity) = action_target(request) else {
+            continue;
+        };
+        if fields
+            .get(entity)
+            .is_ok_and(|state| *state != InputFieldState::Disabled)
+        {
+            commands.set_text(entity, value);
+        }
+    }
+}
+
+/// Adds the clock button toggling the dropdown of a [`NumericFieldHistory`], after the field parts
+pub(super) fn create_history_button(
+    mut commands: Commands,
+    fields: Query<(Entity, &InputTextColor), Added<NumericFieldHistory>>,
+) {
+    for (entity, color) in &fields {
+        let color = color.0 .0;
+        let button = commands
+            .spawn((
+                Name::new("NumericFieldHistoryButton"),
+                HistoryButton { input: entity },
+                Node {
+                    width: Val::Px(HISTORY_ICON_SIZE),
+                    height: Val::Px(HISTORY_ICON_SIZE),
+                    margin: UiRect::left(Val::Px(4.)),
+                    border: UiRect::all(Val::Px(1.)),
+                    flex_shrink: 0.,
+                    ..default()
+                },
+                BorderColor(color),
+                BorderRadius::MAX,
+            ))
+            .with_children(|clock| {
+                // Hands pointing at three o'clock
+                clock.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Percent(50.),
+                        top: Val::Px(1.),
+                        width: Val::Px(1.),
+                        height: Val::Percent(45.),
+                        ..default()
+                    },
+                    BackgroundColor(color),
+                    PickingBehavior::IGNORE,
+                ));
+                clock.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Percent(50.),
+                        top: Val::Percent(45.),
+                        width: Val::Percent(35.),
+                        height: Val::Px(1.),
+                        ..default()
+                    },
+                    BackgroundColor(color),
+                    PickingBehavior::IGNORE,
+                ));
+            })
+            .id();
+        commands.entity(entity).add_child(button);
+    }
+}
+
+pub(super) fn on_history_button_click(
+    mut trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    buttons: Query<&HistoryButton>,
+    mut fields: Query<(&mut NumericFieldHistory, &InputTextFont, &InputTextColor)>,
+) {
+    let Ok(button) = buttons.get(trigger.entity()) else {
+        return;
+    };
+    // Doesn't focus the field
+    trigger.propagate(false);
+    let Ok((mut history, font, color)) = fields.get_mut(button.input) else {
+        return;
+    };
+    if let Some(popup) = history.popup.take() {
+        commands.entity(popup).despawn_recursive();
+        return;
+    }
+    if history.values.is_empty() {
+        return;
+    }
+
+    let popup = commands
+        .spawn((
+            Name::new("NumericFieldHistory"),
+            Node {
+                flex_direction: FlexDirection::Column,
+                border: UiRect::all(Val::Px(1.)),
+                padding: UiRect::vertical(Val::Px(4.)),
+                ..default()
+            },
+            BackgroundColor(DEFAULT_BACKGROUND_COLOR),
+            BorderColor(SELECTED_BORDER_COLOR),
+            BorderRadius::all(Val::Px(4.)),
+            UiLayer::Popup,
+            PopupAnchor::below(button.input).with_anchor_width(),
+        ))
+        .with_children(|parent| {
+            for (index, value) in history.values.iter().enumerate() {
+                parent
+                    .spawn((
+                        HistoryItem {
+                            input: button.input,
+                            index,
+                        },
+                        Node {
+                            padding: UiRect::axes(Val::Px(8.), Val::Px(2.)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::NONE),
+                    ))
+                    .with_child((Text::new(value.clone()), font.0.clone(), color.0));
+            }
+        })
+        .id();
+    history.popup = Some(popup);
+}
+
+pub(super) fn history_item_hover(
+    mut query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (With<HistoryItem>, Changed<Interaction>),
+    >,
+) {
+    for (interaction, mut background) in &mut query {
+        background.0 = if *interaction == Interaction::None {
+            Color::NONE
+        } else {
+            HOVERED_BACKGROUND_COLOR
+        };
+    }
+}
+
+pub(super) fn on_history_item_click(
+    mut trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    items: Query<&HistoryItem>,
+    mut fields: Query<&mut NumericFieldHistory>,
+) {
+    let Ok(item) = items.get(trigger.entity()) else {
+        return;
+    };
+    trigger.propagate(false);
+    if trigger.event().button != PointerButton::Primary {
+        return;
+    }
+    let Ok(mut history) = fields.get_mut(item.input) else {
+        return;
+    };
+    if let Some(popup) = history.popup.take() {
+        commands.entity(popup).despawn_recursive();
+    }
+    let Some(value) = history.values.get(item.index).cloned() else {
+        return;
+    };
+    commands.set_text(item.input, value.clone());
+    history.push(value);
+}
+
+pub(super) fn close_history_on_remove(
+    trigger: Trigger<OnRemove, NumericFieldHistory>,
+    mut commands: Commands,
+    mut fields: Query<&mut NumericFieldHistory>,
+) {
+    if let Some(popup) = fields
+        .get_mut(trigger.entity())
+        .ok()
+        .and_then(|mut history| history.popup.take())
+    {
+        commands.entity(popup).despawn_recursive();
+    }
+}
+
+/// Records submitted values and cycles through them with Up and Down
+pub(super) fn text_input_history(
+    mut input_reader: EventReader<KeyboardInput>,
+    mut submit_reader: EventReader<InputFieldSubmitEvent>,
+    mut query: Query<(
+        &InputInactive,
+        &mut TextInputHistory,
+        &mut InputTextValue,
+        &mut InputTextCursorPos,
+        Option<&TextInputSuggestions>,
+        Has<Focus>,
+    )>,
+) {
+    for event in submit_reader.read() {
+        if let Ok((_, mut history, ..)) = query.get_mut(event.entity) {
+            history.push(event.value.clone());
+        }
+    }
+    let keys: Vec<KeyCode> = input_reader
+        .read()
+        .filter(|input| input.state.is_pressed())
+        .map(|input| input.key_code)
+        .collect();
+    if keys.is_empty() {
+        return;
+    }
+
+    for (inactive, mut history, mut value, mut cursor_pos, suggestions, focused) in &mut query {
+        // Arrows move the highlighted suggestion instead
+        let suggesting = suggestions.is_some_and(|suggestions| !suggestions.items.is_empty());
+        if !focused || inactive.0 || suggesting {
+            continue;
+        }
+        for key in &keys {
+            let text = match key {
+                KeyCode::ArrowUp => history.browse_previous(&value.0),
+                KeyCode::ArrowDown => history.browse_next(),
+                _ => continue,
+            };
+            if let Some(text) = text.map(str::to_string) {
+                cursor_pos.0 = grapheme_len(&text);
+                value.0 = text;
+            }
+        }
     }
 }
@@ -10,20 +10,173 @@
     clippy::borrow_interior_mutable_const,
     clippy::type_complexity,
 )]
+use accessibility::AccessibilityPlugin;
+use angle_field::AngleFieldPlugin;
+use badges::BadgesPlugin;
 use bevy::app::{App, Plugin, Update};
-use buttons::{systems::button_system, ButtonClickedEvent};
+use bevy::ecs::schedule::{IntoSystemConfigs, IntoSystemSetConfigs, SystemSet};
+use binding::BindingPlugin;
+use breadcrumbs::BreadcrumbsPlugin;
+use buttons::{
+    systems::{
+        apply_button_size, button_accessibility_actions, button_system, update_button_accessibility,
+    },
+    ButtonClickedEvent,
+};
+use card::CardPlugin;
 use clipboard::ClipboardPlugin;
+use console::ConsolePlugin;
+use cursor::CursorPlugin;
+use curves::CurvesPlugin;
+use date_time::DateTimePlugin;
+use decorative::DecorativePlugin;
+use derived_label::DerivedLabelPlugin;
+use dialogs::DialogsPlugin;
+use disabled::DisabledPlugin;
+use docking::DockingPlugin;
+use drag_and_drop::DragAndDropPlugin;
 use focus::FocusPlugin;
+use image_preview::ImagePreviewPlugin;
+use inline_edit::InlineEditPlugin;
 use input_fields::InputFieldPlugin;
+use link::LinkPlugin;
+use lists::ListsPlugin;
+use localization::LocalizationPlugin;
+use notifications::NotificationsPlugin;
+use overlay::OverlayPlugin;
+use progress::ProgressPlugin;
+use range_slider::RangeSliderPlugin;
+use responsive::ResponsivePlugin;
+use rich_text::RichTextPlugin;
+use separator::SeparatorPlugin;
+use sparkline::SparklinePlugin;
+use split_pane::SplitPanePlugin;
+use theme::ThemePlugin;
+use time_controls::TimeControlsPlugin;
+use toolbar::ToolbarPlugin;
+use touch::TouchPlugin;
+use transition::TransitionPlugin;
+use zoom::ZoomPlugin;
 
+/// Module containing all accessibility related configuration
+pub mod accessibility;
+/// Module containing all angle field related configuration
+pub mod angle_field;
+/// Module containing all badge and chip related configuration
+pub mod badges;
+/// Module containing all widget data binding related configuration
+pub mod binding;
+/// Module containing all breadcrumbs related configuration
+pub mod breadcrumbs;
 /// Module containing all button related configuration
 pub mod buttons;
+/// Module containing all card related configuration
+pub mod card;
 /// Module containing all clipboard related configuration
 pub mod clipboard;
+/// Module containing all console panel related configuration
+pub mod console;
+/// Module containing all hover cursor icon related configuration
+pub mod cursor;
+/// Module containing all curve and gradient editor related configuration
+pub mod curves;
+/// Module containing all date and time field related configuration
+pub mod date_time;
+/// Module containing all decorative children picking related configuration
+pub mod decorative;
+/// Module containing all derived label related configuration
+pub mod derived_label;
+/// Module containing all diagnostics panel related configuration
+pub mod diagnostics_panel;
+/// Module containing all confirmation and prompt dialog related configuration
+pub mod dialogs;
+/// Module containing all disabled subtree related configuration
+pub mod disabled;
+/// Module containing all docking related configuration
+pub mod docking;
+/// Module containing all drag and drop related configuration
+pub mod drag_and_drop;
 /// Module containing all focus related configuration
 pub mod focus;
+/// Module containing all image preview related configuration
+pub mod image_preview;
+/// Module containing all inline renaming related configuration
+pub mod inline_edit;
 /// Module containing all single line text field related configuration
 pub mod input_fields;
+/// Module containing all link related configuration
+pub mod link;
+/// Module containing all list related configuration
+pub mod lists;
+/// Module containing all widget string localization related configuration
+pub mod localization;
+/// Module containing all notification related configuration
+pub mod notifications;
+/// Module containing all overlay layer and popup positioning related configuration
+pub mod overlay;
+/// Module containing all UI state persistence related configuration
+pub mod persistence;
+/// Module containing all progress indicator related configuration
+pub mod progress;
+/// Module containing all range slider related configuration
+pub mod range_slider;
+/// Module containing all type registry report related configuration
+pub mod registry_report;
+/// Module containing all window size responsive widget related configuration
+pub mod responsive;
+/// Module containing all rich text related configuration
+pub mod rich_text;
+/// Module containing all separator and spacer related configuration
+pub mod separator;
+/// Module containing all sparkline related configuration
+pub mod sparkline;
+/// Module containing all split pane related configuration
+pub mod split_pane;
+/// Module containing all states and schedule panel related configuration
+pub mod states_panel;
+/// Module containing helpers to drive widgets in headless tests, requires the `test_utils` feature
+#[cfg(feature = "test_utils")]
+pub mod test_utils;
+/// Module containing all theme variant related configuration
+pub mod theme;
+/// Module containing all time controls related configuration
+pub mod time_controls;
+/// Module containing all toolbar related configuration
+pub mod toolbar;
+/// Module containing all touch input related configuration
+pub mod touch;
+/// Module containing all widget transition related configuration
+pub mod transition;
+/// Module containing all spawned widget reference related configuration
+pub mod widget_ref;
+/// Module containing all UI zoom related configuration
+pub mod zoom;
+
+/// Public ordering hooks of widget processing, run in `Update` in the declared order.
+///
+/// Order your systems relative to these sets, e.g. `.after(WidgetsSystems::Events)` to read the
+/// events sent by widgets in the same frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub enum WidgetsSystems {
+    /// Keyboard and focus handling, editing widget values
+    Input,
+    /// Text and scroll updates following value changes
+    Layout,
+    /// Colors and visibility reflecting widget states
+    Style,
+    /// Widget events, like button clicks
+    Events,
+}
+
+impl WidgetsSystems {
+    /// Orders the sets, safe to call from every plugin using them
+    pub(crate) fn configure(app: &mut App) {
+        app.configure_sets(
+            Update,
+            (Self::Input, Self::Layout, Self::Style, Self::Events).chain(),
+        );
+    }
+}
 
 /// Plugin for all Bevy widgets
 pub struct WidgetsPlugin;
@@ -32,7 +185,63 @@ impl Plugin for WidgetsPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<ButtonClickedEvent>()
             // Base/Transversal plugins
-            .add_plugins((ClipboardPlugin, FocusPlugin, InputFieldPlugin))
-            .add_systems(Update, button_system);
+            .add_plugins((
+                (
+                    AccessibilityPlugin,
+                    BadgesPlugin,
+                    BindingPlugin,
+                    BreadcrumbsPlugin,
+                    CardPlugin,
+                    ClipboardPlugin,
+                    ConsolePlugin,
+                    CursorPlugin,
+                    CurvesPlugin,
+                    DateTimePlugin,
+                    DecorativePlugin,
+                    DisabledPlugin,
+                    DockingPlugin,
+                    DragAndDropPlugin,
+                    FocusPlugin,
+                ),
+                (
+                    ImagePreviewPlugin,
+                    InputFieldPlugin,
+                    LinkPlugin,
+                    ListsPlugin,
+                    LocalizationPlugin,
+                    NotificationsPlugin,
+                    OverlayPlugin,
+                    ProgressPlugin,
+                    RangeSliderPlugin::<f32>::default(),
+                    RangeSliderPlugin::<i32>::default(),
+                    RichTextPlugin,
+                    SeparatorPlugin,
+                    SparklinePlugin,
+                    SplitPanePlugin,
+                    ToolbarPlugin,
+                ),
+                (
+                    AngleFieldPlugin,
+                    DerivedLabelPlugin,
+                    DialogsPlugin,
+                    InlineEditPlugin,
+                    ResponsivePlugin,
+                    ThemePlugin,
+                    TimeControlsPlugin,
+                    TouchPlugin,
+                    TransitionPlugin,
+                    ZoomPlugin,
+                ),
+            ))
+            .add_systems(
+                Update,
+                (
+                    button_accessibility_actions.in_set(WidgetsSystems::Input),
+                    apply_button_size.in_set(WidgetsSystems::Layout),
+                    update_button_accessibility.in_set(WidgetsSystems::Style),
+                    button_system.in_set(WidgetsSystems::Events),
+                ),
+            );
+        WidgetsSystems::configure(app);
     }
 }
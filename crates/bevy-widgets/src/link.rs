@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+
+use crate::focus::{stop_propagation, EventPropagation};
+
+const LINK_COLOR: Color = Color::srgb(0.19, 0.49, 0.71);
+const LINK_HOVERED_COLOR: Color = Color::srgb(0.51, 0.79, 1.);
+const LINK_PRESSED_COLOR: Color = Color::srgb(0.11, 0.33, 0.49);
+const LINK_FONT_SIZE: f32 = 13.0;
+
+/// Plugin containing the link logic
+pub struct LinkPlugin;
+
+impl Plugin for LinkPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<LinkClicked>()
+            .register_type::<Link>()
+            .add_observer(create_link)
+            .add_observer(on_link_click)
+            .add_systems(Update, link_style);
+
+        #[cfg(feature = "open")]
+        app.add_systems(Update, open_links);
+    }
+}
+
+/// Clickable text, underlined while hovered, sending a [`LinkClicked`] event with its target when clicked.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::link::Link;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn(Link::new("Bevy", "https://bevyengine.org").with_open_url());
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+#[require(Node, Interaction)]
+pub struct Link {
+    /// Text displayed
+    pub text: String,
+    /// Target sent with [`LinkClicked`]
+    pub target: String,
+    /// Opens `http` and `https` targets in the browser when clicked, requires the `open` feature
+    pub open_url: bool,
+}
+
+impl Link {
+    /// Creates a link displaying `text` and pointing to `target`
+    pub fn new(text: impl Into<String>, target: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            target: target.into(),
+            open_url: false,
+        }
+    }
+
+    /// Opens the target in the browser when clicked, requires the `open` feature
+    pub const fn with_open_url(mut self) -> Self {
+        self.open_url = true;
+        self
+    }
+
+    /// Checks if the target is a web URL
+    pub fn is_url(&self) -> bool {
+        self.target.starts_with("https://") || self.target.starts_with("http://")
+    }
+}
+
+/// Event sent with the target of a clicked [`Link`]
+#[derive(Event, Debug, Clone, PartialEq, Eq, Reflect)]
+pub struct LinkClicked(pub String);
+
+fn create_link(
+    trigger: Trigger<OnAdd, Link>,
+    mut commands: Commands,
+    mut links: Query<(&Link, &mut Node)>,
+) {
+    let entity = trigger.entity();
+    let Ok((link, mut node)) = links.get_mut(entity) else {
+        return;
+    };
+    node.border.bottom = Val::Px(1.);
+    commands
+        .entity(entity)
+        .insert(BorderColor(Color::NONE))
+        .with_child((
+            Text::new(link.text.clone()),
+            TextColor(LINK_COLOR),
+            TextFont {
+                font_size: LINK_FONT_SIZE,
+                ..default()
+            },
+            PickingBehavior::IGNORE,
+        ));
+}
+
+fn on_link_click(
+    mut trigger: Trigger<Pointer<Click>>,
+    links: Query<&Link>,
+    mut writer: EventWriter<LinkClicked>,
+    propagation: Query<&EventPropagation>,
+) {
+    let Ok(link) = links.get(trigger.entity()) else {
+        return;
+    };
+    stop_propagation(&mut trigger, &propagation);
+    if trigger.event().button == PointerButton::Primary {
+        writer.send(LinkClicked(link.target.clone()));
+    }
+}
+
+fn link_style(
+    mut links: Query<
+        (&Interaction, &Children, &mut BorderColor),
+        (With<Link>, Changed<Interaction>),
+    >,
+    mut texts: Query<&mut TextColor>,
+) {
+    for (interaction, children, mut border) in &mut links {
+        let color = match interaction {
+            Interaction::Pressed => LINK_PRESSED_COLOR,
+            Interaction::Hovered => LINK_HOVERED_COLOR,
+            Interaction::None => LINK_COLOR,
+        };
+        border.0 = if *interaction == Interaction::None {
+            Color::NONE
+        } else {
+            color
+        };
+        for child in children {
+            if let Ok(mut text_color) = texts.get_mut(*child) {
+                text_color.0 = color;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "open")]
+fn open_links(mut reader: EventReader<LinkClicked>, links: Query<&Link>) {
+    for LinkClicked(target) in reader.read() {
+        let Some(link) = links
+            .iter()
+            .find(|link| link.open_url && link.is_url() && &link.target == target)
+        else {
+            continue;
+        };
+        if let Err(error) = open::that(&link.target) {
+            warn!("Failed to open {}: {error}", link.target);
+        }
+    }
+}
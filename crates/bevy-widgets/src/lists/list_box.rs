@@ -0,0 +1,484 @@
+use std::collections::BTreeSet;
+
+use accesskit::Role;
+use bevy::a11y::AccessibilityNode;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+use crate::focus::{Clickable, Focus};
+
+const BACKGROUND_COLOR: Color = Color::srgb(0.13, 0.13, 0.14);
+const HOVERED_ROW_COLOR: Color = Color::srgb(0.11, 0.29, 0.42);
+const SELECTED_ROW_COLOR: Color = Color::srgb(0.19, 0.49, 0.71);
+const CURSOR_BORDER_COLOR: Color = Color::srgb(0.51, 0.79, 1.);
+const TEXT_COLOR: Color = Color::srgb(0.97, 0.97, 0.98);
+const FONT_SIZE: f32 = 10.0;
+const DEFAULT_HEIGHT: f32 = 160.0;
+/// Seconds after which typed characters start a new type-ahead search
+const TYPE_AHEAD_TIMEOUT: f32 = 1.0;
+
+/// How many rows of a [`ListBox`] can be selected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum SelectionMode {
+    /// Selecting a row replaces the selection
+    #[default]
+    Single,
+    /// Ctrl toggles rows in the selection and Shift extends it as a range
+    Multiple,
+}
+
+/// Scrollable list of selectable rows.
+///
+/// Rows are selected with the mouse, or once the list is focused with the arrows, Home and End,
+/// and by typing the start of a row. In [`SelectionMode::Multiple`], Ctrl + A selects every row.
+/// Each change of the selection sends a [`ListBoxSelectionChanged`] event.
+/// Should be created with [`ListBoxBuilder`].
+#[derive(Component, Debug, Clone, Default)]
+#[require(Node, Clickable)]
+pub struct ListBox {
+    items: Vec<String>,
+    mode: SelectionMode,
+    selected: BTreeSet<usize>,
+    cursor: Option<usize>,
+    /// First row of Shift range selections
+    anchor: Option<usize>,
+    typed: String,
+    typed_at: f32,
+    rows: Vec<Entity>,
+    /// Rows are respawned on the next update
+    rebuild: bool,
+    /// The cursor row is scrolled into view on the next update
+    reveal: bool,
+}
+
+impl ListBox {
+    /// Text of the rows
+    pub fn items(&self) -> &[String] {
+        &self.items
+    }
+
+    /// Replaces the rows, keeping the selected indices that still exist
+    pub fn set_items(&mut self, items: impl IntoIterator<Item = impl Into<String>>) {
+        self.items = items.into_iter().map(Into::into).collect();
+        let len = self.items.len();
+        self.selected.retain(|index| *index < len);
+        self.cursor = self.cursor.filter(|cursor| *cursor < len);
+        self.anchor = self.anchor.filter(|anchor| *anchor < len);
+        self.rebuild = true;
+    }
+
+    /// How many rows can be selected
+    pub const fn selection_mode(&self) -> SelectionMode {
+        self.mode
+    }
+
+    /// Indices of the selected rows, in order
+    pub fn selected(&self) -> impl Iterator<Item = usize> + '_ {
+        self.selected.iter().copied()
+    }
+
+    /// Text of the selected rows, in order
+    pub fn selected_items(&self) -> impl Iterator<Item = &str> + '_ {
+        self.selected
+            .iter()
+            .filter_map(|index| self.items.get(*index).map(String::as_str))
+    }
+
+    /// Whether the row at `index` is selected
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selected.contains(&index)
+    }
+
+    /// Row moved by the keyboard
+    pub const fn cursor(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    /// Selects only the row at `index`, ignored when out of bounds
+    pub fn select(&mut self, index: usize) {
+        if index < self.items.len() {
+            self.pick(index, false, false);
+        }
+    }
+
+    /// Deselects every row
+    pub fn clear_selection(&mut self) {
+        self.selected.clear();
+        self.anchor = None;
+    }
+
+    /// Moves the cursor to `index`, extending the selection as a range or toggling the row
+    fn pick(&mut self, index: usize, extend: bool, toggle: bool) {
+        let multiple = self.mode == SelectionMode::Multiple;
+        match self.anchor {
+            Some(anchor) if multiple && extend => {
+                self.selected = (anchor.min(index)..=anchor.max(index)).collect();
+            }
+            _ if multiple && toggle => {
+                if !self.selected.remove(&index) {
+                    self.selected.insert(index);
+                }
+                self.anchor = Some(index);
+            }
+            _ => {
+                self.selected = BTreeSet::from([index]);
+                self.anchor = Some(index);
+            }
+        }
+        self.cursor = Some(index);
+        self.reveal = true;
+    }
+
+    /// Next row starting with the type-ahead text, case insensitive, from the cursor
+    fn type_ahead_match(&self) -> Option<usize> {
+        let typed = self.typed.to_lowercase();
+        let len = self.items.len();
+        // A repeated character cycles through the rows starting with it
+        let repeated = typed.chars().count() > 1 && typed.chars().all(|c| typed.starts_with(c));
+        let (prefix, skip) = match (repeated, typed.chars().next()) {
+            (true, Some(first)) => (first.to_string(), 1),
+            _ => (typed, 0),
+        };
+        let start = self.cursor.map_or(0, |cursor| cursor + skip);
+        (0..len)
+            .map(|offset| (start + offset) % len)
+            .find(|index| self.items[*index].to_lowercase().starts_with(&prefix))
+    }
+}
+
+/// Row of a [`ListBox`]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[require(Interaction)]
+pub struct ListBoxRow {
+    list: Entity,
+    index: usize,
+}
+
+impl ListBoxRow {
+    /// Entity of the [`ListBox`]
+    pub const fn list(&self) -> Entity {
+        self.list
+    }
+
+    /// Index of the row in the list items
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// An event that is fired when the selection of a [`ListBox`] changes.
+#[derive(Event, Debug, Clone, PartialEq, Eq, Reflect)]
+pub struct ListBoxSelectionChanged {
+    /// The list entity
+    pub entity: Entity,
+    /// Indices of the selected rows, in order
+    pub selected: Vec<usize>,
+}
+
+/// Builder for [`ListBox`]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ListBoxBuilder {
+    items: Vec<String>,
+    mode: SelectionMode,
+    selected: Vec<usize>,
+    height: Option<Val>,
+}
+
+impl ListBoxBuilder {
+    /// Creates a builder of a list showing `items`
+    pub fn new(items: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            items: items.into_iter().map(Into::into).collect(),
+            ..default()
+        }
+    }
+
+    /// Sets how many rows can be selected. Defaults to [`SelectionMode::Single`].
+    pub const fn with_selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the initially selected rows, only the last one in [`SelectionMode::Single`]
+    pub fn with_selected(mut self, selected: impl IntoIterator<Item = usize>) -> Self {
+        self.selected = selected.into_iter().collect();
+        self
+    }
+
+    /// Sets the list height, rows scroll past it. Defaults to `160px`.
+    pub const fn with_height(mut self, height: Val) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Builds the list
+    pub fn build(self) -> (ListBox, Node, BackgroundColor, AccessibilityNode) {
+        let len = self.items.len();
+        let selected: BTreeSet<usize> = if self.mode == SelectionMode::Single {
+            self.selected
+                .iter()
+                .rev()
+                .copied()
+                .find(|index| *index < len)
+                .into_iter()
+                .collect()
+        } else {
+            self.selected
+                .iter()
+                .copied()
+                .filter(|index| *index < len)
+                .collect()
+        };
+        let mut accessibility = accesskit::Node::new(Role::ListBox);
+        if self.mode == SelectionMode::Multiple {
+            accessibility.set_multiselectable();
+        }
+        (
+            ListBox {
+                items: self.items,
+                mode: self.mode,
+                cursor: selected.first().copied(),
+                anchor: selected.first().copied(),
+                selected,
+                rebuild: true,
+                ..default()
+            },
+            Node {
+                flex_direction: FlexDirection::Column,
+                height: self.height.unwrap_or(Val::Px(DEFAULT_HEIGHT)),
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+            BackgroundColor(BACKGROUND_COLOR),
+            AccessibilityNode::from(accessibility),
+        )
+    }
+}
+
+fn ctrl_pressed(keys: &ButtonInput<KeyCode>) -> bool {
+    keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ])
+}
+
+fn shift_pressed(keys: &ButtonInput<KeyCode>) -> bool {
+    keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight])
+}
+
+pub(super) fn update_list_box_rows(
+    mut commands: Commands,
+    mut lists: Query<(Entity, &mut ListBox), Changed<ListBox>>,
+) {
+    for (entity, mut list) in &mut lists {
+        let list = list.bypass_change_detection();
+        if !std::mem::take(&mut list.rebuild) {
+            continue;
+        }
+        let mut rows = Vec::with_capacity(list.items.len());
+        commands
+            .entity(entity)
+            .despawn_descendants()
+            .with_children(|parent| {
+                for (index, item) in list.items.iter().enumerate() {
+                    let mut accessibility = accesskit::Node::new(Role::ListBoxOption);
+                    accessibility.set_label(item.as_str());
+                    let row = parent
+                        .spawn((
+                            ListBoxRow {
+                                list: entity,
+                                index,
+                            },
+                            Node {
+                                padding: UiRect::axes(Val::Px(8.), Val::Px(2.)),
+                                border: UiRect::all(Val::Px(1.)),
+                                flex_shrink: 0.,
+                                ..default()
+                            },
+                            BackgroundColor(Color::NONE),
+                            BorderColor(Color::NONE),
+                            AccessibilityNode::from(accessibility),
+                        ))
+                        .with_child((
+                            Text::new(item.clone()),
+                            TextColor(TEXT_COLOR),
+                            TextFont {
+                                font_size: FONT_SIZE,
+                                ..default()
+                            },
+                        ))
+                        .id();
+                    rows.push(row);
+                }
+            });
+        list.rows = rows;
+    }
+}
+
+pub(super) fn on_list_box_row_click(
+    trigger: Trigger<Pointer<Click>>,
+    rows: Query<&ListBoxRow>,
+    mut lists: Query<&mut ListBox>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut event_writer: EventWriter<ListBoxSelectionChanged>,
+) {
+    // Propagates to the list so it gets focused.
+    let Ok(row) = rows.get(trigger.entity()) else {
+        return;
+    };
+    if trigger.event().button != PointerButton::Primary {
+        return;
+    }
+    let Ok(mut list) = lists.get_mut(row.list) else {
+        return;
+    };
+    let previous = list.selected.clone();
+    list.pick(row.index, shift_pressed(&keys), ctrl_pressed(&keys));
+    list.reveal = false;
+    if list.selected != previous {
+        event_writer.send(ListBoxSelectionChanged {
+            entity: row.list,
+            selected: list.selected().collect(),
+        });
+    }
+}
+
+pub(super) fn list_box_keyboard(
+    mut events: EventReader<KeyboardInput>,
+    mut lists: Query<(Entity, &mut ListBox), With<Focus>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time<Real>>,
+    mut event_writer: EventWriter<ListBoxSelectionChanged>,
+) {
+    let events: Vec<&KeyboardInput> = events
+        .read()
+        .filter(|event| event.state.is_pressed())
+        .collect();
+    if events.is_empty() {
+        return;
+    }
+    let (ctrl, shift) = (ctrl_pressed(&keys), shift_pressed(&keys));
+    let now = time.elapsed_secs();
+    for (entity, mut list) in &mut lists {
+        let len = list.items.len();
+        if len == 0 {
+            continue;
+        }
+        let previous = list.selected.clone();
+        for event in &events {
+            let last = len - 1;
+            let target = match &event.logical_key {
+                Key::ArrowUp => Some(list.cursor.map_or(0, |cursor| cursor.saturating_sub(1))),
+                Key::ArrowDown => Some(list.cursor.map_or(0, |cursor| (cursor + 1).min(last))),
+                Key::Home => Some(0),
+                Key::End => Some(last),
+                Key::Character(text) if ctrl && text.eq_ignore_ascii_case("a") => {
+                    if list.mode == SelectionMode::Multiple {
+                        list.selected = (0..len).collect();
+                    }
+                    None
+                }
+                Key::Character(text) if !ctrl => {
+                    if now - list.typed_at > TYPE_AHEAD_TIMEOUT {
+                        list.typed.clear();
+                    }
+                    list.typed.push_str(text);
+                    list.typed_at = now;
+                    list.type_ahead_match()
+                }
+                _ => None,
+            };
+            if let Some(target) = target {
+                list.pick(target, shift, false);
+            }
+        }
+        if list.selected != previous {
+            event_writer.send(ListBoxSelectionChanged {
+                entity,
+                selected: list.selected().collect(),
+            });
+        }
+    }
+}
+
+pub(super) fn style_list_box_rows(
+    lists: Query<(&ListBox, Has<Focus>)>,
+    mut rows: Query<(
+        &ListBoxRow,
+        &Interaction,
+        &mut BackgroundColor,
+        &mut BorderColor,
+        &mut AccessibilityNode,
+    )>,
+) {
+    for (row, interaction, mut background, mut border, mut accessibility) in &mut rows {
+        let Ok((list, focused)) = lists.get(row.list) else {
+            continue;
+        };
+        let selected = list.is_selected(row.index);
+        let color = if selected {
+            SELECTED_ROW_COLOR
+        } else if *interaction == Interaction::None {
+            Color::NONE
+        } else {
+            HOVERED_ROW_COLOR
+        };
+        if background.0 != color {
+            background.0 = color;
+        }
+        let border_color = if focused && list.cursor == Some(row.index) {
+            CURSOR_BORDER_COLOR
+        } else {
+            Color::NONE
+        };
+        if border.0 != border_color {
+            border.0 = border_color;
+        }
+        if accessibility.is_selected() != Some(selected) {
+            accessibility.set_selected(selected);
+        }
+    }
+}
+
+// Keeps the row moved by the keyboard inside the scrolled area
+pub(super) fn reveal_list_box_cursor(
+    mut lists: Query<(
+        &mut ListBox,
+        &mut ScrollPosition,
+        &GlobalTransform,
+        &ComputedNode,
+    )>,
+    rows: Query<(&GlobalTransform, &ComputedNode), With<ListBoxRow>>,
+) {
+    for (mut list, mut scroll, transform, computed) in &mut lists {
+        if !list.reveal {
+            continue;
+        }
+        list.bypass_change_detection().reveal = false;
+        let Some((row_transform, row_computed)) = list
+            .cursor
+            .and_then(|cursor| list.rows.get(cursor))
+            .and_then(|row| rows.get(*row).ok())
+        else {
+            continue;
+        };
+        let scale = computed.inverse_scale_factor();
+        let list_top = transform
+            .translation()
+            .y
+            .mul_add(scale, -computed.size().y * scale / 2.);
+        let list_bottom = computed.size().y.mul_add(scale, list_top);
+        let row_top = row_transform
+            .translation()
+            .y
+            .mul_add(scale, -row_computed.size().y * scale / 2.);
+        let row_bottom = row_computed.size().y.mul_add(scale, row_top);
+        if row_top < list_top {
+            scroll.offset_y -= list_top - row_top;
+        } else if row_bottom > list_bottom {
+            scroll.offset_y += row_bottom - list_bottom;
+        }
+    }
+}
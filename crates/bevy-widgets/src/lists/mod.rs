@@ -0,0 +1,41 @@
+use bevy::app::{App, Plugin, Update};
+use bevy::ecs::schedule::IntoSystemConfigs;
+
+use crate::WidgetsSystems;
+
+mod list_box;
+mod reorderable;
+
+pub use list_box::{ListBox, ListBoxBuilder, ListBoxRow, ListBoxSelectionChanged, SelectionMode};
+pub use reorderable::{
+    ListReorderedEvent, ReorderIndicator, ReorderableList, ReorderableListBuilder,
+};
+
+/// Plugin containing list widgets logic
+pub struct ListsPlugin;
+
+impl Plugin for ListsPlugin {
+    fn build(&self, app: &mut App) {
+        WidgetsSystems::configure(app);
+        app.add_event::<ListReorderedEvent>()
+            .add_event::<ListBoxSelectionChanged>()
+            .register_type::<SelectionMode>()
+            .add_observer(reorderable::on_row_drag_start)
+            .add_observer(reorderable::on_row_drag)
+            .add_observer(reorderable::on_row_drag_end)
+            .add_observer(list_box::on_list_box_row_click)
+            .add_systems(
+                Update,
+                (
+                    list_box::list_box_keyboard.in_set(WidgetsSystems::Input),
+                    (
+                        list_box::update_list_box_rows,
+                        list_box::reveal_list_box_cursor,
+                    )
+                        .chain()
+                        .in_set(WidgetsSystems::Layout),
+                    list_box::style_list_box_rows.in_set(WidgetsSystems::Style),
+                ),
+            );
+    }
+}
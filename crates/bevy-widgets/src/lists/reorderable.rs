@@ -0,0 +1,284 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+const INDICATOR_COLOR: Color = Color::srgb(0.19, 0.49, 0.71);
+const INDICATOR_THICKNESS: f32 = 2.0;
+const DEFAULT_ROW_GAP: f32 = 4.0;
+
+/// Container whose direct children (rows) can be dragged to new positions.
+///
+/// While a row is dragged, a [`ReorderIndicator`] shows where it will be inserted.
+/// On release, the row is moved and a [`ListReorderedEvent`] is sent.
+/// Should be created with [`ReorderableListBuilder`].
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+#[require(Node)]
+pub struct ReorderableList {
+    #[reflect(ignore)]
+    drag: Option<RowDrag>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct RowDrag {
+    row: Entity,
+    indicator: Entity,
+    from: usize,
+    to: usize,
+}
+
+/// Marker component for the insertion indicator of a [`ReorderableList`].
+#[derive(Component, Default, Reflect)]
+pub struct ReorderIndicator;
+
+/// An event that is fired when a row of a [`ReorderableList`] is moved.
+#[derive(Event, Debug, Reflect)]
+pub struct ListReorderedEvent {
+    /// The list entity
+    pub entity: Entity,
+    /// Previous index of the row
+    pub from: usize,
+    /// New index of the row
+    pub to: usize,
+}
+
+/// Builder for [`ReorderableList`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReorderableListBuilder {
+    direction: FlexDirection,
+    gap: f32,
+}
+
+impl Default for ReorderableListBuilder {
+    fn default() -> Self {
+        Self {
+            direction: FlexDirection::Column,
+            gap: DEFAULT_ROW_GAP,
+        }
+    }
+}
+
+impl ReorderableListBuilder {
+    /// Sets the direction rows are laid out. Defaults to [`FlexDirection::Column`].
+    pub const fn with_direction(mut self, direction: FlexDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the gap between rows in logical pixels. Defaults to `4.`.
+    pub const fn with_gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Builds the list container, rows should be spawned as its children
+    pub fn build(self) -> (ReorderableList, Node) {
+        (
+            ReorderableList::default(),
+            Node {
+                flex_direction: self.direction,
+                row_gap: Val::Px(self.gap),
+                column_gap: Val::Px(self.gap),
+                position_type: PositionType::Relative,
+                ..default()
+            },
+        )
+    }
+}
+
+/// Logical rect of a UI node along the list main axis: `(start, end)`.
+fn main_axis_span(
+    transform: &GlobalTransform,
+    node: &ComputedNode,
+    horizontal: bool,
+) -> (f32, f32) {
+    let center = transform.translation().truncate() * node.inverse_scale_factor();
+    let half = node.size() * node.inverse_scale_factor() / 2.;
+    if horizontal {
+        (center.x - half.x, center.x + half.x)
+    } else {
+        (center.y - half.y, center.y + half.y)
+    }
+}
+
+pub(super) fn on_row_drag_start(
+    mut trigger: Trigger<Pointer<DragStart>>,
+    mut commands: Commands,
+    parents: Query<&Parent>,
+    mut lists: Query<(&mut ReorderableList, &Children, &Node)>,
+    indicators: Query<(), With<ReorderIndicator>>,
+) {
+    let row = trigger.entity();
+    let Ok(parent) = parents.get(row) else {
+        return;
+    };
+    let Ok((mut list, children, node)) = lists.get_mut(parent.get()) else {
+        return;
+    };
+    if indicators.contains(row) {
+        return;
+    }
+    trigger.propagate(false);
+
+    let Some(from) = children
+        .iter()
+        .filter(|child| !indicators.contains(**child))
+        .position(|child| *child == row)
+    else {
+        return;
+    };
+
+    let horizontal = matches!(
+        node.flex_direction,
+        FlexDirection::Row | FlexDirection::RowReverse
+    );
+    let indicator = commands
+        .spawn((
+            ReorderIndicator,
+            Name::new("ReorderIndicator"),
+            Node {
+                position_type: PositionType::Absolute,
+                width: if horizontal {
+                    Val::Px(INDICATOR_THICKNESS)
+                } else {
+                    Val::Percent(100.)
+                },
+                height: if horizontal {
+                    Val::Percent(100.)
+                } else {
+                    Val::Px(INDICATOR_THICKNESS)
+                },
+                ..default()
+            },
+            BackgroundColor(INDICATOR_COLOR),
+            Visibility::Hidden,
+            FocusPolicy::Pass,
+            PickingBehavior::IGNORE,
+        ))
+        .id();
+    commands.entity(parent.get()).add_child(indicator);
+
+    list.drag = Some(RowDrag {
+        row,
+        indicator,
+        from,
+        to: from,
+    });
+}
+
+pub(super) fn on_row_drag(
+    mut trigger: Trigger<Pointer<Drag>>,
+    parents: Query<&Parent>,
+    mut lists: Query<
+        (
+            &mut ReorderableList,
+            &Children,
+            &Node,
+            &GlobalTransform,
+            &ComputedNode,
+        ),
+        Without<ReorderIndicator>,
+    >,
+    rows: Query<(&GlobalTransform, &ComputedNode), Without<ReorderIndicator>>,
+    mut indicators: Query<(&mut Node, &mut Visibility), With<ReorderIndicator>>,
+    ui_scale: Res<UiScale>,
+) {
+    let row = trigger.entity();
+    let Ok(parent) = parents.get(row) else {
+        return;
+    };
+    let Ok((mut list, children, list_node, list_transform, list_computed)) =
+        lists.get_mut(parent.get())
+    else {
+        return;
+    };
+    let Some(drag) = list.drag.as_mut().filter(|drag| drag.row == row) else {
+        return;
+    };
+    trigger.propagate(false);
+
+    let horizontal = matches!(
+        list_node.flex_direction,
+        FlexDirection::Row | FlexDirection::RowReverse
+    );
+    let reverse = matches!(
+        list_node.flex_direction,
+        FlexDirection::RowReverse | FlexDirection::ColumnReverse
+    );
+    let pointer = trigger.pointer_location.position / ui_scale.0;
+    let pointer = if horizontal { pointer.x } else { pointer.y };
+
+    let spans: Vec<(f32, f32)> = children
+        .iter()
+        .filter(|child| **child != drag.indicator)
+        .filter_map(|child| rows.get(*child).ok())
+        .map(|(transform, node)| main_axis_span(transform, node, horizontal))
+        .collect();
+    let Some(last) = spans.last() else {
+        return;
+    };
+
+    // Rows before the pointer, in children order.
+    let slot = spans
+        .iter()
+        .filter(|(start, end)| {
+            let center = (start + end) / 2.;
+            if reverse {
+                center > pointer
+            } else {
+                center < pointer
+            }
+        })
+        .count();
+    drag.to = if slot > drag.from { slot - 1 } else { slot };
+
+    let edge = match (spans.get(slot), reverse) {
+        (Some((start, _)), false) => *start,
+        (Some((_, end)), true) => *end,
+        (None, false) => last.1,
+        (None, true) => last.0,
+    };
+    let (list_start, _) = main_axis_span(list_transform, list_computed, horizontal);
+    let offset = Val::Px(edge - list_start - INDICATOR_THICKNESS / 2.);
+
+    if let Ok((mut node, mut visibility)) = indicators.get_mut(drag.indicator) {
+        if horizontal {
+            node.left = offset;
+        } else {
+            node.top = offset;
+        }
+        *visibility = Visibility::Inherited;
+    }
+}
+
+pub(super) fn on_row_drag_end(
+    mut trigger: Trigger<Pointer<DragEnd>>,
+    mut commands: Commands,
+    parents: Query<&Parent>,
+    mut lists: Query<&mut ReorderableList>,
+    mut event_writer: EventWriter<ListReorderedEvent>,
+) {
+    let row = trigger.entity();
+    let Ok(parent) = parents.get(row) else {
+        return;
+    };
+    let list_entity = parent.get();
+    let Ok(mut list) = lists.get_mut(list_entity) else {
+        return;
+    };
+    let Some(drag) = list.drag.take_if(|drag| drag.row == row) else {
+        return;
+    };
+    trigger.propagate(false);
+
+    commands.entity(drag.indicator).despawn_recursive();
+    if drag.from != drag.to {
+        commands
+            .entity(list_entity)
+            .insert_children(drag.to, &[drag.row]);
+        event_writer.send(ListReorderedEvent {
+            entity: list_entity,
+            from: drag.from,
+            to: drag.to,
+        });
+    }
+}
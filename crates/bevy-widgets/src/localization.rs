@@ -0,0 +1,199 @@
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+use crate::{
+    buttons::builder::ButtonsText,
+    input_fields::{Placeholder, TextInputDescriptions},
+    WidgetsSystems,
+};
+
+/// Plugin resolving localized widget strings with the [`Localizer`] resource
+pub struct LocalizationPlugin;
+
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        WidgetsSystems::configure(app);
+        app.register_type::<LocalizedTexts>().add_systems(
+            Update,
+            resolve_localized_texts.in_set(WidgetsSystems::Input),
+        );
+    }
+}
+
+/// Translates localization keys into strings of the active locale
+pub trait Localize: Send + Sync + 'static {
+    /// Resolves `key`, `None` when the active locale has no translation for it
+    fn localize(&self, key: &str) -> Option<String>;
+}
+
+impl<F> Localize for F
+where
+    F: Fn(&str) -> Option<String> + Send + Sync + 'static,
+{
+    fn localize(&self, key: &str) -> Option<String> {
+        self(key)
+    }
+}
+
+/// Active locale used to resolve the keys of every [`LocalizedTexts`].
+///
+/// Inserting or replacing this resource, e.g. when the user switches language,
+/// re-resolves the labels, placeholders, hints and button texts of all widgets.
+#[derive(Resource, Clone)]
+pub struct Localizer(Arc<dyn Localize>);
+
+impl Localizer {
+    /// Creates a localizer from a [`Localize`] implementation or a `Fn(&str) -> Option<String>`
+    pub fn new(localize: impl Localize) -> Self {
+        Self(Arc::new(localize))
+    }
+
+    /// Resolves `key`, falling back to the key itself when it has no translation
+    pub fn resolve(&self, key: &str) -> String {
+        self.0.localize(key).unwrap_or_else(|| key.to_string())
+    }
+}
+
+impl std::fmt::Debug for Localizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Localizer").finish_non_exhaustive()
+    }
+}
+
+/// A widget string, either displayed as is or resolved by the [`Localizer`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+pub enum LocalizedString {
+    /// String displayed as is
+    Literal(String),
+    /// Localization key, displayed as is until resolved
+    Key(String),
+}
+
+impl LocalizedString {
+    /// Creates a string resolved from a localization key
+    pub fn key(key: impl Into<String>) -> Self {
+        Self::Key(key.into())
+    }
+
+    /// The localization key, `None` for literals
+    pub fn as_key(&self) -> Option<&str> {
+        match self {
+            Self::Literal(_) => None,
+            Self::Key(key) => Some(key),
+        }
+    }
+
+    /// Consumes the string, returning the literal or the key
+    pub fn into_inner(self) -> String {
+        match self {
+            Self::Literal(text) | Self::Key(text) => text,
+        }
+    }
+}
+
+impl From<String> for LocalizedString {
+    fn from(value: String) -> Self {
+        Self::Literal(value)
+    }
+}
+
+impl From<&str> for LocalizedString {
+    fn from(value: &str) -> Self {
+        Self::Literal(value.to_string())
+    }
+}
+
+/// Localization keys of the strings of a widget, added by builders taking a [`LocalizedString::Key`].
+///
+/// - `label`, `hint` and `placeholder` update the text field descriptions and [`Placeholder`].
+/// - `text` updates the button text, or the [`Text`] of the entity itself for any other widget.
+#[derive(Component, Debug, Clone, PartialEq, Eq, Hash, Default, Reflect)]
+#[reflect(Component)]
+pub struct LocalizedTexts {
+    /// Key of the text field label
+    pub label: Option<String>,
+    /// Key of the text field hint
+    pub hint: Option<String>,
+    /// Key of the text field placeholder
+    pub placeholder: Option<String>,
+    /// Key of the displayed text
+    pub text: Option<String>,
+}
+
+impl LocalizedTexts {
+    /// Checks if the widget has no localized string
+    pub const fn is_empty(&self) -> bool {
+        self.label.is_none()
+            && self.hint.is_none()
+            && self.placeholder.is_none()
+            && self.text.is_none()
+    }
+
+    /// Records the key of `string` in `slot`, returning the string to display until it is resolved
+    pub(crate) fn track(slot: &mut Option<String>, string: LocalizedString) -> String {
+        *slot = string.as_key().map(ToString::to_string);
+        string.into_inner()
+    }
+}
+
+fn set_text(mut text: Mut<Text>, value: String) {
+    if text.0 != value {
+        text.0 = value;
+    }
+}
+
+fn resolve_localized_texts(
+    localizer: Option<Res<Localizer>>,
+    mut widgets: Query<(
+        Ref<LocalizedTexts>,
+        Option<&mut TextInputDescriptions>,
+        Option<&mut Placeholder>,
+        Option<&mut ButtonsText>,
+        Option<&mut Text>,
+        Option<&Children>,
+    )>,
+    mut texts: Query<&mut Text, Without<LocalizedTexts>>,
+) {
+    let Some(localizer) = localizer else {
+        return;
+    };
+    for (keys, descriptions, placeholder, button_text, text, children) in &mut widgets {
+        if !localizer.is_changed() && !keys.is_changed() {
+            continue;
+        }
+
+        if let Some(mut descriptions) = descriptions {
+            if let Some(label) = keys.label.as_deref().map(|key| localizer.resolve(key)) {
+                if descriptions.label() != Some(&label) {
+                    descriptions.set_label(Some(label));
+                }
+            }
+            if let Some(hint) = keys.hint.as_deref().map(|key| localizer.resolve(key)) {
+                if descriptions.hint() != Some(&hint) {
+                    descriptions.set_hint(Some(hint));
+                }
+            }
+        }
+        if let (Some(mut placeholder), Some(key)) = (placeholder, &keys.placeholder) {
+            placeholder.set_if_neq(Placeholder(localizer.resolve(key)));
+        }
+
+        let Some(key) = &keys.text else {
+            continue;
+        };
+        let resolved = localizer.resolve(key);
+        if let Some(mut button_text) = button_text {
+            button_text.set_if_neq(ButtonsText(resolved.clone()));
+            let inner = children
+                .into_iter()
+                .flatten()
+                .find(|child| texts.contains(**child));
+            if let Some(text) = inner.and_then(|inner| texts.get_mut(*inner).ok()) {
+                set_text(text, resolved);
+            }
+        } else if let Some(text) = text {
+            set_text(text, resolved);
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::overlay::{OverlayPlugin, UiLayer};
+
+const TOAST_FONT_SIZE: f32 = 10.0;
+const TOAST_WIDTH: f32 = 260.0;
+const TOAST_GAP: f32 = 8.0;
+const TOAST_MARGIN: f32 = 16.0;
+const DEFAULT_DURATION: Duration = Duration::from_secs(4);
+const DEFAULT_MAX_VISIBLE: usize = 5;
+
+const TOAST_TEXT_COLOR: Color = Color::srgb(0.29, 0.31, 0.33);
+const INFO_BORDER_COLOR: Color = Color::srgb(0.19, 0.49, 0.71);
+const INFO_BACKGROUND_COLOR: Color = Color::srgb(0.93, 0.97, 1.0);
+const WARNING_BORDER_COLOR: Color = Color::srgb(1., 0.78, 0.);
+const WARNING_BACKGROUND_COLOR: Color = Color::srgb(1., 0.98, 0.9);
+const ERROR_BORDER_COLOR: Color = Color::srgb(1.0, 0.0, 0.);
+const ERROR_BACKGROUND_COLOR: Color = Color::srgb(1., 0.9, 0.9);
+
+/// Plugin showing stacked, auto-dismissing toast notifications
+pub struct NotificationsPlugin;
+
+impl Plugin for NotificationsPlugin {
+    fn build(&self, app: &mut App) {
+        OverlayPlugin::configure(app);
+        app.init_resource::<NotificationSettings>()
+            .register_type::<NotificationLevel>()
+            .add_observer(on_notify)
+            .add_observer(on_toast_click)
+            .add_systems(Update, tick_toasts);
+    }
+}
+
+/// Severity of a notification, selects its styling
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+pub enum NotificationLevel {
+    /// Informative message
+    #[default]
+    Info,
+    /// Something might be wrong
+    Warning,
+    /// Something went wrong
+    Error,
+}
+
+impl NotificationLevel {
+    const fn border_color(self) -> Color {
+        match self {
+            Self::Info => INFO_BORDER_COLOR,
+            Self::Warning => WARNING_BORDER_COLOR,
+            Self::Error => ERROR_BORDER_COLOR,
+        }
+    }
+
+    const fn background_color(self) -> Color {
+        match self {
+            Self::Info => INFO_BACKGROUND_COLOR,
+            Self::Warning => WARNING_BACKGROUND_COLOR,
+            Self::Error => ERROR_BACKGROUND_COLOR,
+        }
+    }
+}
+
+/// Screen corner where notifications are stacked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NotificationCorner {
+    /// Top left corner
+    TopLeft,
+    /// Top right corner
+    TopRight,
+    /// Bottom left corner
+    BottomLeft,
+    /// Bottom right corner
+    #[default]
+    BottomRight,
+}
+
+/// Configuration of the notifications
+#[derive(Resource, Debug, Clone)]
+pub struct NotificationSettings {
+    /// Time a toast stays visible, not counting the time it is hovered
+    pub duration: Duration,
+    /// Corner where toasts are stacked
+    pub corner: NotificationCorner,
+    /// Maximum number of toasts shown at once, oldest are dismissed first
+    pub max_visible: usize,
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            duration: DEFAULT_DURATION,
+            corner: NotificationCorner::default(),
+            max_visible: DEFAULT_MAX_VISIBLE,
+        }
+    }
+}
+
+/// An event that shows a toast notification.
+/// Prefer [`NotifyExt::notify`].
+#[derive(Event, Debug, Clone)]
+pub struct Notify {
+    /// Severity of the notification
+    pub level: NotificationLevel,
+    /// Message shown
+    pub message: String,
+}
+
+/// Commands extension to show notifications
+pub trait NotifyExt {
+    /// Shows a toast with `message`, styled by `level`
+    fn notify(&mut self, level: NotificationLevel, message: impl Into<String>);
+}
+
+impl NotifyExt for Commands<'_, '_> {
+    fn notify(&mut self, level: NotificationLevel, message: impl Into<String>) {
+        self.trigger(Notify {
+            level,
+            message: message.into(),
+        });
+    }
+}
+
+/// Container stacking the toasts
+#[derive(Component, Debug)]
+pub struct NotificationStack;
+
+/// A toast notification, dismissed when its timer finishes or when clicked.
+/// The timer is paused while hovered.
+#[derive(Component, Debug)]
+#[require(Interaction)]
+pub struct Toast {
+    /// Severity of the notification
+    pub level: NotificationLevel,
+    timer: Timer,
+}
+
+fn on_notify(
+    trigger: Trigger<Notify>,
+    mut commands: Commands,
+    settings: Res<NotificationSettings>,
+    stacks: Query<Entity, With<NotificationStack>>,
+    children: Query<&Children>,
+) {
+    let stack = stacks.iter().next().unwrap_or_else(|| {
+        let (vertical, horizontal) = match settings.corner {
+            NotificationCorner::TopLeft => (FlexDirection::Column, AlignItems::FlexStart),
+            NotificationCorner::TopRight => (FlexDirection::Column, AlignItems::FlexEnd),
+            NotificationCorner::BottomLeft => (FlexDirection::ColumnReverse, AlignItems::FlexStart),
+            NotificationCorner::BottomRight => (FlexDirection::ColumnReverse, AlignItems::FlexEnd),
+        };
+        let top = matches!(
+            settings.corner,
+            NotificationCorner::TopLeft | NotificationCorner::TopRight
+        );
+        let left = matches!(
+            settings.corner,
+            NotificationCorner::TopLeft | NotificationCorner::BottomLeft
+        );
+        let margin = Val::Px(TOAST_MARGIN);
+        commands
+            .spawn((
+                NotificationStack,
+                Name::new("NotificationStack"),
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: if top { margin } else { Val::Auto },
+                    bottom: if top { Val::Auto } else { margin },
+                    left: if left { margin } else { Val::Auto },
+                    right: if left { Val::Auto } else { margin },
+                    flex_direction: vertical,
+                    align_items: horizontal,
+                    row_gap: Val::Px(TOAST_GAP),
+                    ..default()
+                },
+                UiLayer::Toast,
+                PickingBehavior::IGNORE,
+            ))
+            .id()
+    });
+
+    // Stack children are in spawn order, oldest first.
+    if let Ok(toasts) = children.get(stack) {
+        let overflow = (toasts.len() + 1).saturating_sub(settings.max_visible);
+        for toast in toasts.iter().take(overflow) {
+            commands.entity(*toast).despawn_recursive();
+        }
+    }
+
+    let event = trigger.event();
+    let toast = commands
+        .spawn((
+            Toast {
+                level: event.level,
+                timer: Timer::new(settings.duration, TimerMode::Once),
+            },
+            Name::new("Toast"),
+            Node {
+                width: Val::Px(TOAST_WIDTH),
+                padding: UiRect::axes(Val::Px(12.), Val::Px(8.)),
+                border: UiRect::left(Val::Px(4.)),
+                ..default()
+            },
+            BackgroundColor(event.level.background_color()),
+            BorderColor(event.level.border_color()),
+            BorderRadius::all(Val::Px(4.)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(event.message.clone()),
+                TextColor(TOAST_TEXT_COLOR),
+                TextFont {
+                    font_size: TOAST_FONT_SIZE,
+                    ..default()
+                },
+                PickingBehavior::IGNORE,
+            ));
+        })
+        .id();
+    commands.entity(stack).add_child(toast);
+}
+
+fn tick_toasts(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut toasts: Query<(Entity, &mut Toast, &Interaction)>,
+) {
+    for (entity, mut toast, interaction) in &mut toasts {
+        if *interaction != Interaction::None {
+            continue;
+        }
+        if toast.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+fn on_toast_click(
+    mut trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    toasts: Query<(), With<Toast>>,
+) {
+    let entity = trigger.entity();
+    if toasts.contains(entity) {
+        trigger.propagate(false);
+        commands.entity(entity).despawn_recursive();
+    }
+}
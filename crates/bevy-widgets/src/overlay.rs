@@ -0,0 +1,324 @@
+use bevy::ecs::entity::Entities;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy::window::PrimaryWindow;
+
+use crate::WidgetsSystems;
+
+/// Global z-indices available to each [`UiLayer`]
+const LAYER_Z_INDEX_SPAN: i32 = 1000;
+
+/// Plugin containing the overlay layers and popup positioning logic
+pub struct OverlayPlugin;
+
+impl Plugin for OverlayPlugin {
+    fn build(&self, app: &mut App) {
+        Self::configure(app);
+    }
+}
+
+impl OverlayPlugin {
+    /// Adds the overlay logic once, safe to call from every plugin spawning overlays
+    pub(crate) fn configure(app: &mut App) {
+        if app.world().contains_resource::<UiLayerStack>() {
+            return;
+        }
+        WidgetsSystems::configure(app);
+        app.init_resource::<UiLayerStack>()
+            .register_type::<UiLayer>()
+            .register_type::<PopupAnchor>()
+            .add_observer(assign_layer_z_index)
+            .add_observer(reparent_to_overlay_root)
+            .add_systems(Update, position_overlays.in_set(WidgetsSystems::Layout));
+    }
+}
+
+/// Stacking layer of widgets rendered above the regular UI, like popups, modals and toasts.
+///
+/// Adding the component gives the entity a [`GlobalZIndex`] above every lower layer and above
+/// every entity added to the same layer before it, so the last opened popup shows on top.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub enum UiLayer {
+    /// Dropdowns, suggestion lists, calendars, tooltips and context menus
+    Popup,
+    /// Modal dialogs and previews covering the window
+    Modal,
+    /// Notifications shown above modals
+    Toast,
+    /// Content following the pointer while dragging
+    DragGhost,
+}
+
+impl UiLayer {
+    /// Lowest global z-index of the layer, later entities of the layer get higher ones
+    pub const fn base_z_index(self) -> i32 {
+        let below_top = match self {
+            Self::Popup => 4,
+            Self::Modal => 3,
+            Self::Toast => 2,
+            Self::DragGhost => 1,
+        };
+        i32::MAX - below_top * LAYER_Z_INDEX_SPAN
+    }
+}
+
+/// Next global z-index offset of each layer, wrapping after [`LAYER_Z_INDEX_SPAN`] entities
+#[derive(Resource, Debug, Default)]
+struct UiLayerStack(HashMap<UiLayer, i32>);
+
+impl UiLayerStack {
+    fn allocate(&mut self, layer: UiLayer) -> i32 {
+        let next = self.0.entry(layer).or_default();
+        let z_index = layer.base_z_index() + *next;
+        *next = (*next + 1) % LAYER_Z_INDEX_SPAN;
+        z_index
+    }
+}
+
+/// Full window node holding the entities with a [`PopupAnchor`], spawned with the first of them
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct OverlayRoot;
+
+/// Side of the anchor a popup is placed on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+pub enum PopupSide {
+    /// Under the anchor
+    #[default]
+    Below,
+    /// Over the anchor
+    Above,
+    /// Right of the anchor
+    Right,
+    /// Left of the anchor
+    Left,
+}
+
+/// Edge of the anchor a popup is aligned with, along its side
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+pub enum PopupAlign {
+    /// Left edge for popups below or above, top edge for popups on the right or left
+    #[default]
+    Start,
+    /// Right edge for popups below or above, bottom edge for popups on the right or left
+    End,
+}
+
+/// Preferred position of a popup relative to its anchor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+pub struct PopupPlacement {
+    /// Side of the anchor
+    pub side: PopupSide,
+    /// Edge of the anchor the popup is aligned with
+    pub align: PopupAlign,
+}
+
+impl PopupPlacement {
+    /// Under the anchor, aligned to its left edge
+    pub const BELOW_START: Self = Self::new(PopupSide::Below, PopupAlign::Start);
+    /// Under the anchor, aligned to its right edge
+    pub const BELOW_END: Self = Self::new(PopupSide::Below, PopupAlign::End);
+    /// Over the anchor, aligned to its left edge
+    pub const ABOVE_START: Self = Self::new(PopupSide::Above, PopupAlign::Start);
+    /// Over the anchor, aligned to its right edge
+    pub const ABOVE_END: Self = Self::new(PopupSide::Above, PopupAlign::End);
+    /// Right of the anchor, aligned to its top edge
+    pub const RIGHT_START: Self = Self::new(PopupSide::Right, PopupAlign::Start);
+    /// Right of the anchor, aligned to its bottom edge
+    pub const RIGHT_END: Self = Self::new(PopupSide::Right, PopupAlign::End);
+    /// Left of the anchor, aligned to its top edge
+    pub const LEFT_START: Self = Self::new(PopupSide::Left, PopupAlign::Start);
+    /// Left of the anchor, aligned to its bottom edge
+    pub const LEFT_END: Self = Self::new(PopupSide::Left, PopupAlign::End);
+
+    /// Creates a placement on `side` of the anchor, aligned with `align`
+    pub const fn new(side: PopupSide, align: PopupAlign) -> Self {
+        Self { side, align }
+    }
+}
+
+/// Floating node positioned next to the `anchor` entity, for dropdowns, tooltips and date pickers.
+///
+/// The node is moved to the [`OverlayRoot`], so it is not clipped by the panels containing the anchor,
+/// and follows the anchor when it moves. It flips to the opposite side or alignment when it would
+/// overflow the window, is shifted to stay inside it, and is despawned with the anchor.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+#[require(Node)]
+pub struct PopupAnchor {
+    /// Entity the popup is placed next to
+    pub anchor: Entity,
+    /// Preferred position relative to the anchor
+    pub placement: PopupPlacement,
+    /// Makes the popup at least as wide as the anchor
+    pub match_width: bool,
+}
+
+impl PopupAnchor {
+    /// Places the popup at `placement` relative to `anchor`
+    pub const fn new(anchor: Entity, placement: PopupPlacement) -> Self {
+        Self {
+            anchor,
+            placement,
+            match_width: false,
+        }
+    }
+
+    /// Places the popup under `anchor`, aligned to its left edge
+    pub const fn below(anchor: Entity) -> Self {
+        Self::new(anchor, PopupPlacement::BELOW_START)
+    }
+
+    /// Makes the popup at least as wide as the anchor
+    pub const fn with_anchor_width(mut self) -> Self {
+        self.match_width = true;
+        self
+    }
+}
+
+/// Top left position of a popup of `size` next to `anchor`, in the same coordinates as the rects.
+///
+/// The popup goes to the `placement` side and alignment, or the opposite ones when only those fit,
+/// and is then shifted to stay inside `viewport`.
+///
+/// ```
+/// # use bevy::math::{Rect, Vec2};
+/// # use bevy_widgets::overlay::{place_popup, PopupPlacement};
+/// let viewport = Rect::new(0., 0., 800., 600.);
+/// let anchor = Rect::new(700., 560., 780., 580.);
+/// let position = place_popup(anchor, Vec2::new(200., 100.), viewport, PopupPlacement::BELOW_START);
+/// assert_eq!(position, Vec2::new(580., 460.));
+/// ```
+pub fn place_popup(anchor: Rect, size: Vec2, viewport: Rect, placement: PopupPlacement) -> Vec2 {
+    let vertical = matches!(placement.side, PopupSide::Below | PopupSide::Above);
+    // Main axis goes away from the anchor, cross axis runs along its side
+    let (main, cross) = if vertical { (1, 0) } else { (0, 1) };
+
+    let after = anchor.max[main];
+    let before = anchor.min[main] - size[main];
+    let fits_after = after + size[main] <= viewport.max[main];
+    let fits_before = before >= viewport.min[main];
+    let main_position = match placement.side {
+        PopupSide::Below | PopupSide::Right => flip(after, before, fits_after, fits_before),
+        PopupSide::Above | PopupSide::Left => flip(before, after, fits_before, fits_after),
+    };
+
+    let start = anchor.min[cross];
+    let end = anchor.max[cross] - size[cross];
+    let fits_start = start + size[cross] <= viewport.max[cross];
+    let fits_end = end >= viewport.min[cross];
+    let cross_position = match placement.align {
+        PopupAlign::Start => flip(start, end, fits_start, fits_end),
+        PopupAlign::End => flip(end, start, fits_end, fits_start),
+    };
+
+    let position = if vertical {
+        Vec2::new(cross_position, main_position)
+    } else {
+        Vec2::new(main_position, cross_position)
+    };
+    let max = (viewport.max - size).max(viewport.min);
+    position.clamp(viewport.min, max)
+}
+
+/// Keeps the `preferred` position unless only the `opposite` one fits
+const fn flip(preferred: f32, opposite: f32, fits_preferred: bool, fits_opposite: bool) -> f32 {
+    if !fits_preferred && fits_opposite {
+        opposite
+    } else {
+        preferred
+    }
+}
+
+fn assign_layer_z_index(
+    trigger: Trigger<OnAdd, UiLayer>,
+    mut commands: Commands,
+    mut stack: ResMut<UiLayerStack>,
+    layers: Query<&UiLayer>,
+) {
+    let entity = trigger.entity();
+    let Ok(layer) = layers.get(entity) else {
+        return;
+    };
+    commands
+        .entity(entity)
+        .insert(GlobalZIndex(stack.allocate(*layer)));
+}
+
+fn reparent_to_overlay_root(
+    trigger: Trigger<OnAdd, PopupAnchor>,
+    mut commands: Commands,
+    mut nodes: Query<&mut Node>,
+    roots: Query<Entity, With<OverlayRoot>>,
+    entities: &Entities,
+    mut spawned_root: Local<Option<Entity>>,
+) {
+    let entity = trigger.entity();
+    // The root spawned by a previous trigger may not be queryable until the commands are applied
+    let existing = roots
+        .iter()
+        .next()
+        .or_else(|| spawned_root.filter(|root| entities.contains(*root)));
+    let root = existing.unwrap_or_else(|| {
+        let root = commands
+            .spawn((
+                OverlayRoot,
+                Name::new("OverlayRoot"),
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    ..default()
+                },
+                PickingBehavior::IGNORE,
+            ))
+            .id();
+        *spawned_root = Some(root);
+        root
+    });
+    if let Ok(mut node) = nodes.get_mut(entity) {
+        node.position_type = PositionType::Absolute;
+    }
+    commands.entity(root).add_child(entity);
+}
+
+fn position_overlays(
+    mut commands: Commands,
+    mut overlays: Query<(Entity, &PopupAnchor, &mut Node, &ComputedNode)>,
+    anchors: Query<(&ComputedNode, &GlobalTransform)>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    // Node positions are in UI pixels, scaled by `UiScale` from the window logical pixels
+    let viewport = Rect::from_corners(Vec2::ZERO, window.size() / ui_scale.0);
+    for (entity, overlay, mut node, computed) in &mut overlays {
+        let Ok((anchor_node, anchor_transform)) = anchors.get(overlay.anchor) else {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        };
+        let scale = anchor_node.inverse_scale_factor();
+        let anchor = Rect::from_center_size(
+            anchor_transform.translation().truncate() * scale,
+            anchor_node.size() * scale,
+        );
+        let size = computed.size() * computed.inverse_scale_factor();
+        let position = place_popup(anchor, size, viewport, overlay.placement);
+
+        let left = Val::Px(position.x);
+        let top = Val::Px(position.y);
+        let min_width = if overlay.match_width {
+            Val::Px(anchor.width())
+        } else {
+            node.min_width
+        };
+        if node.left != left || node.top != top || node.min_width != min_width {
+            node.left = left;
+            node.top = top;
+            node.min_width = min_width;
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+
+use crate::docking::DockLayout;
+use crate::split_pane::SplitRatios;
+
+const DEFAULT_PATH: &str = "ui_layout.ron";
+
+/// Plugin saving the UI state to a RON file on exit and restoring it on startup.
+///
+/// Persists [`DockLayout`], [`SplitRatios`] and [`PersistedUiState`].
+/// Send [`SaveUiStateEvent`] to save at any other moment.
+#[derive(Debug, Clone)]
+pub struct PersistencePlugin {
+    path: PathBuf,
+}
+
+impl Default for PersistencePlugin {
+    fn default() -> Self {
+        Self::new(DEFAULT_PATH)
+    }
+}
+
+impl PersistencePlugin {
+    /// Creates the plugin persisting to the RON file at `path`
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl Plugin for PersistencePlugin {
+    fn build(&self, app: &mut App) {
+        let file = UiStateFile::load(&self.path).unwrap_or_default();
+        app.insert_resource(file.dock_layout)
+            .insert_resource(file.split_ratios)
+            .insert_resource(file.state)
+            .insert_resource(UiStatePath(self.path.clone()))
+            .add_event::<SaveUiStateEvent>()
+            .register_type::<PersistedUiState>()
+            .add_systems(Last, save_ui_state);
+    }
+}
+
+/// Application defined UI state persisted by [`PersistencePlugin`].
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct PersistedUiState {
+    /// Keys of the collapsed sections
+    pub collapsed: HashSet<String>,
+    /// Name of the last selected entity
+    pub selected: Option<String>,
+    /// Filter texts, by filter key
+    pub filters: HashMap<String, String>,
+}
+
+/// An event that saves the UI state immediately
+#[derive(Event, Debug, Default)]
+pub struct SaveUiStateEvent;
+
+/// Path of the file used by [`PersistencePlugin`]
+#[derive(Resource, Debug, Clone)]
+pub struct UiStatePath(pub PathBuf);
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct UiStateFile {
+    dock_layout: DockLayout,
+    split_ratios: SplitRatios,
+    state: PersistedUiState,
+}
+
+impl UiStateFile {
+    fn load(path: &Path) -> Option<Self> {
+        let content = fs::read_to_string(path).ok()?;
+        ron::from_str(&content)
+            .inspect_err(|err| warn!("Failed to parse UI state {}: {err}", path.display()))
+            .ok()
+    }
+
+    fn save(&self, path: &Path) {
+        let content = match ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()) {
+            Ok(content) => content,
+            Err(err) => {
+                warn!("Failed to serialize UI state: {err}");
+                return;
+            }
+        };
+        if let Err(err) = fs::write(path, content) {
+            warn!("Failed to write UI state {}: {err}", path.display());
+        }
+    }
+}
+
+fn save_ui_state(
+    mut exit: EventReader<AppExit>,
+    mut save: EventReader<SaveUiStateEvent>,
+    path: Res<UiStatePath>,
+    dock_layout: Res<DockLayout>,
+    split_ratios: Res<SplitRatios>,
+    state: Res<PersistedUiState>,
+) {
+    if exit.read().count() + save.read().count() == 0 {
+        return;
+    }
+    UiStateFile {
+        dock_layout: dock_layout.clone(),
+        split_ratios: split_ratios.clone(),
+        state: state.clone(),
+    }
+    .save(&path.0);
+}
@@ -0,0 +1,297 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::theme::WidgetTheme;
+
+const TRACK_COLOR: Color = Color::srgb(0.8, 0.83, 0.85);
+const FILL_COLOR: Color = Color::srgb(0.19, 0.49, 0.71);
+const LABEL_COLOR: Color = Color::srgb(0.29, 0.31, 0.33);
+const SPINNER_COLOR: Color = Color::srgb(0.19, 0.49, 0.71);
+const LABEL_FONT_SIZE: f32 = 8.0;
+const DEFAULT_BAR_WIDTH: f32 = 160.0;
+const DEFAULT_BAR_HEIGHT: f32 = 6.0;
+const SPINNER_DOTS: usize = 3;
+const SPINNER_DOT_SIZE: f32 = 6.0;
+const DEFAULT_SPINNER_SPEED: f32 = 1.0;
+
+/// Plugin containing the progress bar and spinner logic
+pub struct ProgressPlugin;
+
+impl Plugin for ProgressPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ProgressBar>()
+            .register_type::<Spinner>()
+            .add_observer(create_progress_bar)
+            .add_observer(create_spinner)
+            .add_systems(Update, (update_progress_bars, animate_spinners));
+    }
+}
+
+/// Determinate progress bar filled according to [`ProgressBar::value`].
+///
+/// Update the value from any system to report progress.
+/// Should be created with [`ProgressBarBuilder`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Default, Reflect)]
+#[reflect(Component)]
+#[require(Node)]
+pub struct ProgressBar {
+    /// Progress in `0..=1`, values outside are clamped when displayed
+    pub value: f32,
+    /// Shows the percentage next to the bar, the label is added or removed when toggled
+    pub show_label: bool,
+}
+
+impl ProgressBar {
+    /// Creates a progress bar at `value`
+    pub const fn new(value: f32) -> Self {
+        Self {
+            value,
+            show_label: false,
+        }
+    }
+}
+
+#[derive(Component, Debug)]
+struct ProgressFill;
+
+#[derive(Component, Debug)]
+struct ProgressLabel;
+
+/// Builder for [`ProgressBar`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProgressBarBuilder {
+    value: f32,
+    show_label: bool,
+    width: Val,
+    height: f32,
+}
+
+impl Default for ProgressBarBuilder {
+    fn default() -> Self {
+        Self {
+            value: 0.,
+            show_label: false,
+            width: Val::Px(DEFAULT_BAR_WIDTH),
+            height: DEFAULT_BAR_HEIGHT,
+        }
+    }
+}
+
+impl ProgressBarBuilder {
+    /// Sets the initial progress, in `0..=1`
+    pub const fn with_value(mut self, value: f32) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Shows the percentage next to the bar
+    pub const fn with_label(mut self) -> Self {
+        self.show_label = true;
+        self
+    }
+
+    /// Sets the width of the whole widget. Defaults to `160px`.
+    pub const fn with_width(mut self, width: Val) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the bar in logical pixels. Defaults to `6.`.
+    pub const fn with_height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Builds the progress bar
+    pub fn build(self) -> (ProgressBar, Node) {
+        (
+            ProgressBar {
+                value: self.value,
+                show_label: self.show_label,
+            },
+            Node {
+                width: self.width,
+                min_height: Val::Px(self.height),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(6.),
+                ..default()
+            },
+        )
+    }
+}
+
+fn create_progress_bar(
+    trigger: Trigger<OnAdd, ProgressBar>,
+    mut commands: Commands,
+    bars: Query<(&ProgressBar, &Node)>,
+) {
+    let entity = trigger.entity();
+    let Ok((bar, node)) = bars.get(entity) else {
+        return;
+    };
+    let height = match node.min_height {
+        Val::Px(height) => height,
+        _ => DEFAULT_BAR_HEIGHT,
+    };
+    let value = bar.value.clamp(0., 1.);
+
+    let track = commands
+        .spawn((
+            Name::new("ProgressTrack"),
+            Node {
+                flex_grow: 1.,
+                height: Val::Px(height),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            BackgroundColor(TRACK_COLOR),
+            BorderRadius::all(Val::Px(height / 2.)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                ProgressFill,
+                Node {
+                    width: Val::Percent(value * 100.),
+                    height: Val::Percent(100.),
+                    ..default()
+                },
+                BackgroundColor(FILL_COLOR),
+                BorderRadius::all(Val::Px(height / 2.)),
+            ));
+        })
+        .id();
+    commands.entity(entity).add_child(track);
+
+    if bar.show_label {
+        spawn_label(&mut commands, entity, value);
+    }
+}
+
+fn spawn_label(commands: &mut Commands, bar: Entity, value: f32) {
+    let label = commands
+        .spawn((
+            ProgressLabel,
+            Text::new(percentage(value)),
+            TextColor(LABEL_COLOR),
+            TextFont {
+                font_size: LABEL_FONT_SIZE,
+                ..default()
+            },
+        ))
+        .id();
+    commands.entity(bar).add_child(label);
+}
+
+fn percentage(value: f32) -> String {
+    format!("{:.0}%", value * 100.)
+}
+
+// Also adds or removes the label when `show_label` is toggled
+fn update_progress_bars(
+    mut commands: Commands,
+    bars: Query<(Entity, &ProgressBar, &Children), Changed<ProgressBar>>,
+    children_query: Query<&Children>,
+    mut fills: Query<&mut Node, With<ProgressFill>>,
+    mut labels: Query<&mut Text, With<ProgressLabel>>,
+) {
+    for (entity, bar, children) in &bars {
+        let value = bar.value.clamp(0., 1.);
+        let mut has_label = false;
+        for child in children {
+            if let Ok(mut text) = labels.get_mut(*child) {
+                has_label = true;
+                if bar.show_label {
+                    text.0 = percentage(value);
+                } else {
+                    commands.entity(*child).despawn_recursive();
+                }
+            }
+            let Ok(track_children) = children_query.get(*child) else {
+                continue;
+            };
+            for fill in track_children {
+                if let Ok(mut node) = fills.get_mut(*fill) {
+                    node.width = Val::Percent(value * 100.);
+                }
+            }
+        }
+        if bar.show_label && !has_label {
+            spawn_label(&mut commands, entity, value);
+        }
+    }
+}
+
+/// Indeterminate activity indicator, a row of pulsing dots.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+#[require(Node)]
+pub struct Spinner {
+    /// Animation cycles per second
+    pub speed: f32,
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self {
+            speed: DEFAULT_SPINNER_SPEED,
+        }
+    }
+}
+
+#[derive(Component, Debug)]
+struct SpinnerDot {
+    index: usize,
+}
+
+fn create_spinner(
+    trigger: Trigger<OnAdd, Spinner>,
+    mut commands: Commands,
+    mut nodes: Query<&mut Node>,
+) {
+    let entity = trigger.entity();
+    if let Ok(mut node) = nodes.get_mut(entity) {
+        node.flex_direction = FlexDirection::Row;
+        node.align_items = AlignItems::Center;
+        node.column_gap = Val::Px(SPINNER_DOT_SIZE / 2.);
+    }
+    commands.entity(entity).with_children(|parent| {
+        for index in 0..SPINNER_DOTS {
+            parent.spawn((
+                SpinnerDot { index },
+                Node {
+                    width: Val::Px(SPINNER_DOT_SIZE),
+                    height: Val::Px(SPINNER_DOT_SIZE),
+                    ..default()
+                },
+                BackgroundColor(SPINNER_COLOR),
+                BorderRadius::MAX,
+            ));
+        }
+    });
+}
+
+fn animate_spinners(
+    time: Res<Time<Real>>,
+    theme: Option<Res<WidgetTheme>>,
+    spinners: Query<(&Spinner, &Children)>,
+    mut dots: Query<(&SpinnerDot, &mut BackgroundColor)>,
+) {
+    if theme.is_some_and(|theme| theme.reduced_motion) {
+        for (_, mut color) in &mut dots {
+            color.set_if_neq(BackgroundColor(SPINNER_COLOR));
+        }
+        return;
+    }
+    for (spinner, children) in &spinners {
+        let phase = time.elapsed_secs() * spinner.speed * TAU;
+        let mut iter = dots.iter_many_mut(children);
+        while let Some((dot, mut color)) = iter.fetch_next() {
+            let offset = dot.index as f32 / SPINNER_DOTS as f32 * TAU;
+            let pulse = 0.5f32.mul_add((phase - offset).cos(), 0.5);
+            let alpha = 0.75f32.mul_add(pulse, 0.25);
+            color.0 = SPINNER_COLOR.with_alpha(alpha);
+        }
+    }
+}
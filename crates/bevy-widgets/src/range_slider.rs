@@ -0,0 +1,547 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use accesskit::{Action, Role};
+use bevy::a11y::{AccessibilityNode, ActionRequest};
+use bevy::prelude::*;
+
+use crate::{
+    accessibility::{action_numeric_value, action_target},
+    focus::{Clickable, Focus},
+};
+
+const TRACK_COLOR: Color = Color::srgb(0.29, 0.31, 0.33);
+const FILL_COLOR: Color = Color::srgb(0.19, 0.49, 0.71);
+const HANDLE_COLOR: Color = Color::srgb(0.93, 0.97, 1.0);
+const HANDLE_BORDER_COLOR: Color = Color::srgb(0.19, 0.49, 0.71);
+const TRACK_HEIGHT: f32 = 4.0;
+const HANDLE_SIZE: f32 = 14.0;
+const DEFAULT_WIDTH: f32 = 160.0;
+/// Fraction of the bounds moved by arrow keys when the slider has no step
+const DEFAULT_KEYBOARD_STEP: f64 = 0.01;
+const PAGE_STEPS: f64 = 10.0;
+
+/// Plugin containing the range slider logic for values of type `T`.
+///
+/// [`crate::WidgetsPlugin`] adds it for `f32` and `i32`.
+pub struct RangeSliderPlugin<T: SliderValue>(PhantomData<T>);
+
+impl<T: SliderValue> Default for RangeSliderPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: SliderValue> Plugin for RangeSliderPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<RangeChangedEvent<T>>()
+            .add_event::<ActionRequest>()
+            .add_observer(create_range_slider::<T>)
+            .add_observer(on_handle_drag_start::<T>)
+            .add_observer(on_handle_drag::<T>)
+            .add_systems(
+                Update,
+                (
+                    adjust_focused_handle::<T>,
+                    handle_accessibility_actions::<T>,
+                    update_range_sliders::<T>,
+                    update_handle_accessibility::<T>,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Numeric value edited by a [`RangeSlider`]
+pub trait SliderValue: Copy + PartialOrd + Debug + Send + Sync + 'static {
+    /// Values are whole numbers
+    const INTEGRAL: bool;
+
+    /// Converts the value to `f64`
+    fn to_f64(self) -> f64;
+
+    /// Converts back from `f64`, rounding integral values
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_slider_value {
+    ($integral:literal, $($ty:ty),*) => {
+        $(impl SliderValue for $ty {
+            const INTEGRAL: bool = $integral;
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn from_f64(value: f64) -> Self {
+                if Self::INTEGRAL {
+                    value.round() as Self
+                } else {
+                    value as Self
+                }
+            }
+        })*
+    };
+}
+
+impl_slider_value!(false, f32, f64);
+impl_slider_value!(true, i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+/// What happens when a [`RangeSlider`] handle reaches the other one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum HandleCollision {
+    /// The handle stops at the minimum gap from the other one
+    #[default]
+    Block,
+    /// The handle pushes the other one until it reaches the bounds
+    Push,
+}
+
+/// Which handle of a [`RangeSlider`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum RangeHandle {
+    /// Handle of the lower value
+    Min,
+    /// Handle of the upper value
+    Max,
+}
+
+/// Slider with two handles selecting a `min..=max` sub-range of its bounds.
+///
+/// Handles are dragged with the mouse, or moved with the arrow, page and home/end keys once focused.
+/// Should be created with [`RangeSliderBuilder`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[require(Node)]
+pub struct RangeSlider<T: SliderValue> {
+    bounds: (T, T),
+    min: T,
+    max: T,
+    step: Option<T>,
+    min_gap: Option<T>,
+    collision: HandleCollision,
+}
+
+impl<T: SliderValue> RangeSlider<T> {
+    /// Bounds of the slider
+    pub const fn bounds(&self) -> (T, T) {
+        self.bounds
+    }
+
+    /// Selected `(min, max)` range
+    pub const fn range(&self) -> (T, T) {
+        (self.min, self.max)
+    }
+
+    /// Selects the `(min, max)` range, applying the step and gap rules
+    pub fn set_range(&mut self, min: T, max: T) {
+        self.move_handle(RangeHandle::Min, min.to_f64());
+        self.move_handle(RangeHandle::Max, max.to_f64());
+    }
+
+    /// Value of `handle`
+    pub const fn value(&self, handle: RangeHandle) -> T {
+        match handle {
+            RangeHandle::Min => self.min,
+            RangeHandle::Max => self.max,
+        }
+    }
+
+    fn span(&self) -> f64 {
+        (self.bounds.1.to_f64() - self.bounds.0.to_f64()).max(0.)
+    }
+
+    fn gap(&self) -> f64 {
+        self.min_gap
+            .map_or(0., SliderValue::to_f64)
+            .clamp(0., self.span())
+    }
+
+    fn keyboard_step(&self) -> f64 {
+        let step = self
+            .step
+            .map_or_else(|| self.span() * DEFAULT_KEYBOARD_STEP, SliderValue::to_f64);
+        if T::INTEGRAL {
+            step.max(1.)
+        } else {
+            step
+        }
+    }
+
+    fn snap(&self, value: f64) -> f64 {
+        let (low, high) = (self.bounds.0.to_f64(), self.bounds.1.to_f64());
+        let value = match self.step.map(SliderValue::to_f64) {
+            Some(step) if step > 0. => ((value - low) / step).round().mul_add(step, low),
+            _ => value,
+        };
+        value.clamp(low, high)
+    }
+
+    /// Position of `value` in the bounds, from `0` to `1`
+    fn fraction(&self, value: T) -> f64 {
+        let span = self.span();
+        if span <= 0. {
+            0.
+        } else {
+            ((value.to_f64() - self.bounds.0.to_f64()) / span).clamp(0., 1.)
+        }
+    }
+
+    /// Moves `handle` to `value`, returning if the range changed
+    fn move_handle(&mut self, handle: RangeHandle, value: f64) -> bool {
+        let (low, high) = (self.bounds.0.to_f64(), self.bounds.1.to_f64());
+        let gap = self.gap();
+        let value = self.snap(value);
+        let (mut min, mut max) = (self.min.to_f64(), self.max.to_f64());
+        match (handle, self.collision) {
+            (RangeHandle::Min, HandleCollision::Block) => min = value.min(max - gap),
+            (RangeHandle::Min, HandleCollision::Push) => {
+                min = value.min(high - gap);
+                max = max.max(min + gap);
+            }
+            (RangeHandle::Max, HandleCollision::Block) => max = value.max(min + gap),
+            (RangeHandle::Max, HandleCollision::Push) => {
+                max = value.max(low + gap);
+                min = min.min(max - gap);
+            }
+        }
+        let (min, max) = (T::from_f64(min), T::from_f64(max));
+        if min == self.min && max == self.max {
+            return false;
+        }
+        self.min = min;
+        self.max = max;
+        true
+    }
+}
+
+/// Builder for [`RangeSlider`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RangeSliderBuilder<T: SliderValue> {
+    slider: RangeSlider<T>,
+    width: Val,
+}
+
+impl<T: SliderValue> RangeSliderBuilder<T> {
+    /// Creates a builder of a slider between `low` and `high`, selecting the whole bounds
+    pub fn new(low: T, high: T) -> Self {
+        let (low, high) = if high < low { (high, low) } else { (low, high) };
+        Self {
+            slider: RangeSlider {
+                bounds: (low, high),
+                min: low,
+                max: high,
+                step: None,
+                min_gap: None,
+                collision: HandleCollision::default(),
+            },
+            width: Val::Px(DEFAULT_WIDTH),
+        }
+    }
+
+    /// Sets the initially selected range
+    pub const fn with_range(mut self, min: T, max: T) -> Self {
+        self.slider.min = min;
+        self.slider.max = max;
+        self
+    }
+
+    /// Snaps values to multiples of `step` from the lower bound
+    pub const fn with_step(mut self, step: T) -> Self {
+        self.slider.step = Some(step);
+        self
+    }
+
+    /// Sets the smallest distance between the two handles
+    pub const fn with_min_gap(mut self, gap: T) -> Self {
+        self.slider.min_gap = Some(gap);
+        self
+    }
+
+    /// Sets what happens when handles collide. Defaults to [`HandleCollision::Block`].
+    pub const fn with_collision(mut self, collision: HandleCollision) -> Self {
+        self.slider.collision = collision;
+        self
+    }
+
+    /// Sets the slider width. Defaults to `160px`.
+    pub const fn with_width(mut self, width: Val) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Builds the slider
+    pub fn build(self) -> (RangeSlider<T>, Node) {
+        let mut slider = self.slider;
+        let (min, max) = (slider.min, slider.max);
+        slider.set_range(min, max);
+        (
+            slider,
+            Node {
+                width: self.width,
+                height: Val::Px(HANDLE_SIZE),
+                align_items: AlignItems::Center,
+                ..default()
+            },
+        )
+    }
+}
+
+/// Draggable handle of a [`RangeSlider`], focus it to move it with the keyboard
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+#[require(Node, Clickable)]
+pub struct RangeSliderHandle {
+    slider: Entity,
+    handle: RangeHandle,
+    drag_position: f64,
+}
+
+impl RangeSliderHandle {
+    /// Which handle of the slider it is
+    pub const fn handle(&self) -> RangeHandle {
+        self.handle
+    }
+}
+
+#[derive(Component, Debug)]
+struct RangeSliderFill;
+
+/// Event sent when the range of a [`RangeSlider`] is changed by the user
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeChangedEvent<T: SliderValue> {
+    /// Entity of the [`RangeSlider`]
+    pub entity: Entity,
+    /// Lower selected value
+    pub min: T,
+    /// Upper selected value
+    pub max: T,
+}
+
+fn create_range_slider<T: SliderValue>(
+    trigger: Trigger<OnAdd, RangeSlider<T>>,
+    mut commands: Commands,
+    sliders: Query<&RangeSlider<T>>,
+) {
+    let entity = trigger.entity();
+    let Ok(slider) = sliders.get(entity) else {
+        return;
+    };
+    let (min, max) = (
+        slider.fraction(slider.min) as f32,
+        slider.fraction(slider.max) as f32,
+    );
+    commands.entity(entity).with_children(|parent| {
+        parent.spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Px(TRACK_HEIGHT),
+                ..default()
+            },
+            BackgroundColor(TRACK_COLOR),
+            BorderRadius::MAX,
+            PickingBehavior::IGNORE,
+        ));
+        parent.spawn((
+            RangeSliderFill,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(min * 100.),
+                width: Val::Percent((max - min) * 100.),
+                height: Val::Px(TRACK_HEIGHT),
+                ..default()
+            },
+            BackgroundColor(FILL_COLOR),
+            PickingBehavior::IGNORE,
+        ));
+        for (handle, fraction) in [(RangeHandle::Min, min), (RangeHandle::Max, max)] {
+            parent.spawn((
+                RangeSliderHandle {
+                    slider: entity,
+                    handle,
+                    drag_position: 0.,
+                },
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Percent(fraction * 100.),
+                    width: Val::Px(HANDLE_SIZE),
+                    height: Val::Px(HANDLE_SIZE),
+                    margin: UiRect::left(Val::Px(-HANDLE_SIZE / 2.)),
+                    border: UiRect::all(Val::Px(2.)),
+                    ..default()
+                },
+                BackgroundColor(HANDLE_COLOR),
+                BorderColor(HANDLE_BORDER_COLOR),
+                BorderRadius::MAX,
+                AccessibilityNode::from(accesskit::Node::new(Role::Slider)),
+            ));
+        }
+    });
+}
+
+fn on_handle_drag_start<T: SliderValue>(
+    mut trigger: Trigger<Pointer<DragStart>>,
+    mut handles: Query<&mut RangeSliderHandle>,
+    sliders: Query<&RangeSlider<T>>,
+) {
+    let Ok(mut handle) = handles.get_mut(trigger.entity()) else {
+        return;
+    };
+    let Ok(slider) = sliders.get(handle.slider) else {
+        return;
+    };
+    trigger.propagate(false);
+    handle.drag_position = slider.value(handle.handle).to_f64();
+}
+
+fn on_handle_drag<T: SliderValue>(
+    mut trigger: Trigger<Pointer<Drag>>,
+    mut handles: Query<&mut RangeSliderHandle>,
+    mut sliders: Query<(&mut RangeSlider<T>, &ComputedNode)>,
+    mut event_writer: EventWriter<RangeChangedEvent<T>>,
+    ui_scale: Res<UiScale>,
+) {
+    let Ok(mut handle) = handles.get_mut(trigger.entity()) else {
+        return;
+    };
+    let Ok((mut slider, computed)) = sliders.get_mut(handle.slider) else {
+        return;
+    };
+    trigger.propagate(false);
+    let width = computed.size().x * computed.inverse_scale_factor();
+    if width <= 0. {
+        return;
+    }
+    // Unsnapped position, so small moves add up on stepped and integral sliders.
+    handle.drag_position += f64::from(trigger.delta.x / ui_scale.0 / width) * slider.span();
+    if slider.move_handle(handle.handle, handle.drag_position) {
+        event_writer.send(RangeChangedEvent {
+            entity: handle.slider,
+            min: slider.min,
+            max: slider.max,
+        });
+    }
+}
+
+fn adjust_focused_handle<T: SliderValue>(
+    keys: Res<ButtonInput<KeyCode>>,
+    handles: Query<&RangeSliderHandle, With<Focus>>,
+    mut sliders: Query<&mut RangeSlider<T>>,
+    mut event_writer: EventWriter<RangeChangedEvent<T>>,
+) {
+    for handle in &handles {
+        let Ok(mut slider) = sliders.get_mut(handle.slider) else {
+            continue;
+        };
+        let step = slider.keyboard_step();
+        let value = slider.value(handle.handle).to_f64();
+        let target = if keys.any_just_pressed([KeyCode::ArrowLeft, KeyCode::ArrowDown]) {
+            value - step
+        } else if keys.any_just_pressed([KeyCode::ArrowRight, KeyCode::ArrowUp]) {
+            value + step
+        } else if keys.just_pressed(KeyCode::PageDown) {
+            step.mul_add(-PAGE_STEPS, value)
+        } else if keys.just_pressed(KeyCode::PageUp) {
+            step.mul_add(PAGE_STEPS, value)
+        } else if keys.just_pressed(KeyCode::Home) {
+            slider.bounds.0.to_f64()
+        } else if keys.just_pressed(KeyCode::End) {
+            slider.bounds.1.to_f64()
+        } else {
+            continue;
+        };
+        if slider.move_handle(handle.handle, target) {
+            event_writer.send(RangeChangedEvent {
+                entity: handle.slider,
+                min: slider.min,
+                max: slider.max,
+            });
+        }
+    }
+}
+
+/// Moves handles on the set value, increment and decrement actions of assistive technologies
+fn handle_accessibility_actions<T: SliderValue>(
+    mut requests: EventReader<ActionRequest>,
+    handles: Query<&RangeSliderHandle>,
+    mut sliders: Query<&mut RangeSlider<T>>,
+    mut event_writer: EventWriter<RangeChangedEvent<T>>,
+) {
+    for request in requests.read() {
+        let Some(handle) = action_target(request).and_then(|target| handles.get(target).ok())
+        else {
+            continue;
+        };
+        let Ok(mut slider) = sliders.get_mut(handle.slider) else {
+            continue;
+        };
+        let value = slider.value(handle.handle).to_f64();
+        let target = match request.action {
+            Action::Increment => value + slider.keyboard_step(),
+            Action::Decrement => value - slider.keyboard_step(),
+            _ => match action_numeric_value(request) {
+                Some(target) => target,
+                None => continue,
+            },
+        };
+        if slider.move_handle(handle.handle, target) {
+            event_writer.send(RangeChangedEvent {
+                entity: handle.slider,
+                min: slider.min,
+                max: slider.max,
+            });
+        }
+    }
+}
+
+/// Publishes the value and bounds of each handle to assistive technologies
+fn update_handle_accessibility<T: SliderValue>(
+    sliders: Query<(&RangeSlider<T>, &Children), Changed<RangeSlider<T>>>,
+    mut handles: Query<(&RangeSliderHandle, &mut AccessibilityNode)>,
+) {
+    for (slider, children) in &sliders {
+        let mut iter = handles.iter_many_mut(children);
+        while let Some((handle, mut accessible)) = iter.fetch_next() {
+            accessible.set_label(match handle.handle {
+                RangeHandle::Min => "Minimum",
+                RangeHandle::Max => "Maximum",
+            });
+            accessible.set_numeric_value(slider.value(handle.handle).to_f64());
+            accessible.set_min_numeric_value(slider.bounds.0.to_f64());
+            accessible.set_max_numeric_value(slider.bounds.1.to_f64());
+            accessible.set_numeric_value_step(slider.keyboard_step());
+            for action in [
+                Action::Focus,
+                Action::SetValue,
+                Action::Increment,
+                Action::Decrement,
+            ] {
+                accessible.add_action(action);
+            }
+        }
+    }
+}
+
+fn update_range_sliders<T: SliderValue>(
+    sliders: Query<(&RangeSlider<T>, &Children), Changed<RangeSlider<T>>>,
+    mut fills: Query<&mut Node, With<RangeSliderFill>>,
+    mut handles: Query<(&RangeSliderHandle, &mut Node), Without<RangeSliderFill>>,
+) {
+    for (slider, children) in &sliders {
+        let (min, max) = (
+            slider.fraction(slider.min) as f32,
+            slider.fraction(slider.max) as f32,
+        );
+        let mut iter = fills.iter_many_mut(children);
+        while let Some(mut node) = iter.fetch_next() {
+            node.left = Val::Percent(min * 100.);
+            node.width = Val::Percent((max - min) * 100.);
+        }
+        let mut iter = handles.iter_many_mut(children);
+        while let Some((handle, mut node)) = iter.fetch_next() {
+            let fraction = match handle.handle {
+                RangeHandle::Min => min,
+                RangeHandle::Max => max,
+            };
+            node.left = Val::Percent(fraction * 100.);
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use bevy::ecs::component::ComponentInfo;
+use bevy::ecs::reflect::{ReflectComponent, ReflectResource};
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistry;
+
+use crate::clipboard::ClipboardAccess;
+use crate::toolbar::{ToolbarBuilder, ToolbarButtonBuilder, ToolbarButtonClickedEvent};
+
+const PANEL_BACKGROUND_COLOR: Color = Color::srgba(0.08, 0.08, 0.09, 0.9);
+const LABEL_COLOR: Color = Color::srgb(0.49, 0.53, 0.55);
+const TYPE_COLOR: Color = Color::srgb(0.93, 0.97, 1.0);
+const FONT_SIZE: f32 = 10.0;
+const REFRESH_SECONDS: f32 = 1.0;
+
+const COPY_ID: &str = "registry_report.copy";
+
+/// Optional plugin reporting component and resource types that can't be reflected,
+/// displayed by [`RegistryReportPanel`]s
+pub struct RegistryReportPlugin;
+
+impl Plugin for RegistryReportPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RegistryReport>()
+            .add_observer(create_registry_report_panel)
+            .add_systems(
+                Update,
+                (
+                    refresh_registry_report,
+                    update_registry_report_panels,
+                    on_registry_report_toolbar_click,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Why a type of the world can't be reflected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RegistryIssue {
+    /// The type isn't registered in the [`AppTypeRegistry`]
+    NotRegistered,
+    /// The component is registered without `#[reflect(Component)]`
+    MissingReflectComponent,
+    /// The resource is registered without `#[reflect(Resource)]`
+    MissingReflectResource,
+}
+
+impl fmt::Display for RegistryIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NotRegistered => "not registered",
+            Self::MissingReflectComponent => "missing #[reflect(Component)]",
+            Self::MissingReflectResource => "missing #[reflect(Resource)]",
+        })
+    }
+}
+
+/// Type present in the world that can't be reflected
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RegistryReportEntry {
+    /// Full type name
+    pub name: String,
+    /// Why the type can't be reflected
+    pub issue: RegistryIssue,
+}
+
+impl RegistryReportEntry {
+    /// Code fixing the issue
+    pub fn snippet(&self) -> String {
+        match self.issue {
+            RegistryIssue::NotRegistered => format!("app.register_type::<{}>();", self.name),
+            RegistryIssue::MissingReflectComponent => {
+                format!("// Add #[reflect(Component)] to {}", self.name)
+            }
+            RegistryIssue::MissingReflectResource => {
+                format!("// Add #[reflect(Resource)] to {}", self.name)
+            }
+        }
+    }
+}
+
+/// Component and resource types of the world that can't be reflected, refreshed every second.
+///
+/// Types without a [`TypeId`](std::any::TypeId), like dynamic components, are ignored.
+#[derive(Resource, Debug)]
+pub struct RegistryReport {
+    entries: Vec<RegistryReportEntry>,
+    timer: Timer,
+    /// Scans the world without waiting for the timer
+    pending: bool,
+}
+
+impl Default for RegistryReport {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            timer: Timer::from_seconds(REFRESH_SECONDS, TimerMode::Repeating),
+            pending: true,
+        }
+    }
+}
+
+impl RegistryReport {
+    /// Types that can't be reflected, sorted by name
+    pub fn entries(&self) -> &[RegistryReportEntry] {
+        &self.entries
+    }
+
+    /// Scans the world on the next update
+    pub const fn refresh(&mut self) {
+        self.pending = true;
+    }
+
+    /// Code fixing every issue, one line per type
+    pub fn snippet(&self) -> String {
+        self.entries
+            .iter()
+            .map(RegistryReportEntry::snippet)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Panel listing the types of [`RegistryReport`] with a button copying the registration snippet.
+///
+/// Requires [`RegistryReportPlugin`].
+#[derive(Component, Debug, Clone, Copy, Default)]
+#[require(Node)]
+pub struct RegistryReportPanel {
+    list: Option<Entity>,
+}
+
+fn check(
+    registry: &TypeRegistry,
+    info: &ComponentInfo,
+    resource: bool,
+) -> Option<RegistryReportEntry> {
+    let type_id = info.type_id()?;
+    let issue = match registry.get(type_id) {
+        None => RegistryIssue::NotRegistered,
+        Some(registration) if resource && registration.data::<ReflectResource>().is_none() => {
+            RegistryIssue::MissingReflectResource
+        }
+        Some(registration) if !resource && registration.data::<ReflectComponent>().is_none() => {
+            RegistryIssue::MissingReflectComponent
+        }
+        Some(_) => return None,
+    };
+    Some(RegistryReportEntry {
+        name: info.name().to_string(),
+        issue,
+    })
+}
+
+fn refresh_registry_report(world: &mut World) {
+    let delta = world.resource::<Time>().delta();
+    let mut report = world.resource_mut::<RegistryReport>();
+    let report = report.bypass_change_detection();
+    let elapsed = report.timer.tick(delta).just_finished();
+    if !std::mem::take(&mut report.pending) && !elapsed {
+        return;
+    }
+
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let mut entries = BTreeMap::new();
+    for archetype in world.archetypes().iter() {
+        if archetype.is_empty() {
+            continue;
+        }
+        for info in archetype
+            .components()
+            .filter_map(|id| world.components().get_info(id))
+        {
+            if let Some(entry) = check(&registry, info, false) {
+                entries.insert(entry.name.clone(), entry);
+            }
+        }
+    }
+    for (info, _) in world.iter_resources() {
+        if let Some(entry) = check(&registry, info, true) {
+            entries.insert(entry.name.clone(), entry);
+        }
+    }
+    let entries: Vec<_> = entries.into_values().collect();
+    drop(registry);
+
+    let mut report = world.resource_mut::<RegistryReport>();
+    if report.entries != entries {
+        report.entries = entries;
+    }
+}
+
+fn create_registry_report_panel(
+    trigger: Trigger<OnAdd, RegistryReportPanel>,
+    mut commands: Commands,
+    mut panels: Query<(&mut RegistryReportPanel, &mut Node)>,
+) {
+    let entity = trigger.entity();
+    let Ok((mut panel, mut node)) = panels.get_mut(entity) else {
+        return;
+    };
+    node.flex_direction = FlexDirection::Column;
+    node.row_gap = Val::Px(4.);
+    node.padding = UiRect::all(Val::Px(8.));
+
+    let toolbar = commands.spawn(ToolbarBuilder::default().build()).id();
+    ToolbarButtonBuilder::new(COPY_ID)
+        .with_label("Copy snippet")
+        .build(&mut commands, toolbar);
+    let list = commands
+        .spawn((
+            Name::new("RegistryReportList"),
+            Node {
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(2.),
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+        ))
+        .id();
+
+    commands
+        .entity(entity)
+        .insert(BackgroundColor(PANEL_BACKGROUND_COLOR))
+        .add_children(&[toolbar, list]);
+    panel.list = Some(list);
+}
+
+fn update_registry_report_panels(
+    mut commands: Commands,
+    report: Res<RegistryReport>,
+    panels: Query<Ref<RegistryReportPanel>>,
+) {
+    let text_font = TextFont {
+        font_size: FONT_SIZE,
+        ..default()
+    };
+    for panel in &panels {
+        // Rebuilt when the report changes, or once the panel is created
+        if !report.is_changed() && !panel.is_changed() {
+            continue;
+        }
+        let Some(list) = panel.list else {
+            continue;
+        };
+        commands
+            .entity(list)
+            .despawn_descendants()
+            .with_children(|parent| {
+                if report.entries.is_empty() {
+                    parent.spawn((
+                        Text::new("Every type can be reflected"),
+                        TextColor(LABEL_COLOR),
+                        text_font.clone(),
+                    ));
+                }
+                for entry in &report.entries {
+                    parent
+                        .spawn(Node {
+                            justify_content: JustifyContent::SpaceBetween,
+                            column_gap: Val::Px(8.),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            row.spawn((
+                                Text::new(entry.name.clone()),
+                                TextColor(TYPE_COLOR),
+                                text_font.clone(),
+                            ));
+                            row.spawn((
+                                Text::new(entry.issue.to_string()),
+                                TextColor(LABEL_COLOR),
+                                text_font.clone(),
+                            ));
+                        });
+                }
+            });
+    }
+}
+
+fn on_registry_report_toolbar_click(
+    mut events: EventReader<ToolbarButtonClickedEvent>,
+    parents: Query<&Parent>,
+    panels: Query<(), With<RegistryReportPanel>>,
+    report: Res<RegistryReport>,
+    mut clipboard: ClipboardAccess,
+) {
+    for event in events.read() {
+        let in_panel = parents
+            .iter_ancestors(event.entity)
+            .any(|ancestor| panels.contains(ancestor));
+        if in_panel && event.id == COPY_ID && !report.entries.is_empty() {
+            clipboard.copy_text(report.snippet());
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowResized};
+
+use crate::{buttons::prelude::ButtonSize, input_fields::InputFieldSize, WidgetsSystems};
+
+const DEFAULT_MEDIUM_MIN_WIDTH: f32 = 720.;
+const DEFAULT_LARGE_MIN_WIDTH: f32 = 1280.;
+
+/// Plugin switching widget sizes with the window width
+pub struct ResponsivePlugin;
+
+impl Plugin for ResponsivePlugin {
+    fn build(&self, app: &mut App) {
+        WidgetsSystems::configure(app);
+        app.register_type::<ResponsiveSize>()
+            .add_systems(Update, apply_responsive_sizes.in_set(WidgetsSystems::Input));
+    }
+}
+
+/// Size class of a widget, matching the small, medium and large design system sizes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+pub enum SizeClass {
+    /// Small widgets, for narrow windows
+    Small,
+    /// Medium widgets
+    #[default]
+    Medium,
+    /// Large widgets, for wide windows
+    Large,
+}
+
+impl From<SizeClass> for InputFieldSize {
+    fn from(class: SizeClass) -> Self {
+        match class {
+            SizeClass::Small => Self::Small,
+            SizeClass::Medium => Self::Medium,
+            SizeClass::Large => Self::Large,
+        }
+    }
+}
+
+impl From<SizeClass> for ButtonSize {
+    fn from(class: SizeClass) -> Self {
+        match class {
+            SizeClass::Small => Self::Small,
+            SizeClass::Medium => Self::Medium,
+            SizeClass::Large => Self::Large,
+        }
+    }
+}
+
+/// Binds the [`InputFieldSize`] or [`ButtonSize`] of a widget to breakpoints of the primary window width.
+///
+/// The size switches when the window is resized, and the widget fonts, paddings and heights follow it.
+///
+/// ```
+/// # use bevy_widgets::responsive::{ResponsiveSize, SizeClass};
+/// let responsive = ResponsiveSize::new(600., 1200.);
+/// assert_eq!(responsive.size_class(480.), SizeClass::Small);
+/// assert_eq!(responsive.size_class(800.), SizeClass::Medium);
+/// assert_eq!(responsive.size_class(1200.), SizeClass::Large);
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct ResponsiveSize {
+    /// Window width, in logical pixels, from which the widget is medium
+    pub medium_min_width: f32,
+    /// Window width, in logical pixels, from which the widget is large
+    pub large_min_width: f32,
+}
+
+impl Default for ResponsiveSize {
+    fn default() -> Self {
+        Self::new(DEFAULT_MEDIUM_MIN_WIDTH, DEFAULT_LARGE_MIN_WIDTH)
+    }
+}
+
+impl ResponsiveSize {
+    /// Creates breakpoints switching to medium and large from the given window widths
+    pub const fn new(medium_min_width: f32, large_min_width: f32) -> Self {
+        Self {
+            medium_min_width,
+            large_min_width,
+        }
+    }
+
+    /// Size class of the widget for a window `width`
+    pub fn size_class(&self, width: f32) -> SizeClass {
+        if width >= self.large_min_width {
+            SizeClass::Large
+        } else if width >= self.medium_min_width {
+            SizeClass::Medium
+        } else {
+            SizeClass::Small
+        }
+    }
+}
+
+fn apply_responsive_sizes(
+    mut resized: EventReader<WindowResized>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut widgets: Query<(
+        Ref<ResponsiveSize>,
+        Option<&mut InputFieldSize>,
+        Option<&mut ButtonSize>,
+    )>,
+) {
+    let window_resized = !resized.is_empty();
+    resized.clear();
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    for (responsive, field_size, button_size) in &mut widgets {
+        if !window_resized && !responsive.is_changed() {
+            continue;
+        }
+        let class = responsive.size_class(window.width());
+        if let Some(mut field_size) = field_size {
+            field_size.set_if_neq(class.into());
+        }
+        if let Some(mut button_size) = button_size {
+            button_size.set_if_neq(class.into());
+        }
+    }
+}
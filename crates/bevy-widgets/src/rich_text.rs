@@ -0,0 +1,209 @@
+use bevy::prelude::*;
+
+const RICH_TEXT_COLOR: Color = Color::srgb(0.29, 0.31, 0.33);
+const RICH_CODE_COLOR: Color = Color::srgb(0.19, 0.49, 0.71);
+const RICH_FONT_SIZE: f32 = 13.0;
+
+/// Plugin containing the rich label logic
+pub struct RichTextPlugin;
+
+impl Plugin for RichTextPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RichTextFonts>()
+            .register_type::<RichLabel>()
+            .add_systems(Update, build_rich_labels);
+    }
+}
+
+/// Fonts used by [`RichLabel`] spans. Defaults to Bevy's default font for every style.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct RichTextFonts {
+    /// Font of unstyled text
+    pub regular: Handle<Font>,
+    /// Font of `**bold**` text
+    pub bold: Handle<Font>,
+    /// Font of `*italic*` text
+    pub italic: Handle<Font>,
+    /// Font of `` `code` `` text
+    pub code: Handle<Font>,
+}
+
+/// Text label built from a small markup:
+/// - `**bold**`
+/// - `*italic*`
+/// - `` `inline code` ``
+/// - `[color=#ff8800]colored[/color]`, with hexadecimal colors
+///
+/// `\` escapes the next character. Each styled run becomes a [`TextSpan`] child, rebuilt when the markup changes.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::rich_text::RichLabel;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn(RichLabel::new("Press `Ctrl+S` to **save** [color=#e8b400]now[/color]"));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+#[require(Text)]
+pub struct RichLabel {
+    /// Markup of the label
+    pub markup: String,
+    /// Font size of every span
+    pub font_size: f32,
+    /// Color of spans without a `[color]` tag
+    pub color: Color,
+}
+
+impl RichLabel {
+    /// Creates a label from `markup`
+    pub fn new(markup: impl Into<String>) -> Self {
+        Self {
+            markup: markup.into(),
+            font_size: RICH_FONT_SIZE,
+            color: RICH_TEXT_COLOR,
+        }
+    }
+
+    /// Sets the font size of the label
+    pub const fn with_font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    /// Sets the color of spans without a `[color]` tag
+    pub const fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+/// A run of text sharing the same style
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RichSpan {
+    /// Text of the run
+    pub text: String,
+    /// Inside `**`
+    pub bold: bool,
+    /// Inside `*`
+    pub italic: bool,
+    /// Inside backticks
+    pub code: bool,
+    /// Color of the innermost `[color]` tag
+    pub color: Option<Color>,
+}
+
+/// Splits `markup` into styled runs.
+///
+/// Unclosed styles extend to the end of the markup and malformed tags are kept as text.
+/// ```rust
+/// # use bevy_widgets::rich_text::parse_markup;
+/// let spans = parse_markup("a **b** `*c*`");
+/// assert_eq!(spans.len(), 4);
+/// assert!(spans[1].bold);
+/// assert!(spans[3].code && spans[3].text == "*c*");
+/// ```
+pub fn parse_markup(markup: &str) -> Vec<RichSpan> {
+    let mut spans = Vec::new();
+    let mut current = RichSpan::default();
+    let mut colors: Vec<Color> = Vec::new();
+    let mut rest = markup;
+
+    while let Some(c) = rest.chars().next() {
+        let mut style = current.clone();
+        style.text.clear();
+        let consumed = if current.code {
+            if c == '`' {
+                style.code = false;
+                1
+            } else {
+                0
+            }
+        } else if c == '\\' {
+            let escaped = rest[1..].chars().next();
+            current.text.extend(escaped);
+            rest = &rest[1 + escaped.map_or(0, char::len_utf8)..];
+            continue;
+        } else if rest.starts_with("**") {
+            style.bold = !style.bold;
+            2
+        } else if c == '*' {
+            style.italic = !style.italic;
+            1
+        } else if c == '`' {
+            style.code = true;
+            1
+        } else if rest.starts_with("[/color]") {
+            colors.pop();
+            style.color = colors.last().copied();
+            "[/color]".len()
+        } else if let Some((color, length)) = color_tag(rest) {
+            colors.push(color);
+            style.color = Some(color);
+            length
+        } else {
+            0
+        };
+
+        if consumed == 0 {
+            current.text.push(c);
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+        if !current.text.is_empty() {
+            spans.push(std::mem::take(&mut current));
+        }
+        current = style;
+        rest = &rest[consumed..];
+    }
+    if !current.text.is_empty() {
+        spans.push(current);
+    }
+    spans
+}
+
+/// Color and length of a `[color=#rrggbb]` tag at the start of `input`
+fn color_tag(input: &str) -> Option<(Color, usize)> {
+    let tag = input.strip_prefix("[color=")?;
+    let end = tag.find(']')?;
+    let color = Srgba::hex(&tag[..end]).ok()?;
+    Some((color.into(), "[color=".len() + end + 1))
+}
+
+fn build_rich_labels(
+    mut commands: Commands,
+    labels: Query<(Entity, &RichLabel), Changed<RichLabel>>,
+    fonts: Res<RichTextFonts>,
+) {
+    for (entity, label) in &labels {
+        commands
+            .entity(entity)
+            .despawn_descendants()
+            .with_children(|parent| {
+                for span in parse_markup(&label.markup) {
+                    let font = if span.code {
+                        &fonts.code
+                    } else if span.bold {
+                        &fonts.bold
+                    } else if span.italic {
+                        &fonts.italic
+                    } else {
+                        &fonts.regular
+                    };
+                    let color = span.color.unwrap_or(if span.code {
+                        RICH_CODE_COLOR
+                    } else {
+                        label.color
+                    });
+                    parent.spawn((
+                        TextSpan::new(span.text),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: label.font_size,
+                            ..default()
+                        },
+                        TextColor(color),
+                    ));
+                }
+            });
+    }
+}
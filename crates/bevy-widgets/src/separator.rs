@@ -0,0 +1,159 @@
+use bevy::prelude::*;
+
+const SEPARATOR_COLOR: Color = Color::srgb(0.8, 0.83, 0.85);
+const SEPARATOR_THICKNESS: f32 = 1.0;
+
+/// Plugin containing the separator and spacer types
+pub struct SeparatorPlugin;
+
+impl Plugin for SeparatorPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Separator>().register_type::<Spacer>();
+    }
+}
+
+/// Direction of the line drawn by a [`Separator`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+pub enum SeparatorDirection {
+    /// Line across the width of its parent, separating stacked widgets
+    #[default]
+    Horizontal,
+    /// Line across the height of its parent, separating widgets side by side
+    Vertical,
+}
+
+/// Hairline separating widgets.
+/// Should be created with [`SeparatorBuilder`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Component)]
+#[require(Node)]
+pub struct Separator {
+    /// Direction of the line
+    pub direction: SeparatorDirection,
+}
+
+/// Builder for [`Separator`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeparatorBuilder {
+    direction: SeparatorDirection,
+    thickness: f32,
+    margin: f32,
+    color: Color,
+}
+
+impl Default for SeparatorBuilder {
+    fn default() -> Self {
+        Self {
+            direction: SeparatorDirection::Horizontal,
+            thickness: SEPARATOR_THICKNESS,
+            margin: 0.,
+            color: SEPARATOR_COLOR,
+        }
+    }
+}
+
+impl SeparatorBuilder {
+    /// Creates a vertical separator builder
+    pub fn vertical() -> Self {
+        Self {
+            direction: SeparatorDirection::Vertical,
+            ..default()
+        }
+    }
+
+    /// Sets the line thickness in pixels. Defaults to `1px`.
+    pub const fn with_thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Sets the space on both sides of the line in pixels
+    pub const fn with_margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Sets the line color
+    pub const fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Builds the separator
+    pub fn build(self) -> (Separator, Node, BackgroundColor) {
+        let node = match self.direction {
+            SeparatorDirection::Horizontal => Node {
+                width: Val::Percent(100.),
+                height: Val::Px(self.thickness),
+                margin: UiRect::vertical(Val::Px(self.margin)),
+                flex_shrink: 0.,
+                ..default()
+            },
+            SeparatorDirection::Vertical => Node {
+                width: Val::Px(self.thickness),
+                align_self: AlignSelf::Stretch,
+                margin: UiRect::horizontal(Val::Px(self.margin)),
+                flex_shrink: 0.,
+                ..default()
+            },
+        };
+        (
+            Separator {
+                direction: self.direction,
+            },
+            node,
+            BackgroundColor(self.color),
+        )
+    }
+}
+
+/// Empty widget growing to fill the free space of its parent, pushing its siblings apart.
+/// Should be created with [`SpacerBuilder`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Component)]
+#[require(Node)]
+pub struct Spacer;
+
+/// Builder for [`Spacer`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpacerBuilder {
+    grow: f32,
+    min_size: f32,
+}
+
+impl Default for SpacerBuilder {
+    fn default() -> Self {
+        Self {
+            grow: 1.,
+            min_size: 0.,
+        }
+    }
+}
+
+impl SpacerBuilder {
+    /// Sets the share of free space taken relative to sibling spacers. Defaults to `1`.
+    pub const fn with_grow(mut self, grow: f32) -> Self {
+        self.grow = grow;
+        self
+    }
+
+    /// Sets the size kept when there is no free space, in pixels
+    pub const fn with_min_size(mut self, min_size: f32) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Builds the spacer
+    pub fn build(self) -> (Spacer, Node) {
+        (
+            Spacer,
+            Node {
+                flex_grow: self.grow,
+                flex_basis: Val::Px(self.min_size),
+                min_width: Val::Px(0.),
+                min_height: Val::Px(0.),
+                ..default()
+            },
+        )
+    }
+}
@@ -0,0 +1,311 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+const LINE_COLOR: Color = Color::srgb(0.51, 0.79, 1.);
+const LABEL_COLOR: Color = Color::srgb(0.49, 0.53, 0.55);
+const LABEL_FONT_SIZE: f32 = 8.0;
+const LINE_THICKNESS: f32 = 1.5;
+const DEFAULT_CAPACITY: usize = 60;
+const DEFAULT_WIDTH: f32 = 120.0;
+const DEFAULT_HEIGHT: f32 = 32.0;
+
+/// Plugin containing the sparkline logic
+pub struct SparklinePlugin;
+
+impl Plugin for SparklinePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(create_sparkline)
+            .add_systems(PostUpdate, draw_sparklines.after(UiSystem::Layout));
+    }
+}
+
+/// Small line chart of the latest samples pushed into it, e.g. diagnostics or the history of a value.
+///
+/// The line is drawn with UI nodes and scaled to fit the samples between their minimum and maximum,
+/// unless a fixed range is set. Can be created with [`SparklineBuilder`].
+#[derive(Component, Debug, Clone)]
+#[require(Node)]
+pub struct Sparkline {
+    samples: VecDeque<f32>,
+    capacity: usize,
+    color: Color,
+    range: Option<(f32, f32)>,
+    show_labels: bool,
+}
+
+impl Default for Sparkline {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl Sparkline {
+    /// Creates a sparkline keeping the latest `capacity` samples
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(2),
+            color: LINE_COLOR,
+            range: None,
+            show_labels: false,
+        }
+    }
+
+    /// Adds a sample, dropping the oldest one when full
+    pub fn push(&mut self, sample: f32) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    /// Samples from oldest to latest
+    pub fn samples(&self) -> impl Iterator<Item = f32> + '_ {
+        self.samples.iter().copied()
+    }
+
+    /// Maximum number of samples kept
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Removes every sample
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Latest sample
+    pub fn latest(&self) -> Option<f32> {
+        self.samples.back().copied()
+    }
+
+    /// Fixed `(min, max)` range of the chart, `None` fits the samples
+    pub const fn range(&self) -> Option<(f32, f32)> {
+        self.range
+    }
+
+    /// Sets a fixed `(min, max)` range, `None` fits the samples
+    pub const fn set_range(&mut self, range: Option<(f32, f32)>) {
+        self.range = range;
+    }
+
+    /// Range displayed: the fixed range or the one of the finite samples
+    pub fn displayed_range(&self) -> Option<(f32, f32)> {
+        self.range.or_else(|| self.samples_range())
+    }
+
+    fn samples_range(&self) -> Option<(f32, f32)> {
+        self.samples()
+            .filter(|sample| sample.is_finite())
+            .fold(None, |range, sample| match range {
+                None => Some((sample, sample)),
+                Some((min, max)) => Some((min.min(sample), max.max(sample))),
+            })
+    }
+}
+
+/// Builder for [`Sparkline`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SparklineBuilder {
+    window: usize,
+    color: Color,
+    range: Option<(f32, f32)>,
+    show_labels: bool,
+    width: Val,
+    height: Val,
+}
+
+impl Default for SparklineBuilder {
+    fn default() -> Self {
+        Self {
+            window: DEFAULT_CAPACITY,
+            color: LINE_COLOR,
+            range: None,
+            show_labels: false,
+            width: Val::Px(DEFAULT_WIDTH),
+            height: Val::Px(DEFAULT_HEIGHT),
+        }
+    }
+}
+
+impl SparklineBuilder {
+    /// Sets the number of samples displayed. Defaults to `60`.
+    pub const fn with_window(mut self, window: usize) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Sets the line color
+    pub const fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Uses a fixed `(min, max)` range instead of fitting the samples
+    pub const fn with_range(mut self, min: f32, max: f32) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Shows the minimum and maximum of the displayed range
+    pub const fn with_labels(mut self) -> Self {
+        self.show_labels = true;
+        self
+    }
+
+    /// Sets the chart size. Defaults to `120px` by `32px`.
+    pub const fn with_size(mut self, width: Val, height: Val) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Builds the sparkline
+    pub fn build(self) -> (Sparkline, Node) {
+        let mut sparkline = Sparkline::new(self.window);
+        sparkline.color = self.color;
+        sparkline.range = self.range;
+        sparkline.show_labels = self.show_labels;
+        (
+            sparkline,
+            Node {
+                width: self.width,
+                height: self.height,
+                ..default()
+            },
+        )
+    }
+}
+
+#[derive(Component, Debug)]
+struct SparklineSegment;
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+enum SparklineLabel {
+    Min,
+    Max,
+}
+
+fn format_label(value: f32) -> String {
+    if value.abs() >= 100. || value.fract() == 0. {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.2}")
+    }
+}
+
+fn create_sparkline(
+    trigger: Trigger<OnAdd, Sparkline>,
+    mut commands: Commands,
+    sparklines: Query<&Sparkline>,
+) {
+    let entity = trigger.entity();
+    let Ok(sparkline) = sparklines.get(entity) else {
+        return;
+    };
+    commands.entity(entity).with_children(|parent| {
+        for _ in 1..sparkline.capacity {
+            parent.spawn((
+                SparklineSegment,
+                Node {
+                    position_type: PositionType::Absolute,
+                    height: Val::Px(LINE_THICKNESS),
+                    ..default()
+                },
+                BackgroundColor(sparkline.color),
+                Visibility::Hidden,
+                PickingBehavior::IGNORE,
+            ));
+        }
+        if sparkline.show_labels {
+            for label in [SparklineLabel::Max, SparklineLabel::Min] {
+                parent.spawn((
+                    label,
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(2.),
+                        top: if label == SparklineLabel::Max {
+                            Val::Px(0.)
+                        } else {
+                            Val::Auto
+                        },
+                        bottom: if label == SparklineLabel::Min {
+                            Val::Px(0.)
+                        } else {
+                            Val::Auto
+                        },
+                        ..default()
+                    },
+                    Text::default(),
+                    TextColor(LABEL_COLOR),
+                    TextFont {
+                        font_size: LABEL_FONT_SIZE,
+                        ..default()
+                    },
+                    PickingBehavior::IGNORE,
+                ));
+            }
+        }
+    });
+}
+
+fn draw_sparklines(
+    sparklines: Query<
+        (&Sparkline, &ComputedNode, &Children),
+        Or<(Changed<Sparkline>, Changed<ComputedNode>)>,
+    >,
+    mut segments: Query<(&mut Node, &mut Transform, &mut Visibility), With<SparklineSegment>>,
+    mut labels: Query<(&SparklineLabel, &mut Text)>,
+) {
+    for (sparkline, computed, children) in &sparklines {
+        let segment_count = sparkline.capacity - 1;
+        let size = computed.size() * computed.inverse_scale_factor();
+        let (min, max) = sparkline.displayed_range().unwrap_or((0., 1.));
+        let span = if max > min { max - min } else { 1. };
+        let step = size.x / segment_count as f32;
+        // Latest sample is on the right edge.
+        let offset = sparkline.capacity - sparkline.samples.len();
+        let points: Vec<Vec2> = sparkline
+            .samples()
+            .enumerate()
+            .map(|(index, sample)| {
+                let normalized = if sample.is_finite() {
+                    ((sample - min) / span).clamp(0., 1.)
+                } else {
+                    0.
+                };
+                Vec2::new((index + offset) as f32 * step, size.y - normalized * size.y)
+            })
+            .collect();
+
+        let mut label_iter = labels.iter_many_mut(children);
+        while let Some((label, mut text)) = label_iter.fetch_next() {
+            text.0 = match (sparkline.displayed_range(), label) {
+                (None, _) => String::new(),
+                (Some((min, _)), SparklineLabel::Min) => format_label(min),
+                (Some((_, max)), SparklineLabel::Max) => format_label(max),
+            };
+        }
+
+        let mut iter = segments.iter_many_mut(children);
+        let mut index = 0;
+        while let Some((mut node, mut transform, mut visibility)) = iter.fetch_next() {
+            let (start, end) = (points.get(index), points.get(index + 1));
+            index += 1;
+            let (Some(start), Some(end)) = (start, end) else {
+                *visibility = Visibility::Hidden;
+                continue;
+            };
+            let delta = *end - *start;
+            let length = delta.length();
+            let center = (*start + *end) / 2.;
+            node.width = Val::Px(length);
+            node.left = Val::Px(center.x - length / 2.);
+            node.top = Val::Px(center.y - LINE_THICKNESS / 2.);
+            transform.rotation = Quat::from_rotation_z(delta.y.atan2(delta.x));
+            *visibility = Visibility::Inherited;
+        }
+    }
+}
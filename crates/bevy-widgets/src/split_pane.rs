@@ -0,0 +1,274 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy::window::SystemCursorIcon;
+use serde::{Deserialize, Serialize};
+
+use crate::cursor::HoverCursor;
+
+const DIVIDER_COLOR: Color = Color::srgb(0.13, 0.13, 0.14);
+const DIVIDER_THICKNESS: f32 = 4.0;
+const DEFAULT_MIN_PANE_SIZE: f32 = 40.0;
+
+/// Plugin containing the split pane logic
+pub struct SplitPanePlugin;
+
+impl Plugin for SplitPanePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SplitRatios>()
+            .register_type::<SplitDirection>()
+            .register_type::<SplitRatios>()
+            .add_observer(restore_ratio)
+            .add_observer(on_divider_drag)
+            .add_systems(Update, (insert_divider, apply_ratio).chain());
+    }
+}
+
+/// How the two panes of a [`SplitPane`] are laid out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect, Serialize, Deserialize)]
+pub enum SplitDirection {
+    /// Panes side by side, divider is vertical
+    #[default]
+    Horizontal,
+    /// Panes stacked, divider is horizontal
+    Vertical,
+}
+
+/// Container splitting its two children with a draggable divider.
+///
+/// Spawn exactly two children, the divider is inserted between them.
+/// Should be created with [`SplitPaneBuilder`].
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+#[require(Node)]
+pub struct SplitPane {
+    direction: SplitDirection,
+    ratio: f32,
+    min_size: f32,
+    key: Option<String>,
+    #[reflect(ignore)]
+    divider: Option<Entity>,
+}
+
+impl SplitPane {
+    /// Layout direction of the panes
+    pub const fn direction(&self) -> SplitDirection {
+        self.direction
+    }
+
+    /// Fraction of the container taken by the first pane, in `0..=1`
+    pub const fn ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    /// Sets the fraction of the container taken by the first pane
+    pub const fn set_ratio(&mut self, ratio: f32) {
+        self.ratio = ratio.clamp(0., 1.);
+    }
+
+    /// Key used to persist the ratio in [`SplitRatios`]
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
+/// Marks the draggable divider of a [`SplitPane`]
+#[derive(Component, Debug)]
+pub struct SplitDivider {
+    pane: Entity,
+}
+
+/// Split ratios of every keyed [`SplitPane`].
+///
+/// Updated while dividers are dragged. Panes spawned with a key found here start with the stored ratio.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Reflect, Serialize, Deserialize)]
+#[reflect(Resource)]
+pub struct SplitRatios(pub HashMap<String, f32>);
+
+impl SplitRatios {
+    /// Ratio stored for `key`
+    pub fn get(&self, key: &str) -> Option<f32> {
+        self.0.get(key).copied()
+    }
+}
+
+/// Builder for [`SplitPane`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitPaneBuilder {
+    direction: SplitDirection,
+    ratio: f32,
+    min_size: f32,
+    key: Option<String>,
+}
+
+impl SplitPaneBuilder {
+    /// Creates a builder for a pane split in `direction`, in half
+    pub const fn new(direction: SplitDirection) -> Self {
+        Self {
+            direction,
+            ratio: 0.5,
+            min_size: DEFAULT_MIN_PANE_SIZE,
+            key: None,
+        }
+    }
+
+    /// Sets the initial fraction taken by the first pane. Defaults to `0.5`.
+    pub const fn with_ratio(mut self, ratio: f32) -> Self {
+        self.ratio = ratio.clamp(0., 1.);
+        self
+    }
+
+    /// Sets the minimum size of each pane in logical pixels. Defaults to `40.`.
+    pub const fn with_min_size(mut self, min_size: f32) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Persists the ratio under `key` in [`SplitRatios`]
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// Builds the split container, the two panes should be spawned as its children
+    pub fn build(self) -> (SplitPane, Node) {
+        (
+            SplitPane {
+                direction: self.direction,
+                ratio: self.ratio,
+                min_size: self.min_size,
+                key: self.key,
+                divider: None,
+            },
+            Node {
+                flex_direction: match self.direction {
+                    SplitDirection::Horizontal => FlexDirection::Row,
+                    SplitDirection::Vertical => FlexDirection::Column,
+                },
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                ..default()
+            },
+        )
+    }
+}
+
+fn restore_ratio(
+    trigger: Trigger<OnAdd, SplitPane>,
+    mut panes: Query<&mut SplitPane>,
+    ratios: Res<SplitRatios>,
+) {
+    let Ok(mut pane) = panes.get_mut(trigger.entity()) else {
+        return;
+    };
+    if let Some(ratio) = pane.key().and_then(|key| ratios.get(key)) {
+        pane.set_ratio(ratio);
+    }
+}
+
+fn insert_divider(
+    mut commands: Commands,
+    mut panes: Query<(Entity, &mut SplitPane, &Children), Changed<Children>>,
+) {
+    for (entity, mut pane, children) in &mut panes {
+        if pane.divider.is_some() || children.len() < 2 {
+            continue;
+        }
+        let vertical = pane.direction == SplitDirection::Vertical;
+        let divider = commands
+            .spawn((
+                SplitDivider { pane: entity },
+                Name::new("SplitDivider"),
+                Node {
+                    width: if vertical {
+                        Val::Percent(100.)
+                    } else {
+                        Val::Px(DIVIDER_THICKNESS)
+                    },
+                    height: if vertical {
+                        Val::Px(DIVIDER_THICKNESS)
+                    } else {
+                        Val::Percent(100.)
+                    },
+                    flex_shrink: 0.,
+                    ..default()
+                },
+                BackgroundColor(DIVIDER_COLOR),
+                HoverCursor(if vertical {
+                    SystemCursorIcon::NsResize
+                } else {
+                    SystemCursorIcon::EwResize
+                }),
+            ))
+            .id();
+        commands.entity(entity).insert_children(1, &[divider]);
+        pane.divider = Some(divider);
+    }
+}
+
+fn apply_ratio(
+    panes: Query<(&SplitPane, &Children), Or<(Changed<SplitPane>, Changed<Children>)>>,
+    mut nodes: Query<&mut Node, Without<SplitPane>>,
+) {
+    for (pane, children) in &panes {
+        let Some(divider) = pane.divider else {
+            continue;
+        };
+        let mut panels = children.iter().filter(|child| **child != divider);
+        let (Some(first), Some(second)) = (panels.next(), panels.next()) else {
+            continue;
+        };
+        let vertical = pane.direction == SplitDirection::Vertical;
+        if let Ok(mut node) = nodes.get_mut(*first) {
+            node.flex_basis = Val::Percent(pane.ratio * 100.);
+            node.flex_grow = 0.;
+            node.flex_shrink = 1.;
+            set_min_size(&mut node, pane.min_size, vertical);
+        }
+        if let Ok(mut node) = nodes.get_mut(*second) {
+            node.flex_basis = Val::Px(0.);
+            node.flex_grow = 1.;
+            set_min_size(&mut node, pane.min_size, vertical);
+        }
+    }
+}
+
+const fn set_min_size(node: &mut Node, min_size: f32, vertical: bool) {
+    if vertical {
+        node.min_height = Val::Px(min_size);
+    } else {
+        node.min_width = Val::Px(min_size);
+    }
+}
+
+fn on_divider_drag(
+    mut trigger: Trigger<Pointer<Drag>>,
+    dividers: Query<&SplitDivider>,
+    mut panes: Query<(&mut SplitPane, &ComputedNode)>,
+    mut ratios: ResMut<SplitRatios>,
+    ui_scale: Res<UiScale>,
+) {
+    let Ok(divider) = dividers.get(trigger.entity()) else {
+        return;
+    };
+    trigger.propagate(false);
+    let Ok((mut pane, computed)) = panes.get_mut(divider.pane) else {
+        return;
+    };
+
+    let size = computed.size() * computed.inverse_scale_factor();
+    let (length, delta) = match pane.direction {
+        SplitDirection::Horizontal => (size.x, trigger.delta.x / ui_scale.0),
+        SplitDirection::Vertical => (size.y, trigger.delta.y / ui_scale.0),
+    };
+    let available = length - DIVIDER_THICKNESS;
+    if available <= 0. {
+        return;
+    }
+    let min = (pane.min_size / available).min(0.5);
+    let ratio = (pane.ratio + delta / available).clamp(min, 1. - min);
+    pane.set_ratio(ratio);
+
+    if let Some(key) = pane.key.clone() {
+        ratios.0.insert(key, pane.ratio);
+    }
+}
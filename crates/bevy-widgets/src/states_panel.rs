@@ -0,0 +1,372 @@
+use bevy::ecs::schedule::BoxedCondition;
+use bevy::prelude::*;
+use bevy::reflect::{DynamicEnum, DynamicVariant, TypeInfo, Typed, VariantInfo};
+use bevy::state::state::FreelyMutableState;
+
+use crate::derived_label::DerivedLabel;
+use crate::toolbar::{ToolbarBuilder, ToolbarButtonBuilder, ToolbarButtonClickedEvent};
+
+const PANEL_BACKGROUND_COLOR: Color = Color::srgba(0.08, 0.08, 0.09, 0.9);
+const LABEL_COLOR: Color = Color::srgb(0.49, 0.53, 0.55);
+const VALUE_COLOR: Color = Color::srgb(0.93, 0.97, 1.0);
+const CONDITION_COLOR: Color = Color::srgb(0.51, 0.79, 1.);
+const FONT_SIZE: f32 = 10.0;
+const REFRESH_SECONDS: f32 = 1.0;
+
+/// Optional plugin showing states and schedules in [`StatesPanel`]s.
+///
+/// States are listed once registered with [`StatesPanelAppExt::add_state_control`].
+pub struct StatesPanelPlugin;
+
+impl Plugin for StatesPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StateControls>()
+            .init_resource::<ScheduleReport>()
+            .add_observer(create_states_panel)
+            .add_systems(First, refresh_schedule_report)
+            .add_systems(Update, (update_schedule_lists, on_state_transition_click));
+    }
+}
+
+/// Extension trait for [`App`] listing states in [`StatesPanel`]s.
+///
+/// Unit variants of the state can be queued as transitions from the panel.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::states_panel::StatesPanelAppExt;
+/// #[derive(States, Reflect, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// enum EditorMode {
+///     #[default]
+///     Edit,
+///     Play,
+/// }
+///
+/// App::new().add_state_control::<EditorMode>();
+/// ```
+pub trait StatesPanelAppExt {
+    /// Lists the state `S` in [`StatesPanel`]s
+    fn add_state_control<S: FreelyMutableState + FromReflect + Typed>(&mut self) -> &mut Self;
+}
+
+impl StatesPanelAppExt for App {
+    fn add_state_control<S: FreelyMutableState + FromReflect + Typed>(&mut self) -> &mut Self {
+        let variants = match S::type_info() {
+            TypeInfo::Enum(info) => info
+                .iter()
+                .filter(|variant| matches!(variant, VariantInfo::Unit(_)))
+                .map(|variant| variant.name().to_string())
+                .collect(),
+            _ => Vec::new(),
+        };
+        self.world_mut()
+            .get_resource_or_init::<StateControls>()
+            .0
+            .push(StateControl {
+                name: S::short_type_path().to_string(),
+                variants,
+                current: current_state::<S>,
+                queue: queue_state::<S>,
+            });
+        self
+    }
+}
+
+/// State listed in [`StatesPanel`]s
+#[derive(Debug, Clone)]
+struct StateControl {
+    name: String,
+    variants: Vec<String>,
+    current: fn(&World) -> Option<String>,
+    queue: fn(&mut World, &str),
+}
+
+/// States registered with [`StatesPanelAppExt::add_state_control`]
+#[derive(Resource, Debug, Default)]
+struct StateControls(Vec<StateControl>);
+
+fn current_state<S: FreelyMutableState>(world: &World) -> Option<String> {
+    world
+        .get_resource::<State<S>>()
+        .map(|state| format!("{:?}", state.get()))
+}
+
+fn queue_state<S: FreelyMutableState + FromReflect>(world: &mut World, variant: &str) {
+    let value = DynamicEnum::new(variant, DynamicVariant::Unit);
+    let (Some(state), Some(mut next)) = (
+        S::from_reflect(&value),
+        world.get_resource_mut::<NextState<S>>(),
+    ) else {
+        return;
+    };
+    next.set(state);
+}
+
+/// Button queueing a transition to a state variant
+#[derive(Component, Debug, Clone)]
+struct StateTransitionButton {
+    state: usize,
+    variant: String,
+}
+
+/// System or system set of a schedule with its run conditions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleNode {
+    /// System or system set name
+    pub name: String,
+    /// Names of the run conditions
+    pub conditions: Vec<String>,
+}
+
+/// Systems and system sets of a schedule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleEntry {
+    /// Schedule label
+    pub label: String,
+    /// Named system sets
+    pub sets: Vec<ScheduleNode>,
+    /// Systems
+    pub systems: Vec<ScheduleNode>,
+}
+
+/// Schedules of the world, refreshed every second in [`First`].
+///
+/// Schedules are removed from the world while they run, so [`Main`] and [`First`] are not listed.
+#[derive(Resource, Debug)]
+pub struct ScheduleReport {
+    entries: Vec<ScheduleEntry>,
+    timer: Timer,
+    /// Reads the schedules without waiting for the timer
+    pending: bool,
+}
+
+impl Default for ScheduleReport {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            timer: Timer::from_seconds(REFRESH_SECONDS, TimerMode::Repeating),
+            pending: true,
+        }
+    }
+}
+
+impl ScheduleReport {
+    /// Schedules sorted by label
+    pub fn entries(&self) -> &[ScheduleEntry] {
+        &self.entries
+    }
+
+    /// Reads the schedules on the next update
+    pub const fn refresh(&mut self) {
+        self.pending = true;
+    }
+}
+
+/// Panel listing the states registered with [`StatesPanelAppExt::add_state_control`], with buttons
+/// queueing transitions, and a read-only view of the [`ScheduleReport`].
+///
+/// Requires [`StatesPanelPlugin`].
+#[derive(Component, Debug, Clone, Copy, Default)]
+#[require(Node)]
+pub struct StatesPanel {
+    schedules: Option<Entity>,
+}
+
+fn schedule_node(name: String, conditions: &[BoxedCondition]) -> ScheduleNode {
+    ScheduleNode {
+        name,
+        conditions: conditions
+            .iter()
+            .map(|condition| condition.name().to_string())
+            .collect(),
+    }
+}
+
+fn refresh_schedule_report(world: &mut World) {
+    let delta = world.resource::<Time>().delta();
+    let mut report = world.resource_mut::<ScheduleReport>();
+    let report = report.bypass_change_detection();
+    let elapsed = report.timer.tick(delta).just_finished();
+    if !std::mem::take(&mut report.pending) && !elapsed {
+        return;
+    }
+
+    let mut entries: Vec<_> = world
+        .resource::<Schedules>()
+        .iter()
+        .map(|(label, schedule)| {
+            let graph = schedule.graph();
+            ScheduleEntry {
+                label: format!("{label:?}"),
+                // Every system has an anonymous set of its type
+                sets: graph
+                    .system_sets()
+                    .filter(|(_, set, _)| set.system_type().is_none())
+                    .map(|(_, set, conditions)| schedule_node(format!("{set:?}"), conditions))
+                    .collect(),
+                systems: graph
+                    .systems()
+                    .map(|(_, system, conditions)| {
+                        schedule_node(system.name().to_string(), conditions)
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+    entries.sort_by(|a, b| a.label.cmp(&b.label));
+
+    let mut report = world.resource_mut::<ScheduleReport>();
+    if report.entries != entries {
+        report.entries = entries;
+    }
+}
+
+fn create_states_panel(
+    trigger: Trigger<OnAdd, StatesPanel>,
+    mut commands: Commands,
+    mut panels: Query<(&mut StatesPanel, &mut Node)>,
+    controls: Res<StateControls>,
+) {
+    let entity = trigger.entity();
+    let Ok((mut panel, mut node)) = panels.get_mut(entity) else {
+        return;
+    };
+    node.flex_direction = FlexDirection::Column;
+    node.row_gap = Val::Px(4.);
+    node.padding = UiRect::all(Val::Px(8.));
+
+    let text_font = TextFont {
+        font_size: FONT_SIZE,
+        ..default()
+    };
+    let mut children = Vec::new();
+    for (index, control) in controls.0.iter().enumerate() {
+        let current = control.current;
+        let row = commands
+            .spawn(Node {
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.),
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn((
+                    Text::new(control.name.clone()),
+                    TextColor(LABEL_COLOR),
+                    text_font.clone(),
+                ));
+                row.spawn((
+                    DerivedLabel::new(move |world| current(world).unwrap_or_else(|| "-".into())),
+                    TextColor(VALUE_COLOR),
+                    text_font.clone(),
+                ));
+            })
+            .id();
+        let toolbar = commands.spawn(ToolbarBuilder::default().build()).id();
+        for variant in &control.variants {
+            let button = ToolbarButtonBuilder::new(format!("states.{}.{variant}", control.name))
+                .with_label(variant.clone())
+                .build(&mut commands, toolbar);
+            commands.entity(button).insert(StateTransitionButton {
+                state: index,
+                variant: variant.clone(),
+            });
+        }
+        commands.entity(row).add_child(toolbar);
+        children.push(row);
+    }
+
+    let schedules = commands
+        .spawn((
+            Name::new("StatesPanelSchedules"),
+            Node {
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(2.),
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+        ))
+        .id();
+    children.push(schedules);
+
+    commands
+        .entity(entity)
+        .insert(BackgroundColor(PANEL_BACKGROUND_COLOR))
+        .add_children(&children);
+    panel.schedules = Some(schedules);
+}
+
+fn spawn_schedule_node(parent: &mut ChildBuilder, node: &ScheduleNode, text_font: &TextFont) {
+    parent
+        .spawn(Node {
+            column_gap: Val::Px(8.),
+            padding: UiRect::left(Val::Px(8.)),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Text::new(node.name.clone()),
+                TextColor(VALUE_COLOR),
+                text_font.clone(),
+            ));
+            if !node.conditions.is_empty() {
+                row.spawn((
+                    Text::new(format!("if {}", node.conditions.join(", "))),
+                    TextColor(CONDITION_COLOR),
+                    text_font.clone(),
+                ));
+            }
+        });
+}
+
+fn update_schedule_lists(
+    mut commands: Commands,
+    report: Res<ScheduleReport>,
+    panels: Query<Ref<StatesPanel>>,
+) {
+    let text_font = TextFont {
+        font_size: FONT_SIZE,
+        ..default()
+    };
+    for panel in &panels {
+        // Rebuilt when the report changes, or once the panel is created
+        if !report.is_changed() && !panel.is_changed() {
+            continue;
+        }
+        let Some(schedules) = panel.schedules else {
+            continue;
+        };
+        commands
+            .entity(schedules)
+            .despawn_descendants()
+            .with_children(|parent| {
+                for entry in &report.entries {
+                    parent.spawn((
+                        Text::new(entry.label.clone()),
+                        TextColor(LABEL_COLOR),
+                        text_font.clone(),
+                    ));
+                    for node in entry.sets.iter().chain(&entry.systems) {
+                        spawn_schedule_node(parent, node, &text_font);
+                    }
+                }
+            });
+    }
+}
+
+fn on_state_transition_click(
+    mut commands: Commands,
+    mut events: EventReader<ToolbarButtonClickedEvent>,
+    buttons: Query<&StateTransitionButton>,
+    controls: Res<StateControls>,
+) {
+    for event in events.read() {
+        let Ok(button) = buttons.get(event.entity) else {
+            continue;
+        };
+        let Some(control) = controls.0.get(button.state) else {
+            continue;
+        };
+        let queue = control.queue;
+        let variant = button.variant.clone();
+        commands.queue(move |world: &mut World| queue(world, &variant));
+    }
+}
@@ -0,0 +1,439 @@
+use std::time::Duration;
+
+use bevy::color::ColorToComponents;
+use bevy::ecs::event::EventCursor;
+use bevy::input::keyboard::{Key, KeyboardInput, NativeKey, NativeKeyCode};
+use bevy::input::ButtonState;
+use bevy::picking::backend::HitData;
+use bevy::picking::pointer::{Location, PointerButton, PointerId};
+use bevy::prelude::*;
+use bevy::render::camera::{ManualTextureViewHandle, NormalizedRenderTarget};
+use serde::{Deserialize, Serialize};
+
+use crate::input_fields::{InputTextValue, NumericField, NumericFieldValue};
+
+/// Extension trait for [`App`] driving widgets in headless tests, without a window or a renderer.
+///
+/// Input is sent as [`KeyboardInput`] events and [`Pointer`] triggers, and is handled on the next
+/// [`App::update`]. The app needs [`InputPlugin`](bevy::input::InputPlugin) for keyboard input,
+/// and the plugins of the widgets under test.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::test_utils::WidgetTestExt;
+/// # use bevy_widgets::input_fields::builder::TextInputBuilder;
+/// fn type_in_field(app: &mut App) {
+///     let field = app.world_mut().spawn(TextInputBuilder::default().build()).id();
+///     app.click(field).advance_frames(1);
+///     app.type_text("hello").advance_frames(1);
+///     app.assert_field_text(field, "hello");
+/// }
+/// ```
+pub trait WidgetTestExt {
+    /// Runs `frames` updates of the app
+    fn advance_frames(&mut self, frames: usize) -> &mut Self;
+
+    /// Presses `key_code`, holding it until [`WidgetTestExt::release_key`], e.g. for modifiers
+    fn press_key(&mut self, key_code: KeyCode) -> &mut Self;
+
+    /// Releases a key pressed with [`WidgetTestExt::press_key`]
+    fn release_key(&mut self, key_code: KeyCode) -> &mut Self;
+
+    /// Presses and releases `key_code` within the next frame
+    fn tap_key(&mut self, key_code: KeyCode) -> &mut Self;
+
+    /// Types every character of `text` within the next frame
+    fn type_text(&mut self, text: &str) -> &mut Self;
+
+    /// Moves the mouse over `entity`
+    fn hover(&mut self, entity: Entity) -> &mut Self;
+
+    /// Moves the mouse out of `entity`
+    fn unhover(&mut self, entity: Entity) -> &mut Self;
+
+    /// Presses, releases and clicks the primary mouse button on `entity`
+    fn click(&mut self, entity: Entity) -> &mut Self;
+
+    /// Drags `entity` by `delta` logical pixels with the primary mouse button, in a single move
+    fn drag(&mut self, entity: Entity, delta: Vec2) -> &mut Self;
+
+    /// Text of the text or numeric field `entity`
+    fn field_text(&self, entity: Entity) -> String;
+
+    /// Value of the numeric field `entity`
+    fn numeric_value<T: NumericFieldValue>(&self, entity: Entity) -> T;
+
+    /// Panics with both texts when the field `entity` doesn't contain `expected`
+    fn assert_field_text(&self, entity: Entity, expected: &str) -> &Self;
+
+    /// Events of type `E` sent since the last call for this type
+    fn drain_events<E: Event + Clone>(&mut self) -> Vec<E>;
+
+    /// Style of the widget subtree `entity`, see [`StyleSnapshot`]
+    fn style_snapshot(&self, entity: Entity) -> StyleSnapshot;
+}
+
+impl WidgetTestExt for App {
+    fn advance_frames(&mut self, frames: usize) -> &mut Self {
+        for _ in 0..frames {
+            self.update();
+        }
+        self
+    }
+
+    fn press_key(&mut self, key_code: KeyCode) -> &mut Self {
+        send_key(self.world_mut(), key_code, logical_key(key_code), true);
+        self
+    }
+
+    fn release_key(&mut self, key_code: KeyCode) -> &mut Self {
+        send_key(self.world_mut(), key_code, logical_key(key_code), false);
+        self
+    }
+
+    fn tap_key(&mut self, key_code: KeyCode) -> &mut Self {
+        self.press_key(key_code).release_key(key_code)
+    }
+
+    fn type_text(&mut self, text: &str) -> &mut Self {
+        for character in text.chars() {
+            let key_code = character_key_code(character);
+            let key = if character == ' ' {
+                Key::Space
+            } else {
+                Key::Character(character.to_string().into())
+            };
+            send_key(self.world_mut(), key_code, key.clone(), true);
+            send_key(self.world_mut(), key_code, key, false);
+        }
+        self
+    }
+
+    fn hover(&mut self, entity: Entity) -> &mut Self {
+        trigger_pointer(self.world_mut(), entity, Over { hit: hit() });
+        self
+    }
+
+    fn unhover(&mut self, entity: Entity) -> &mut Self {
+        trigger_pointer(self.world_mut(), entity, Out { hit: hit() });
+        self
+    }
+
+    fn click(&mut self, entity: Entity) -> &mut Self {
+        let button = PointerButton::Primary;
+        let world = self.world_mut();
+        trigger_pointer(world, entity, Down { button, hit: hit() });
+        trigger_pointer(world, entity, Up { button, hit: hit() });
+        trigger_pointer(
+            world,
+            entity,
+            Click {
+                button,
+                hit: hit(),
+                duration: Duration::ZERO,
+            },
+        );
+        self
+    }
+
+    fn drag(&mut self, entity: Entity, delta: Vec2) -> &mut Self {
+        let button = PointerButton::Primary;
+        let world = self.world_mut();
+        trigger_pointer(world, entity, Down { button, hit: hit() });
+        trigger_pointer(world, entity, DragStart { button, hit: hit() });
+        trigger_pointer(
+            world,
+            entity,
+            Drag {
+                button,
+                distance: delta,
+                delta,
+            },
+        );
+        trigger_pointer(
+            world,
+            entity,
+            DragEnd {
+                button,
+                distance: delta,
+            },
+        );
+        trigger_pointer(world, entity, Up { button, hit: hit() });
+        self
+    }
+
+    fn field_text(&self, entity: Entity) -> String {
+        self.world()
+            .get::<InputTextValue>(entity)
+            .unwrap_or_else(|| panic!("{entity} is not a text or numeric field"))
+            .0
+            .clone()
+    }
+
+    fn numeric_value<T: NumericFieldValue>(&self, entity: Entity) -> T {
+        self.world()
+            .get::<NumericField<T>>(entity)
+            .unwrap_or_else(|| {
+                panic!(
+                    "{entity} is not a numeric field of `{}`",
+                    std::any::type_name::<T>()
+                )
+            })
+            .get_value()
+    }
+
+    fn assert_field_text(&self, entity: Entity, expected: &str) -> &Self {
+        let text = self.field_text(entity);
+        assert_eq!(text, expected, "unexpected text in field {entity}");
+        self
+    }
+
+    fn drain_events<E: Event + Clone>(&mut self) -> Vec<E> {
+        let world = self.world_mut();
+        let mut cursor = world
+            .remove_resource::<TestEventCursor<E>>()
+            .unwrap_or_default();
+        let events = cursor
+            .0
+            .read(world.resource::<Events<E>>())
+            .cloned()
+            .collect();
+        world.insert_resource(cursor);
+        events
+    }
+
+    fn style_snapshot(&self, entity: Entity) -> StyleSnapshot {
+        StyleSnapshot::capture(self.world(), entity)
+            .unwrap_or_else(|| panic!("{entity} doesn't exist"))
+    }
+}
+
+/// Serializable style of a node and its descendants, used to assert visual states without rendering.
+///
+/// Colors are sRGBA rounded to 3 decimals, sizes are the declared [`Val`]s, and the computed size is
+/// only set once the layout ran. Compare snapshots directly, or their [`StyleSnapshot::to_ron`] text
+/// against a stored file:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::test_utils::WidgetTestExt;
+/// fn assert_hover_style(app: &mut App, button: Entity) {
+///     let idle = app.style_snapshot(button);
+///     app.hover(button).advance_frames(1);
+///     assert_ne!(app.style_snapshot(button).background, idle.background);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StyleSnapshot {
+    /// [`Name`] of the node
+    pub name: Option<String>,
+    /// Declared width
+    pub width: String,
+    /// Declared height
+    pub height: String,
+    /// Declared border widths, as left, right, top and bottom
+    pub border: [String; 4],
+    /// Size computed by the layout
+    pub computed_size: Option<[f32; 2]>,
+    /// [`BackgroundColor`]
+    pub background: Option<[f32; 4]>,
+    /// [`BorderColor`]
+    pub border_color: Option<[f32; 4]>,
+    /// Whether [`Visibility::Hidden`] is set
+    pub hidden: bool,
+    /// [`Text`] content
+    pub text: Option<String>,
+    /// [`TextColor`]
+    pub text_color: Option<[f32; 4]>,
+    /// [`TextFont`] size
+    pub font_size: Option<f32>,
+    /// Snapshots of the children
+    pub children: Vec<Self>,
+}
+
+impl StyleSnapshot {
+    /// Captures the style of `entity` and its descendants, `None` when it doesn't exist
+    pub fn capture(world: &World, entity: Entity) -> Option<Self> {
+        let entity = world.get_entity(entity).ok()?;
+        let node = entity.get::<Node>();
+        let val = |val: Option<Val>| val.map(|val| format!("{val:?}")).unwrap_or_default();
+        Some(Self {
+            name: entity.get::<Name>().map(|name| name.as_str().to_string()),
+            width: val(node.map(|node| node.width)),
+            height: val(node.map(|node| node.height)),
+            border: [
+                val(node.map(|node| node.border.left)),
+                val(node.map(|node| node.border.right)),
+                val(node.map(|node| node.border.top)),
+                val(node.map(|node| node.border.bottom)),
+            ],
+            computed_size: entity
+                .get::<ComputedNode>()
+                .map(|computed| computed.size())
+                .filter(|size| *size != Vec2::ZERO)
+                .map(|size| [round(size.x), round(size.y)]),
+            background: entity.get::<BackgroundColor>().map(|color| srgba(color.0)),
+            border_color: entity.get::<BorderColor>().map(|color| srgba(color.0)),
+            hidden: entity.get::<Visibility>() == Some(&Visibility::Hidden),
+            text: entity.get::<Text>().map(|text| text.0.clone()),
+            text_color: entity.get::<TextColor>().map(|color| srgba(color.0)),
+            font_size: entity.get::<TextFont>().map(|font| font.font_size),
+            children: entity
+                .get::<Children>()
+                .into_iter()
+                .flatten()
+                .filter_map(|child| Self::capture(world, *child))
+                .collect(),
+        })
+    }
+
+    /// Pretty RON text of the snapshot
+    pub fn to_ron(&self) -> String {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .expect("style snapshots always serialize")
+    }
+}
+
+fn round(value: f32) -> f32 {
+    (value * 1000.).round() / 1000.
+}
+
+fn srgba(color: Color) -> [f32; 4] {
+    Srgba::from(color).to_f32_array().map(round)
+}
+
+/// Events already returned by [`WidgetTestExt::drain_events`]
+#[derive(Resource)]
+struct TestEventCursor<E: Event>(EventCursor<E>);
+
+impl<E: Event> Default for TestEventCursor<E> {
+    fn default() -> Self {
+        Self(EventCursor::default())
+    }
+}
+
+fn send_key(world: &mut World, key_code: KeyCode, logical_key: Key, pressed: bool) {
+    world.send_event(KeyboardInput {
+        key_code,
+        logical_key,
+        state: if pressed {
+            ButtonState::Pressed
+        } else {
+            ButtonState::Released
+        },
+        repeat: false,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+fn logical_key(key_code: KeyCode) -> Key {
+    match key_code {
+        KeyCode::Enter | KeyCode::NumpadEnter => Key::Enter,
+        KeyCode::Tab => Key::Tab,
+        KeyCode::Space => Key::Space,
+        KeyCode::Backspace => Key::Backspace,
+        KeyCode::Delete => Key::Delete,
+        KeyCode::Escape => Key::Escape,
+        KeyCode::Home => Key::Home,
+        KeyCode::End => Key::End,
+        KeyCode::ArrowLeft => Key::ArrowLeft,
+        KeyCode::ArrowRight => Key::ArrowRight,
+        KeyCode::ArrowUp => Key::ArrowUp,
+        KeyCode::ArrowDown => Key::ArrowDown,
+        KeyCode::ShiftLeft | KeyCode::ShiftRight => Key::Shift,
+        KeyCode::ControlLeft | KeyCode::ControlRight => Key::Control,
+        KeyCode::AltLeft | KeyCode::AltRight => Key::Alt,
+        KeyCode::SuperLeft | KeyCode::SuperRight => Key::Super,
+        _ => character_of(key_code).map_or(Key::Unidentified(NativeKey::Unidentified), |c| {
+            Key::Character(c.to_string().into())
+        }),
+    }
+}
+
+const LETTER_KEYS: [KeyCode; 26] = [
+    KeyCode::KeyA,
+    KeyCode::KeyB,
+    KeyCode::KeyC,
+    KeyCode::KeyD,
+    KeyCode::KeyE,
+    KeyCode::KeyF,
+    KeyCode::KeyG,
+    KeyCode::KeyH,
+    KeyCode::KeyI,
+    KeyCode::KeyJ,
+    KeyCode::KeyK,
+    KeyCode::KeyL,
+    KeyCode::KeyM,
+    KeyCode::KeyN,
+    KeyCode::KeyO,
+    KeyCode::KeyP,
+    KeyCode::KeyQ,
+    KeyCode::KeyR,
+    KeyCode::KeyS,
+    KeyCode::KeyT,
+    KeyCode::KeyU,
+    KeyCode::KeyV,
+    KeyCode::KeyW,
+    KeyCode::KeyX,
+    KeyCode::KeyY,
+    KeyCode::KeyZ,
+];
+
+const DIGIT_KEYS: [KeyCode; 10] = [
+    KeyCode::Digit0,
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+    KeyCode::Digit8,
+    KeyCode::Digit9,
+];
+
+/// Lowercase character of letter and digit keys
+fn character_of(key_code: KeyCode) -> Option<char> {
+    if let Some(index) = LETTER_KEYS.iter().position(|key| *key == key_code) {
+        return Some((b'a' + index as u8) as char);
+    }
+    DIGIT_KEYS
+        .iter()
+        .position(|key| *key == key_code)
+        .map(|index| (b'0' + index as u8) as char)
+}
+
+/// Physical key typing `character` on a US layout, unidentified for symbols
+const fn character_key_code(character: char) -> KeyCode {
+    match character.to_ascii_lowercase() {
+        ' ' => KeyCode::Space,
+        '.' => KeyCode::Period,
+        ',' => KeyCode::Comma,
+        '-' => KeyCode::Minus,
+        c @ 'a'..='z' => LETTER_KEYS[(c as u8 - b'a') as usize],
+        c @ '0'..='9' => DIGIT_KEYS[(c as u8 - b'0') as usize],
+        _ => KeyCode::Unidentified(NativeKeyCode::Unidentified),
+    }
+}
+
+fn hit() -> HitData {
+    HitData::new(Entity::PLACEHOLDER, 0., None, None)
+}
+
+fn trigger_pointer<E: std::fmt::Debug + Clone + Reflect>(
+    world: &mut World,
+    target: Entity,
+    event: E,
+) {
+    world.trigger_targets(
+        Pointer {
+            target,
+            pointer_id: PointerId::Mouse,
+            pointer_location: Location {
+                target: NormalizedRenderTarget::TextureView(ManualTextureViewHandle(0)),
+                position: Vec2::ZERO,
+            },
+            event,
+        },
+        target,
+    );
+    world.flush();
+}
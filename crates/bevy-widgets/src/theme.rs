@@ -0,0 +1,214 @@
+use std::marker::PhantomData;
+
+use bevy::color::Luminance;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+const HIGH_CONTRAST_ACCENT_COLOR: Color = Color::srgb(1.0, 0.86, 0.0);
+/// Relative luminance above which a design system color counts as light
+const LIGHT_LUMINANCE: f32 = 0.3;
+
+/// Plugin containing the theme variants logic
+pub struct ThemePlugin;
+
+impl Plugin for ThemePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WidgetTheme>()
+            .register_type::<WidgetTheme>()
+            .add_systems(
+                PostUpdate,
+                (
+                    apply_contrast::<BackgroundColor>,
+                    apply_contrast::<BorderColor>,
+                    apply_contrast::<TextColor>,
+                )
+                    // Transitions animate colors before the layout
+                    .after(UiSystem::Layout),
+            );
+    }
+}
+
+/// Theme of the widgets, can be changed at runtime for accessibility.
+///
+/// - `variant` swaps the design system colors of every UI node for a high contrast palette.
+/// - `reduced_motion` snaps [`crate::transition::Transition`]s, keeps text cursors solid instead of
+///   blinking and stops spinners.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+#[reflect(Resource)]
+pub struct WidgetTheme {
+    /// Color palette of the widgets
+    pub variant: ThemeVariant,
+    /// Disables animations
+    pub reduced_motion: bool,
+}
+
+impl WidgetTheme {
+    /// Theme with the `variant` palette
+    pub const fn new(variant: ThemeVariant) -> Self {
+        Self {
+            variant,
+            reduced_motion: false,
+        }
+    }
+
+    /// Disables animations
+    pub const fn with_reduced_motion(mut self) -> Self {
+        self.reduced_motion = true;
+        self
+    }
+
+    /// Checks if transitions should snap, with reduced motion or a high contrast variant
+    pub const fn snaps_transitions(&self) -> bool {
+        self.reduced_motion || !matches!(self.variant, ThemeVariant::Standard)
+    }
+}
+
+/// Color palette of the widgets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+pub enum ThemeVariant {
+    /// Design system colors
+    #[default]
+    Standard,
+    /// Black and white, light backgrounds stay white and dark ones turn black, with black borders
+    HighContrast,
+    /// Black backgrounds, white text and yellow borders
+    HighContrastDark,
+}
+
+/// What a color paints, which decides its high contrast replacement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum ColorRole {
+    /// Node background
+    Background,
+    /// Node border
+    Border,
+    /// Text
+    Text,
+}
+
+impl ThemeVariant {
+    /// Color displayed in this variant for a design system `color`, transparency is kept
+    ///
+    /// ```
+    /// # use bevy::color::Color;
+    /// # use bevy_widgets::theme::{ColorRole, ThemeVariant};
+    /// let light_blue = Color::srgb(0.93, 0.97, 1.0);
+    /// assert_eq!(ThemeVariant::HighContrast.color(ColorRole::Background, light_blue), Color::WHITE);
+    /// assert_eq!(ThemeVariant::HighContrastDark.color(ColorRole::Background, light_blue), Color::BLACK);
+    /// ```
+    pub fn color(self, role: ColorRole, color: Color) -> Color {
+        let alpha = color.alpha();
+        if alpha == 0. {
+            return color;
+        }
+        let light = color.luminance() > LIGHT_LUMINANCE;
+        let mapped = match (self, role) {
+            (Self::Standard, _) => return color,
+            (Self::HighContrast, ColorRole::Border) => Color::BLACK,
+            (Self::HighContrast, _) if light => Color::WHITE,
+            (Self::HighContrast, _) => Color::BLACK,
+            (Self::HighContrastDark, ColorRole::Background) => Color::BLACK,
+            (Self::HighContrastDark, ColorRole::Border) => HIGH_CONTRAST_ACCENT_COLOR,
+            (Self::HighContrastDark, ColorRole::Text) => Color::WHITE,
+        };
+        mapped.with_alpha(alpha)
+    }
+}
+
+/// Color components replaced by the high contrast variants
+trait ThemedColor: Component {
+    const ROLE: ColorRole;
+
+    fn color(&self) -> Color;
+
+    fn set_color(&mut self, color: Color);
+}
+
+impl ThemedColor for BackgroundColor {
+    const ROLE: ColorRole = ColorRole::Background;
+
+    fn color(&self) -> Color {
+        self.0
+    }
+
+    fn set_color(&mut self, color: Color) {
+        self.0 = color;
+    }
+}
+
+impl ThemedColor for BorderColor {
+    const ROLE: ColorRole = ColorRole::Border;
+
+    fn color(&self) -> Color {
+        self.0
+    }
+
+    fn set_color(&mut self, color: Color) {
+        self.0 = color;
+    }
+}
+
+impl ThemedColor for TextColor {
+    const ROLE: ColorRole = ColorRole::Text;
+
+    fn color(&self) -> Color {
+        self.0
+    }
+
+    fn set_color(&mut self, color: Color) {
+        self.0 = color;
+    }
+}
+
+/// Design system color of an entity displayed with a high contrast variant
+#[derive(Component, Debug)]
+struct ContrastColor<C: ThemedColor> {
+    original: Color,
+    shown: Color,
+    marker: PhantomData<C>,
+}
+
+fn apply_contrast<C: ThemedColor>(
+    mut commands: Commands,
+    theme: Res<WidgetTheme>,
+    mut colors: Query<(Entity, &mut C, Option<&mut ContrastColor<C>>)>,
+) {
+    for (entity, mut color, contrast) in &mut colors {
+        if !theme.is_changed() && !color.is_changed() {
+            continue;
+        }
+        let current = color.color();
+        // A color set by a widget since the last mapping replaces the original one
+        let original = match &contrast {
+            Some(contrast) if contrast.shown == current => contrast.original,
+            _ => current,
+        };
+
+        if theme.variant == ThemeVariant::Standard {
+            if contrast.is_some() {
+                commands.entity(entity).remove::<ContrastColor<C>>();
+                if current != original {
+                    color.set_color(original);
+                }
+            }
+            continue;
+        }
+
+        let shown = theme.variant.color(C::ROLE, original);
+        if current != shown {
+            color.set_color(shown);
+        }
+        if let Some(mut contrast) = contrast {
+            if contrast.original != original || contrast.shown != shown {
+                contrast.original = original;
+                contrast.shown = shown;
+            }
+        } else {
+            commands.entity(entity).insert(ContrastColor::<C> {
+                original,
+                shown,
+                marker: PhantomData,
+            });
+        }
+    }
+}
@@ -0,0 +1,286 @@
+use bevy::prelude::*;
+use bevy::time::TimeSystem;
+
+use crate::input_fields::{
+    builder::NumericFieldBuilder, InputFieldSize, NumericField, TextFieldExt,
+};
+use crate::toolbar::{
+    ToolbarBuilder, ToolbarButton, ToolbarButtonBuilder, ToolbarButtonClickedEvent,
+};
+use crate::WidgetsSystems;
+
+const PAUSE_ID: &str = "time.pause";
+const STEP_ID: &str = "time.step";
+const MAX_SPEED: f32 = 16.0;
+const SPEED_DRAG_STEP: f32 = 0.01;
+const SPEED_FIELD_WIDTH: f32 = 56.0;
+
+/// Plugin containing the time controls logic
+pub struct TimeControlsPlugin;
+
+impl Plugin for TimeControlsPlugin {
+    fn build(&self, app: &mut App) {
+        WidgetsSystems::configure(app);
+        app.init_resource::<TimeControls>()
+            .add_event::<TimeControlEvent>()
+            .add_observer(create_time_transport)
+            .add_systems(First, apply_time_controls.before(TimeSystem))
+            .add_systems(
+                Update,
+                (
+                    on_transport_click.in_set(WidgetsSystems::Events),
+                    on_speed_changed.in_set(WidgetsSystems::Events),
+                    sync_transports.after(WidgetsSystems::Events),
+                ),
+            );
+    }
+}
+
+/// Pause, single frame steps and speed of the virtual [`Time`], driven by [`TimeTransport`]s.
+///
+/// Changes are applied to [`Time<Virtual>`] at the start of the next frame, and each applied change
+/// sends a [`TimeControlEvent`]. Game systems can respect the editor pause with [`time_running`].
+/// Widgets animate with [`Time<Real>`], so the editor UI keeps its pace while the game is paused or sped up.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct TimeControls {
+    paused: bool,
+    speed: f32,
+    step: StepState,
+    /// Values applied to the virtual time
+    applied: (bool, f32),
+}
+
+/// Progress of a single frame step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StepState {
+    Idle,
+    Requested,
+    Running,
+}
+
+impl Default for TimeControls {
+    fn default() -> Self {
+        Self {
+            paused: false,
+            speed: 1.,
+            step: StepState::Idle,
+            applied: (false, 1.),
+        }
+    }
+}
+
+impl TimeControls {
+    /// Whether the virtual time is paused
+    pub const fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Whether game systems should run this frame: unpaused, or stepping a paused time
+    pub fn is_running(&self) -> bool {
+        !self.paused || self.step == StepState::Running
+    }
+
+    /// Relative speed of the virtual time
+    pub const fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Pauses the virtual time
+    pub const fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resumes the virtual time
+    pub const fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Advances a paused virtual time by one frame, does nothing while running
+    pub fn step(&mut self) {
+        if self.paused && self.step == StepState::Idle {
+            self.step = StepState::Requested;
+        }
+    }
+
+    /// Sets the relative speed of the virtual time, negative speeds are clamped to `0`
+    pub const fn set_speed(&mut self, speed: f32) {
+        self.speed = speed.max(0.);
+    }
+}
+
+/// Run condition true while [`TimeControls`] isn't paused, and for single frame steps.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::time_controls::time_running;
+/// # fn move_enemies() {}
+/// App::new().add_systems(Update, move_enemies.run_if(time_running));
+/// ```
+pub fn time_running(controls: Option<Res<TimeControls>>) -> bool {
+    controls.is_none_or(|controls| controls.is_running())
+}
+
+/// An event that is fired when [`TimeControls`] changes the virtual [`Time`].
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub enum TimeControlEvent {
+    /// The virtual time was paused
+    Paused,
+    /// The virtual time was resumed
+    Resumed,
+    /// A paused virtual time advances by one frame
+    Stepped,
+    /// The relative speed of the virtual time changed
+    SpeedChanged(f32),
+}
+
+/// Toolbar with a pause toggle, a single frame step button and a speed field controlling [`TimeControls`]
+#[derive(Component, Debug, Clone, Copy, Default)]
+#[require(Node)]
+pub struct TimeTransport {
+    pause: Option<Entity>,
+    speed: Option<Entity>,
+}
+
+/// Speed field of a [`TimeTransport`]
+#[derive(Component, Debug, Clone, Copy)]
+struct TimeSpeedField;
+
+fn apply_time_controls(
+    mut controls: ResMut<TimeControls>,
+    mut time: ResMut<Time<Virtual>>,
+    mut events: EventWriter<TimeControlEvent>,
+) {
+    let controls = controls.bypass_change_detection();
+    match controls.step {
+        StepState::Requested => {
+            time.unpause();
+            controls.step = StepState::Running;
+            events.send(TimeControlEvent::Stepped);
+            return;
+        }
+        StepState::Running => {
+            time.pause();
+            controls.step = StepState::Idle;
+        }
+        StepState::Idle => {}
+    }
+
+    let (paused, speed) = controls.applied;
+    if controls.paused != paused {
+        if controls.paused {
+            time.pause();
+            events.send(TimeControlEvent::Paused);
+        } else {
+            time.unpause();
+            events.send(TimeControlEvent::Resumed);
+        }
+    }
+    if controls.speed != speed {
+        time.set_relative_speed(controls.speed);
+        events.send(TimeControlEvent::SpeedChanged(controls.speed));
+    }
+    controls.applied = (controls.paused, controls.speed);
+}
+
+fn create_time_transport(
+    trigger: Trigger<OnAdd, TimeTransport>,
+    mut commands: Commands,
+    mut transports: Query<&mut TimeTransport>,
+    controls: Res<TimeControls>,
+) {
+    let entity = trigger.entity();
+    let Ok(mut transport) = transports.get_mut(entity) else {
+        return;
+    };
+
+    let toolbar = commands.spawn(ToolbarBuilder::default().build()).id();
+    let pause = ToolbarButtonBuilder::new(PAUSE_ID)
+        .with_label("Pause")
+        .toggle()
+        .pressed(controls.paused)
+        .build(&mut commands, toolbar);
+    ToolbarButtonBuilder::new(STEP_ID)
+        .with_label("Step")
+        .build(&mut commands, toolbar);
+    let speed = commands
+        .spawn((
+            TimeSpeedField,
+            NumericFieldBuilder::<f32>::default()
+                .with_initial_value(controls.speed)
+                .with_range(0.0..=MAX_SPEED)
+                .with_drag_step(SPEED_DRAG_STEP)
+                .with_size(InputFieldSize::Small)
+                .with_fixed_width(SPEED_FIELD_WIDTH)
+                .build(),
+        ))
+        .id();
+    commands.entity(toolbar).add_child(speed);
+    commands.entity(entity).add_child(toolbar);
+    transport.pause = Some(pause);
+    transport.speed = Some(speed);
+}
+
+fn on_transport_click(
+    mut events: EventReader<ToolbarButtonClickedEvent>,
+    parents: Query<&Parent>,
+    transports: Query<(), With<TimeTransport>>,
+    mut controls: ResMut<TimeControls>,
+) {
+    for event in events.read() {
+        let in_transport = parents
+            .iter_ancestors(event.entity)
+            .any(|ancestor| transports.contains(ancestor));
+        if !in_transport {
+            continue;
+        }
+        match event.id.as_str() {
+            PAUSE_ID if event.pressed => controls.pause(),
+            PAUSE_ID => controls.resume(),
+            STEP_ID => controls.step(),
+            _ => {}
+        }
+    }
+}
+
+fn on_speed_changed(
+    fields: Query<&NumericField<f32>, (With<TimeSpeedField>, Changed<NumericField<f32>>)>,
+    mut controls: ResMut<TimeControls>,
+) {
+    for field in &fields {
+        if field.get_value() != controls.speed {
+            controls.set_speed(field.get_value());
+        }
+    }
+}
+
+// Reflects changes made by game code or other transports
+fn sync_transports(
+    mut commands: Commands,
+    controls: Res<TimeControls>,
+    transports: Query<&TimeTransport>,
+    mut buttons: Query<&mut ToolbarButton>,
+    fields: Query<&NumericField<f32>>,
+) {
+    if !controls.is_changed() {
+        return;
+    }
+    for transport in &transports {
+        if let Some(mut button) = transport
+            .pause
+            .and_then(|pause| buttons.get_mut(pause).ok())
+        {
+            if button.pressed != controls.paused {
+                button.pressed = controls.paused;
+            }
+        }
+        let Some(speed) = transport.speed else {
+            continue;
+        };
+        if fields
+            .get(speed)
+            .is_ok_and(|field| field.get_value() != controls.speed)
+        {
+            commands.set_text(speed, controls.speed.to_string());
+        }
+    }
+}
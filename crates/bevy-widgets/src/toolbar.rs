@@ -0,0 +1,338 @@
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::picking::focus::HoverMap;
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+
+use crate::focus::{stop_propagation, EventPropagation};
+
+const TOOLBAR_COLOR: Color = Color::srgb(0.13, 0.13, 0.14);
+const BUTTON_COLOR: Color = Color::NONE;
+const HOVERED_BUTTON_COLOR: Color = Color::srgb(0.11, 0.29, 0.42);
+const PRESSED_BUTTON_COLOR: Color = Color::srgb(0.19, 0.49, 0.71);
+const BUTTON_TEXT_COLOR: Color = Color::srgb(0.97, 0.97, 0.98);
+const SEPARATOR_COLOR: Color = Color::srgb(0.29, 0.31, 0.33);
+const FONT_SIZE: f32 = 10.0;
+const BUTTON_SIZE: f32 = 24.0;
+const ICON_SIZE: f32 = 16.0;
+const TOOLBAR_PADDING: f32 = 4.0;
+const SCROLL_LINE_HEIGHT: f32 = 20.0;
+
+/// Plugin containing the toolbar logic
+pub struct ToolbarPlugin;
+
+impl Plugin for ToolbarPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ToolbarButtonClickedEvent>()
+            .register_type::<ToolbarOverflow>()
+            .register_type::<ToolbarButton>()
+            .add_observer(on_toolbar_button_click)
+            .add_systems(Update, (toolbar_button_style, scroll_toolbars));
+    }
+}
+
+/// How a [`Toolbar`] behaves when narrower than its content
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum ToolbarOverflow {
+    /// Buttons wrap to new rows
+    #[default]
+    Wrap,
+    /// Buttons stay in a single row, scrolled with the mouse wheel
+    Scroll,
+}
+
+/// Horizontal container of [`ToolbarButton`]s and [`ToolbarSeparator`]s.
+/// Should be created with [`ToolbarBuilder`].
+#[derive(Component, Debug, Clone, Copy, Default)]
+#[require(Node)]
+pub struct Toolbar {
+    /// Overflow behavior
+    pub overflow: ToolbarOverflow,
+}
+
+/// Builder for [`Toolbar`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ToolbarBuilder {
+    overflow: ToolbarOverflow,
+}
+
+impl ToolbarBuilder {
+    /// Sets the overflow behavior. Defaults to [`ToolbarOverflow::Wrap`].
+    pub const fn with_overflow(mut self, overflow: ToolbarOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Builds the toolbar container, items should be spawned as its children
+    pub fn build(self) -> (Toolbar, Node, BackgroundColor, ScrollPosition) {
+        let scroll = self.overflow == ToolbarOverflow::Scroll;
+        (
+            Toolbar {
+                overflow: self.overflow,
+            },
+            Node {
+                width: Val::Percent(100.),
+                flex_direction: FlexDirection::Row,
+                flex_wrap: if scroll {
+                    FlexWrap::NoWrap
+                } else {
+                    FlexWrap::Wrap
+                },
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(2.),
+                row_gap: Val::Px(2.),
+                padding: UiRect::all(Val::Px(TOOLBAR_PADDING)),
+                overflow: if scroll {
+                    Overflow::scroll_x()
+                } else {
+                    Overflow::visible()
+                },
+                ..default()
+            },
+            BackgroundColor(TOOLBAR_COLOR),
+            ScrollPosition::default(),
+        )
+    }
+}
+
+/// A button of a [`Toolbar`].
+///
+/// Toggle buttons stay highlighted while pressed.
+/// Buttons sharing a group in the same toolbar are toggles where only one is pressed at a time.
+#[derive(Component, Debug, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+#[require(Interaction)]
+pub struct ToolbarButton {
+    /// Identifier sent in [`ToolbarButtonClickedEvent`]
+    pub id: String,
+    /// Keeps the pressed state between clicks
+    pub toggle: bool,
+    /// Mutually exclusive group
+    pub group: Option<String>,
+    /// Pressed state of toggle buttons
+    pub pressed: bool,
+}
+
+/// Thin divider between groups of toolbar buttons
+#[derive(Component, Debug, Clone, Copy, Default)]
+#[require(Node)]
+pub struct ToolbarSeparator;
+
+impl ToolbarSeparator {
+    /// Separator bundle, spawn it as a child of a [`Toolbar`]
+    pub fn bundle() -> (Self, Node, BackgroundColor) {
+        (
+            Self,
+            Node {
+                width: Val::Px(1.),
+                height: Val::Px(BUTTON_SIZE - 8.),
+                margin: UiRect::horizontal(Val::Px(4.)),
+                ..default()
+            },
+            BackgroundColor(SEPARATOR_COLOR),
+        )
+    }
+}
+
+/// An event that is fired when a [`ToolbarButton`] is clicked.
+#[derive(Event, Debug, Clone, Reflect)]
+pub struct ToolbarButtonClickedEvent {
+    /// The button entity
+    pub entity: Entity,
+    /// Identifier of the button
+    pub id: String,
+    /// Pressed state after the click, always `false` for non toggle buttons
+    pub pressed: bool,
+}
+
+/// Builder for [`ToolbarButton`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToolbarButtonBuilder {
+    id: String,
+    label: Option<String>,
+    icon: Option<Handle<Image>>,
+    toggle: bool,
+    group: Option<String>,
+    pressed: bool,
+}
+
+impl ToolbarButtonBuilder {
+    /// Creates a button builder identified by `id`
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            ..default()
+        }
+    }
+
+    /// Text shown in the button
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Icon shown in the button, before the label
+    pub fn with_icon(mut self, icon: Handle<Image>) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Makes the button a toggle
+    pub const fn toggle(mut self) -> Self {
+        self.toggle = true;
+        self
+    }
+
+    /// Makes the button a toggle of the mutually exclusive `group`
+    pub fn in_group(mut self, group: impl Into<String>) -> Self {
+        self.toggle = true;
+        self.group = Some(group.into());
+        self
+    }
+
+    /// Initial pressed state of a toggle
+    pub const fn pressed(mut self, pressed: bool) -> Self {
+        self.pressed = pressed;
+        self
+    }
+
+    /// Spawns the button as a child of `toolbar` returning its entity id
+    pub fn build(self, commands: &mut Commands, toolbar: Entity) -> Entity {
+        let button = commands
+            .spawn((
+                ToolbarButton {
+                    id: self.id,
+                    toggle: self.toggle,
+                    group: self.group,
+                    pressed: self.toggle && self.pressed,
+                },
+                Button,
+                Node {
+                    min_width: Val::Px(BUTTON_SIZE),
+                    height: Val::Px(BUTTON_SIZE),
+                    padding: UiRect::horizontal(Val::Px(4.)),
+                    flex_shrink: 0.,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(4.),
+                    ..default()
+                },
+                BackgroundColor(BUTTON_COLOR),
+                BorderRadius::all(Val::Px(4.)),
+            ))
+            .with_children(|parent| {
+                if let Some(icon) = self.icon {
+                    parent.spawn((
+                        ImageNode::new(icon),
+                        Node {
+                            width: Val::Px(ICON_SIZE),
+                            height: Val::Px(ICON_SIZE),
+                            ..default()
+                        },
+                        PickingBehavior::IGNORE,
+                    ));
+                }
+                if let Some(label) = self.label {
+                    parent.spawn((
+                        Text::new(label),
+                        TextColor(BUTTON_TEXT_COLOR),
+                        TextFont {
+                            font_size: FONT_SIZE,
+                            ..default()
+                        },
+                        PickingBehavior::IGNORE,
+                    ));
+                }
+            })
+            .id();
+        commands.entity(toolbar).add_child(button);
+        button
+    }
+}
+
+fn toolbar_button_style(
+    mut buttons: Query<
+        (&ToolbarButton, &Interaction, &mut BackgroundColor),
+        Or<(Changed<ToolbarButton>, Changed<Interaction>)>,
+    >,
+) {
+    for (button, interaction, mut color) in &mut buttons {
+        color.0 = match interaction {
+            _ if button.pressed => PRESSED_BUTTON_COLOR,
+            Interaction::Pressed | Interaction::Hovered => HOVERED_BUTTON_COLOR,
+            Interaction::None => BUTTON_COLOR,
+        };
+    }
+}
+
+fn on_toolbar_button_click(
+    mut trigger: Trigger<Pointer<Click>>,
+    mut buttons: Query<&mut ToolbarButton>,
+    parents: Query<&Parent>,
+    children: Query<&Children>,
+    mut event_writer: EventWriter<ToolbarButtonClickedEvent>,
+    propagation: Query<&EventPropagation>,
+) {
+    let entity = trigger.entity();
+    let Ok(mut button) = buttons.get_mut(entity) else {
+        return;
+    };
+    stop_propagation(&mut trigger, &propagation);
+
+    if button.group.is_some() {
+        button.pressed = true;
+    } else if button.toggle {
+        button.pressed = !button.pressed;
+    }
+    let id = button.id.clone();
+    let pressed = button.pressed;
+
+    if let Some(group) = button.group.clone() {
+        let siblings = parents
+            .get(entity)
+            .and_then(|parent| children.get(parent.get()))
+            .map(|children| children.to_vec())
+            .unwrap_or_default();
+        let mut iter = buttons.iter_many_mut(&siblings);
+        while let Some(mut sibling) = iter.fetch_next() {
+            if sibling.group.as_ref() == Some(&group) && sibling.id != id && sibling.pressed {
+                sibling.pressed = false;
+            }
+        }
+    }
+
+    event_writer.send(ToolbarButtonClickedEvent {
+        entity,
+        id,
+        pressed,
+    });
+}
+
+fn scroll_toolbars(
+    mut mouse_wheel: EventReader<MouseWheel>,
+    hover_map: Res<HoverMap>,
+    parents: Query<&Parent>,
+    mut toolbars: Query<(&Toolbar, &mut ScrollPosition)>,
+) {
+    for event in mouse_wheel.read() {
+        let delta = match event.unit {
+            MouseScrollUnit::Line => (event.x + event.y) * SCROLL_LINE_HEIGHT,
+            MouseScrollUnit::Pixel => event.x + event.y,
+        };
+        let hovered: HashSet<Entity> = hover_map
+            .values()
+            .flat_map(|hits| hits.keys())
+            .filter_map(|entity| {
+                std::iter::once(*entity)
+                    .chain(parents.iter_ancestors(*entity))
+                    .find(|ancestor| toolbars.contains(*ancestor))
+            })
+            .collect();
+        for toolbar in hovered {
+            if let Ok((toolbar, mut scroll)) = toolbars.get_mut(toolbar) {
+                if toolbar.overflow == ToolbarOverflow::Scroll {
+                    scroll.offset_x = (scroll.offset_x - delta).max(0.);
+                }
+            }
+        }
+    }
+}
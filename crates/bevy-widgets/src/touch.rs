@@ -0,0 +1,178 @@
+use bevy::input::touch::Touches;
+use bevy::picking::{focus::HoverMap, pointer::PointerId};
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::{focus::Clickable, WidgetsSystems};
+
+const DEFAULT_MIN_HIT_SIZE: f32 = 44.;
+const DEFAULT_LONG_PRESS_DURATION: f32 = 0.5;
+const DEFAULT_LONG_PRESS_SLOP: f32 = 10.;
+
+/// Plugin containing the touch input logic
+pub struct TouchPlugin;
+
+impl Plugin for TouchPlugin {
+    fn build(&self, app: &mut App) {
+        WidgetsSystems::configure(app);
+        app.init_resource::<TouchMode>()
+            .register_type::<LongPress>()
+            .add_systems(
+                Update,
+                (
+                    detect_long_press.in_set(WidgetsSystems::Input),
+                    apply_touch_hit_targets.in_set(WidgetsSystems::Style),
+                ),
+            );
+    }
+}
+
+/// Touch settings of the widgets.
+///
+/// Taps and touch drags work like mouse clicks and drags without any setting,
+/// enable the touch mode to make interactive widgets large enough for fingers on mobile and tablet builds.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct TouchMode {
+    /// Enlarges every interactive widget to at least `min_hit_size`. Defaults to `false`.
+    pub enabled: bool,
+    /// Minimum width and height of interactive widgets in touch mode, in logical pixels
+    pub min_hit_size: f32,
+    /// Seconds a touch must be held to trigger a [`LongPress`]
+    pub long_press_duration: f32,
+    /// Distance a held touch can move, in logical pixels, and still trigger a [`LongPress`]
+    pub long_press_slop: f32,
+}
+
+impl Default for TouchMode {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_hit_size: DEFAULT_MIN_HIT_SIZE,
+            long_press_duration: DEFAULT_LONG_PRESS_DURATION,
+            long_press_slop: DEFAULT_LONG_PRESS_SLOP,
+        }
+    }
+}
+
+impl TouchMode {
+    /// Touch mode enabled with the default settings
+    pub fn enabled() -> Self {
+        Self {
+            enabled: true,
+            ..default()
+        }
+    }
+}
+
+/// Triggered on the entity under a touch held in place for [`TouchMode::long_press_duration`],
+/// propagating to its ancestors.
+/// > Text fields select the word under the touch.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct LongPress {
+    /// Position of the touch in the window
+    pub position: Vec2,
+}
+
+impl Event for LongPress {
+    type Traversal = &'static Parent;
+
+    const AUTO_PROPAGATE: bool = true;
+}
+
+/// Size constraints of a widget before the touch mode enlarged it
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+struct TouchHitTarget {
+    min_width: Val,
+    min_height: Val,
+}
+
+/// Start time of a held touch, and whether it already triggered a [`LongPress`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct HeldTouch {
+    since: f32,
+    triggered: bool,
+}
+
+fn detect_long_press(
+    mut commands: Commands,
+    mut held: Local<HashMap<u64, HeldTouch>>,
+    touches: Option<Res<Touches>>,
+    time: Res<Time<Real>>,
+    mode: Res<TouchMode>,
+    hover_map: Res<HoverMap>,
+) {
+    let Some(touches) = touches else {
+        return;
+    };
+    let now = time.elapsed_secs();
+    held.retain(|id, _| touches.get_pressed(*id).is_some());
+
+    for touch in touches.iter() {
+        let held = held.entry(touch.id()).or_insert(HeldTouch {
+            since: now,
+            triggered: false,
+        });
+        if held.triggered || now - held.since < mode.long_press_duration {
+            continue;
+        }
+        // Moving the touch makes it a drag instead
+        if touch.start_position().distance(touch.position()) > mode.long_press_slop {
+            held.triggered = true;
+            continue;
+        }
+        let target = hover_map
+            .get(&PointerId::Touch(touch.id()))
+            .and_then(|hits| {
+                hits.iter()
+                    .min_by(|(_, a), (_, b)| a.depth.total_cmp(&b.depth))
+                    .map(|(entity, _)| *entity)
+            });
+        if let Some(target) = target {
+            commands.trigger_targets(
+                LongPress {
+                    position: touch.position(),
+                },
+                target,
+            );
+        }
+        held.triggered = true;
+    }
+}
+
+/// Raises `size` to at least `min` pixels, relative sizes are kept
+fn enlarge(size: Val, min: f32) -> Val {
+    match size {
+        Val::Px(px) if px >= min => size,
+        Val::Px(_) | Val::Auto => Val::Px(min),
+        _ => size,
+    }
+}
+
+fn apply_touch_hit_targets(
+    mut commands: Commands,
+    mode: Res<TouchMode>,
+    mut widgets: Query<
+        (Entity, &mut Node, Option<&TouchHitTarget>),
+        Or<(With<Clickable>, With<Interaction>)>,
+    >,
+    added: Query<(), Or<(Added<Clickable>, Added<Interaction>)>>,
+) {
+    for (entity, mut node, original) in &mut widgets {
+        if !mode.is_changed() && !added.contains(entity) {
+            continue;
+        }
+        if mode.enabled {
+            let original = original.copied().unwrap_or(TouchHitTarget {
+                min_width: node.min_width,
+                min_height: node.min_height,
+            });
+            node.min_width = enlarge(original.min_width, mode.min_hit_size);
+            node.min_height = enlarge(original.min_height, mode.min_hit_size);
+            commands.entity(entity).insert(original);
+        } else if let Some(original) = original {
+            node.min_width = original.min_width;
+            node.min_height = original.min_height;
+            commands.entity(entity).remove::<TouchHitTarget>();
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use bevy::math::curve::{Curve, EaseFunction, EasingCurve};
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+use crate::theme::WidgetTheme;
+
+const DEFAULT_DURATION: f32 = 0.15;
+
+/// Plugin containing the transition logic
+pub struct TransitionPlugin;
+
+impl Plugin for TransitionPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Transition>()
+            .add_systems(PostUpdate, animate_transitions.before(UiSystem::Layout));
+    }
+}
+
+/// Smooths style changes of a widget between interaction states.
+///
+/// When another system, like the button or text field styling, sets a new [`BackgroundColor`],
+/// [`BorderColor`] or pixel `width`/`height` of the [`Node`], the value is tweened from the displayed
+/// one over `duration` instead of snapping.
+/// > Transitions snap with [`WidgetTheme::reduced_motion`] or a high contrast variant.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+#[require(TransitionState)]
+pub struct Transition {
+    /// Duration of the tween in seconds
+    pub duration: f32,
+    /// Easing of the tween
+    pub easing: EaseFunction,
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Self {
+            duration: DEFAULT_DURATION,
+            easing: EaseFunction::CubicOut,
+        }
+    }
+}
+
+impl Transition {
+    /// Creates a transition lasting `duration` seconds
+    pub fn new(duration: f32) -> Self {
+        Self {
+            duration,
+            ..default()
+        }
+    }
+
+    /// Sets the easing. Defaults to [`EaseFunction::CubicOut`].
+    pub const fn with_easing(mut self, easing: EaseFunction) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    fn progress(&self, elapsed: f32) -> f32 {
+        if self.duration <= 0. {
+            return 1.;
+        }
+        EasingCurve::new(0., 1., self.easing).sample_clamped(elapsed / self.duration)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Tween<T> {
+    from: T,
+    to: T,
+    elapsed: f32,
+}
+
+/// Tween of a single property, `shown` is the last value written by the transition
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct Tweened<T> {
+    shown: Option<T>,
+    tween: Option<Tween<T>>,
+}
+
+impl<T: Copy + PartialEq> Tweened<T> {
+    /// Starts a tween when `value` was set by another system, returns the value to display
+    fn update(
+        &mut self,
+        value: T,
+        delta: f32,
+        transition: &Transition,
+        lerp: impl Fn(T, T, f32) -> Option<T>,
+    ) -> T {
+        match self.shown {
+            Some(shown) if shown != value => {
+                self.tween = Some(Tween {
+                    from: shown,
+                    to: value,
+                    elapsed: 0.,
+                });
+            }
+            None => self.shown = Some(value),
+            _ => {}
+        }
+        let Some(tween) = &mut self.tween else {
+            return value;
+        };
+        tween.elapsed += delta;
+        let t = transition.progress(tween.elapsed);
+        let shown = if t >= 1. {
+            tween.to
+        } else {
+            lerp(tween.from, tween.to, t).unwrap_or(tween.to)
+        };
+        if shown == tween.to {
+            self.tween = None;
+        }
+        self.shown = Some(shown);
+        shown
+    }
+}
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Default)]
+struct TransitionState {
+    background: Tweened<Color>,
+    border: Tweened<Color>,
+    width: Tweened<Val>,
+    height: Tweened<Val>,
+}
+
+fn lerp_color(from: Color, to: Color, t: f32) -> Option<Color> {
+    Some(LinearRgba::from(from).mix(&LinearRgba::from(to), t).into())
+}
+
+fn lerp_val(from: Val, to: Val, t: f32) -> Option<Val> {
+    match (from, to) {
+        (Val::Px(from), Val::Px(to)) => Some(Val::Px(from.lerp(to, t))),
+        _ => None,
+    }
+}
+
+fn animate_transitions(
+    time: Res<Time<Real>>,
+    theme: Option<Res<WidgetTheme>>,
+    mut query: Query<(
+        &Transition,
+        &mut TransitionState,
+        Option<&mut BackgroundColor>,
+        Option<&mut BorderColor>,
+        Option<&mut Node>,
+    )>,
+) {
+    // Snapping tweens end them on their first update
+    let delta = if theme.is_some_and(|theme| theme.snaps_transitions()) {
+        f32::INFINITY
+    } else {
+        time.delta_secs()
+    };
+    for (transition, mut state, background, border, node) in &mut query {
+        let state = &mut *state;
+        if let Some(mut background) = background {
+            let shown = state
+                .background
+                .update(background.0, delta, transition, lerp_color);
+            if shown != background.0 {
+                background.0 = shown;
+            }
+        }
+        if let Some(mut border) = border {
+            let shown = state.border.update(border.0, delta, transition, lerp_color);
+            if shown != border.0 {
+                border.0 = shown;
+            }
+        }
+        if let Some(mut node) = node {
+            let width = state.width.update(node.width, delta, transition, lerp_val);
+            let height = state
+                .height
+                .update(node.height, delta, transition, lerp_val);
+            if width != node.width || height != node.height {
+                node.width = width;
+                node.height = height;
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+
+/// Entities of a spawned widget, returned by the `spawn` function of widget builders.
+///
+/// Parts the widget doesn't have are `None`. Inner entities may be populated a command flush later,
+/// but their ids are valid right away, so they can be customized with further commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub struct WidgetRef {
+    /// Root entity of the widget, holding the widget components
+    pub root: Entity,
+    /// Entity of the text displayed by the widget
+    pub text: Option<Entity>,
+    /// Entity of the label text
+    pub label: Option<Entity>,
+    /// Entity of the hint text
+    pub hint: Option<Entity>,
+}
+
+impl WidgetRef {
+    /// Creates a reference to a widget without inner parts
+    pub const fn new(root: Entity) -> Self {
+        Self {
+            root,
+            text: None,
+            label: None,
+            hint: None,
+        }
+    }
+}
+
+/// Where widgets can be spawned from, either [`Commands`] or a [`ChildBuilder`]
+pub trait WidgetSpawner {
+    /// Spawns the empty root entity of a widget
+    fn spawn_root(&mut self) -> EntityCommands<'_>;
+}
+
+impl WidgetSpawner for Commands<'_, '_> {
+    fn spawn_root(&mut self) -> EntityCommands<'_> {
+        self.spawn_empty()
+    }
+}
+
+impl WidgetSpawner for ChildBuilder<'_> {
+    fn spawn_root(&mut self) -> EntityCommands<'_> {
+        self.spawn_empty()
+    }
+}
@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+
+use crate::WidgetsSystems;
+
+const DEFAULT_ZOOM_STEP: f32 = 0.1;
+const DEFAULT_MIN_ZOOM: f32 = 0.5;
+const DEFAULT_MAX_ZOOM: f32 = 3.0;
+
+#[cfg(not(target_os = "macos"))]
+const ZOOM_MODIFIERS: [KeyCode; 2] = [KeyCode::ControlLeft, KeyCode::ControlRight];
+#[cfg(target_os = "macos")]
+const ZOOM_MODIFIERS: [KeyCode; 2] = [KeyCode::SuperLeft, KeyCode::SuperRight];
+
+/// Plugin containing the UI zoom logic
+pub struct ZoomPlugin;
+
+impl Plugin for ZoomPlugin {
+    fn build(&self, app: &mut App) {
+        WidgetsSystems::configure(app);
+        app.init_resource::<UiZoom>().add_systems(
+            Update,
+            (zoom_shortcuts, apply_ui_zoom)
+                .chain()
+                .in_set(WidgetsSystems::Input),
+        );
+    }
+}
+
+/// Zoom level of the whole widget set.
+///
+/// The level drives Bevy's [`UiScale`], which scales every pixel size of the UI, so the fonts,
+/// paddings and heights of the design system constants used by all builders scale proportionally.
+/// `Ctrl` + `=` / `-` / `0` (`Cmd` on macOS) zoom in, out and reset when `shortcuts` is enabled.
+/// A [`UiScale`] set by the app is kept until the zoom level changes.
+///
+/// ```
+/// # use bevy_widgets::zoom::UiZoom;
+/// let mut zoom = UiZoom::default();
+/// zoom.zoom_in();
+/// assert_eq!(zoom.level(), 1.1);
+/// zoom.set_level(10.);
+/// assert_eq!(zoom.level(), zoom.max);
+/// ```
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct UiZoom {
+    level: f32,
+    /// Level change of each zoom in or out
+    pub step: f32,
+    /// Lowest zoom level
+    pub min: f32,
+    /// Highest zoom level
+    pub max: f32,
+    /// Enables the zoom keyboard shortcuts. Defaults to `true`.
+    pub shortcuts: bool,
+}
+
+impl Default for UiZoom {
+    fn default() -> Self {
+        Self {
+            level: 1.,
+            step: DEFAULT_ZOOM_STEP,
+            min: DEFAULT_MIN_ZOOM,
+            max: DEFAULT_MAX_ZOOM,
+            shortcuts: true,
+        }
+    }
+}
+
+impl UiZoom {
+    /// Current zoom level, `1.0` being the design system sizes
+    pub const fn level(&self) -> f32 {
+        self.level
+    }
+
+    /// Sets the zoom level, clamped between `min` and `max`
+    pub fn set_level(&mut self, level: f32) {
+        // Rounded so repeated steps land on the same levels
+        let level = (level * 100.).round() / 100.;
+        self.level = level.clamp(self.min, self.max);
+    }
+
+    /// Increases the zoom level by `step`
+    pub fn zoom_in(&mut self) {
+        self.set_level(self.level + self.step);
+    }
+
+    /// Decreases the zoom level by `step`
+    pub fn zoom_out(&mut self) {
+        self.set_level(self.level - self.step);
+    }
+
+    /// Restores the design system sizes
+    pub const fn reset(&mut self) {
+        self.level = 1.;
+    }
+
+    /// Effective size of a design system size `px`, in window logical pixels
+    pub fn scale(&self, px: f32) -> f32 {
+        px * self.level
+    }
+}
+
+fn zoom_shortcuts(keyboard: Res<ButtonInput<KeyCode>>, mut zoom: ResMut<UiZoom>) {
+    if !zoom.shortcuts || !keyboard.any_pressed(ZOOM_MODIFIERS) {
+        return;
+    }
+    if keyboard.any_just_pressed([KeyCode::Equal, KeyCode::NumpadAdd]) {
+        zoom.zoom_in();
+    } else if keyboard.any_just_pressed([KeyCode::Minus, KeyCode::NumpadSubtract]) {
+        zoom.zoom_out();
+    } else if keyboard.any_just_pressed([KeyCode::Digit0, KeyCode::Numpad0]) {
+        zoom.reset();
+    }
+}
+
+// Skips the added resource so the `UiScale` set by the app is kept until the zoom changes
+fn apply_ui_zoom(zoom: Res<UiZoom>, mut ui_scale: ResMut<UiScale>) {
+    if zoom.is_changed() && !zoom.is_added() && ui_scale.0 != zoom.level() {
+        ui_scale.0 = zoom.level();
+    }
+}
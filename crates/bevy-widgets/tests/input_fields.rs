@@ -0,0 +1,117 @@
+//! Typing into and dragging input fields in a headless app
+
+use accesskit::{Action, ActionData, NodeId};
+use bevy::a11y::ActionRequest;
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy_widgets::focus::FocusPlugin;
+use bevy_widgets::input_fields::builder::{NumericFieldBuilder, TextInputBuilder};
+use bevy_widgets::input_fields::{InputFieldChangedEvent, InputFieldPlugin, InputFieldSubmitEvent};
+use bevy_widgets::test_utils::WidgetTestExt;
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), InputPlugin))
+        .init_asset::<Image>()
+        .init_asset::<TextureAtlasLayout>()
+        .add_plugins(bevy::text::TextPlugin)
+        .init_resource::<UiScale>()
+        .add_plugins((FocusPlugin, InputFieldPlugin));
+    app.advance_frames(1);
+    app
+}
+
+#[test]
+fn typing_into_focused_text_field() {
+    let mut app = app();
+    let field = app
+        .world_mut()
+        .spawn(TextInputBuilder::default().build())
+        .id();
+    app.advance_frames(1);
+
+    app.type_text("ignored").advance_frames(1);
+    app.assert_field_text(field, "");
+
+    app.click(field).advance_frames(1);
+    app.type_text("hello world").advance_frames(1);
+    app.assert_field_text(field, "hello world");
+    assert!(!app.drain_events::<InputFieldChangedEvent>().is_empty());
+
+    app.tap_key(KeyCode::Enter).advance_frames(1);
+    let submitted = app.drain_events::<InputFieldSubmitEvent>();
+    assert_eq!(submitted.len(), 1);
+    assert_eq!(submitted[0].entity, field);
+    assert_eq!(submitted[0].value, "hello world");
+}
+
+#[test]
+fn dragging_numeric_field_by_steps() {
+    let mut app = app();
+    let field = app
+        .world_mut()
+        .spawn(
+            NumericFieldBuilder::<i32>::default()
+                .with_initial_value(10)
+                .with_range(0..=20)
+                .with_drag_step(1)
+                .build(),
+        )
+        .id();
+    app.advance_frames(1);
+
+    // Each drag event moves the value by one drag step, right and up increase it
+    app.drag(field, Vec2::new(4., 0.)).advance_frames(1);
+    assert_eq!(app.numeric_value::<i32>(field), 11);
+    app.assert_field_text(field, "11");
+    app.drag(field, Vec2::new(-4., 0.)).advance_frames(1);
+    assert_eq!(app.numeric_value::<i32>(field), 10);
+
+    // Clamped to the range
+    for _ in 0..15 {
+        app.drag(field, Vec2::new(4., 0.));
+    }
+    app.advance_frames(1);
+    assert_eq!(app.numeric_value::<i32>(field), 20);
+}
+
+#[test]
+fn typed_numeric_value_snaps_to_step() {
+    let mut app = app();
+    let field = app
+        .world_mut()
+        .spawn(
+            NumericFieldBuilder::<f32>::default()
+                .with_step(0.25)
+                .build(),
+        )
+        .id();
+    app.advance_frames(1);
+
+    app.click(field).advance_frames(1);
+    app.type_text("1.3").advance_frames(1);
+    assert_eq!(app.numeric_value::<f32>(field), 1.25);
+}
+
+#[test]
+fn screen_reader_set_value_changes_text_like_set_text() {
+    let mut app = app();
+    let field = app
+        .world_mut()
+        .spawn(TextInputBuilder::default().build())
+        .id();
+    app.advance_frames(1);
+    app.drain_events::<InputFieldChangedEvent>();
+
+    app.world_mut()
+        .send_event(ActionRequest(accesskit::ActionRequest {
+            action: Action::SetValue,
+            target: NodeId(field.to_bits()),
+            data: Some(ActionData::Value("read aloud".into())),
+        }));
+    app.advance_frames(2);
+    app.assert_field_text(field, "read aloud");
+    let changes = app.drain_events::<InputFieldChangedEvent>();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].value, "read aloud");
+}
@@ -0,0 +1,108 @@
+//! Selecting list box rows with the mouse and the keyboard in a headless app
+
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy_widgets::focus::FocusPlugin;
+use bevy_widgets::lists::{
+    ListBox, ListBoxBuilder, ListBoxRow, ListBoxSelectionChanged, ListsPlugin, SelectionMode,
+};
+use bevy_widgets::test_utils::WidgetTestExt;
+
+const ITEMS: [&str; 5] = ["Camera", "Cube", "Light", "Player", "Sphere"];
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin))
+        .add_plugins((FocusPlugin, ListsPlugin));
+    app.advance_frames(1);
+    app
+}
+
+fn spawn_list(app: &mut App, mode: SelectionMode) -> Entity {
+    let list = app
+        .world_mut()
+        .spawn(ListBoxBuilder::new(ITEMS).with_selection_mode(mode).build())
+        .id();
+    app.advance_frames(1);
+    list
+}
+
+fn row(app: &mut App, index: usize) -> Entity {
+    app.world_mut()
+        .query::<(Entity, &ListBoxRow)>()
+        .iter(app.world())
+        .find(|(_, row)| row.index() == index)
+        .map(|(entity, _)| entity)
+        .expect("row exists")
+}
+
+fn selected(app: &App, list: Entity) -> Vec<usize> {
+    app.world()
+        .get::<ListBox>(list)
+        .expect("list exists")
+        .selected()
+        .collect()
+}
+
+fn click_row(app: &mut App, index: usize, modifier: Option<KeyCode>) {
+    let row = row(app, index);
+    // Modifiers are read from `ButtonInput`, updated on the next frame
+    if let Some(modifier) = modifier {
+        app.press_key(modifier).advance_frames(1);
+    }
+    app.click(row).advance_frames(1);
+    if let Some(modifier) = modifier {
+        app.release_key(modifier).advance_frames(1);
+    }
+}
+
+#[test]
+fn single_selection_replaces_the_selected_row() {
+    let mut app = app();
+    let list = spawn_list(&mut app, SelectionMode::Single);
+
+    click_row(&mut app, 1, None);
+    assert_eq!(selected(&app, list), [1]);
+
+    // Ctrl and Shift don't extend single selections
+    click_row(&mut app, 3, Some(KeyCode::ControlLeft));
+    assert_eq!(selected(&app, list), [3]);
+    click_row(&mut app, 0, Some(KeyCode::ShiftLeft));
+    assert_eq!(selected(&app, list), [0]);
+
+    // Clicking a row focuses the list for keyboard navigation
+    app.tap_key(KeyCode::ArrowDown).advance_frames(1);
+    assert_eq!(selected(&app, list), [1]);
+    app.tap_key(KeyCode::End).advance_frames(1);
+    assert_eq!(selected(&app, list), [4]);
+
+    let changes = app.drain_events::<ListBoxSelectionChanged>();
+    let selections: Vec<Vec<usize>> = changes.into_iter().map(|event| event.selected).collect();
+    assert_eq!(selections, [vec![1], vec![3], vec![0], vec![1], vec![4]]);
+}
+
+#[test]
+fn multiple_selection_toggles_and_extends_ranges() {
+    let mut app = app();
+    let list = spawn_list(&mut app, SelectionMode::Multiple);
+
+    click_row(&mut app, 1, None);
+    click_row(&mut app, 3, Some(KeyCode::ControlLeft));
+    assert_eq!(selected(&app, list), [1, 3]);
+
+    // Ranges start from the last clicked row
+    click_row(&mut app, 0, Some(KeyCode::ShiftLeft));
+    assert_eq!(selected(&app, list), [0, 1, 2, 3]);
+
+    click_row(&mut app, 2, Some(KeyCode::ControlLeft));
+    assert_eq!(selected(&app, list), [0, 1, 3]);
+
+    app.press_key(KeyCode::ControlLeft).advance_frames(1);
+    app.tap_key(KeyCode::KeyA).advance_frames(1);
+    app.release_key(KeyCode::ControlLeft).advance_frames(1);
+    assert_eq!(selected(&app, list), [0, 1, 2, 3, 4]);
+
+    // A plain click goes back to a single row
+    click_row(&mut app, 4, None);
+    assert_eq!(selected(&app, list), [4]);
+}
@@ -0,0 +1,52 @@
+//! Updating progress bars after they are spawned in a headless app
+
+use bevy::prelude::*;
+use bevy_widgets::progress::{ProgressBar, ProgressBarBuilder, ProgressPlugin};
+use bevy_widgets::test_utils::WidgetTestExt;
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, ProgressPlugin));
+    app.advance_frames(1);
+    app
+}
+
+fn labels(app: &mut App) -> Vec<String> {
+    app.world_mut()
+        .query::<&Text>()
+        .iter(app.world())
+        .map(|text| text.0.clone())
+        .collect()
+}
+
+#[test]
+fn toggling_show_label_adds_and_removes_the_label() {
+    let mut app = app();
+    let bar = app
+        .world_mut()
+        .spawn(ProgressBarBuilder::default().with_value(0.25).build())
+        .id();
+    app.advance_frames(1);
+    assert!(labels(&mut app).is_empty());
+
+    app.world_mut()
+        .get_mut::<ProgressBar>(bar)
+        .expect("bar exists")
+        .show_label = true;
+    app.advance_frames(1);
+    assert_eq!(labels(&mut app), ["25%"]);
+
+    app.world_mut()
+        .get_mut::<ProgressBar>(bar)
+        .expect("bar exists")
+        .value = 0.5;
+    app.advance_frames(1);
+    assert_eq!(labels(&mut app), ["50%"]);
+
+    app.world_mut()
+        .get_mut::<ProgressBar>(bar)
+        .expect("bar exists")
+        .show_label = false;
+    app.advance_frames(1);
+    assert!(labels(&mut app).is_empty());
+}
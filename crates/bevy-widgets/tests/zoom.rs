@@ -0,0 +1,22 @@
+//! Driving the UI scale with the zoom shortcuts in a headless app
+
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy_widgets::test_utils::WidgetTestExt;
+use bevy_widgets::zoom::{UiZoom, ZoomPlugin};
+
+#[test]
+fn app_ui_scale_is_kept_until_zoom_changes() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin))
+        .insert_resource(UiScale(2.))
+        .add_plugins(ZoomPlugin);
+    app.advance_frames(2);
+    assert_eq!(app.world().resource::<UiScale>().0, 2.);
+
+    app.press_key(KeyCode::ControlLeft).advance_frames(1);
+    app.tap_key(KeyCode::Equal).advance_frames(1);
+    app.release_key(KeyCode::ControlLeft).advance_frames(1);
+    assert_eq!(app.world().resource::<UiZoom>().level(), 1.1);
+    assert_eq!(app.world().resource::<UiScale>().0, 1.1);
+}